@@ -0,0 +1,532 @@
+use num::Rational64;
+
+use crate::{Clip, FilmFormat, Framerate, Timecode, TimecodeError, TimecodeRange};
+
+/// The kinds of change listed in a [ChangeList].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChangeKind {
+    /// New material which is not in the old version.
+    Insert,
+    /// Old material which is not in the new version.
+    Delete,
+    /// Material added to or removed from the head or tail of an event which is otherwise
+    /// unchanged.
+    Trim,
+    /// Material removed from here and placed elsewhere in the new version.
+    MoveFrom,
+    /// Material placed here from elsewhere in the old version.
+    MoveTo,
+}
+
+impl ChangeKind {
+    /// Returns the name of the change, as shown in [ChangeList::report].
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChangeKind::Insert => "Insert",
+            ChangeKind::Delete => "Delete",
+            ChangeKind::Trim => "Trim",
+            ChangeKind::MoveFrom => "Move from",
+            ChangeKind::MoveTo => "Move to",
+        }
+    }
+}
+
+/// A single change between two cut versions, returned by [ChangeList::changes].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    /// The kind of change.
+    pub kind: ChangeKind,
+    /// The source reel of the material.
+    pub reel: String,
+    /// The source range of the material.
+    pub source: TimecodeRange,
+    /// The record range of the material in the old version, or [None] for material which is
+    /// only in the new version.
+    pub old: Option<TimecodeRange>,
+    /// The record range of the material in the new version, or [None] for material which is
+    /// only in the old version.
+    pub new: Option<TimecodeRange>,
+    /// The record timecode in the new version the change is made at. For removed material,
+    /// this is the point it was removed from.
+    pub at: Timecode,
+    /// The length the change adds to the cut, negative for removed material.
+    pub length: Timecode,
+    /// The running total of `length` up to and including this change.
+    pub total: Timecode,
+}
+
+/**
+The ordered list of inserts, deletes, trims and moves which turn one cut version into another,
+for turning picture changes over to sound.
+
+Each version is a single track of [Clip] events. Material is matched between versions by reel and
+source timecode, and the longest run of material which stays in order is treated as unchanged.
+Footages are counted from a shared start timecode in a chosen [FilmFormat].
+
+# Examples
+
+```rust
+use vtc::{ChangeKind, ChangeList, Clip, FilmFormat, Timecode, TimecodeRange, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+
+let old = vec![
+    Clip::new("A001", range("14:00:00:00", "14:00:10:00"), range("01:00:00:00", "01:00:10:00")),
+    Clip::new("A002", range("15:00:00:00", "15:00:10:00"), range("01:00:10:00", "01:00:20:00")),
+];
+let new = vec![
+    Clip::new("A001", range("14:00:00:00", "14:00:08:00"), range("01:00:00:00", "01:00:08:00")),
+    Clip::new("B001", range("09:00:00:00", "09:00:04:00"), range("01:00:08:00", "01:00:12:00")),
+    Clip::new("A002", range("15:00:00:00", "15:00:10:00"), range("01:00:12:00", "01:00:22:00")),
+];
+
+let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+let changes = ChangeList::compare(&old, &new, start, FilmFormat::FF35mm4perf).unwrap();
+
+let kinds: Vec<ChangeKind> = changes.changes().iter().map(|change| change.kind).collect();
+assert_eq!(vec![ChangeKind::Trim, ChangeKind::Insert], kinds);
+
+let insert = &changes.changes()[1];
+assert_eq!("B001", insert.reel);
+assert_eq!("12+00", changes.footage(&insert.at));
+assert_eq!("6+00", insert.length.feet_and_frames(FilmFormat::FF35mm4perf));
+assert_eq!("3+00", insert.total.feet_and_frames(FilmFormat::FF35mm4perf));
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangeList {
+    start: Timecode,
+    format: FilmFormat,
+    old_length: Timecode,
+    new_length: Timecode,
+    changes: Vec<Change>,
+}
+
+impl ChangeList {
+    /// Compares the `old` and `new` event lists and returns the changes between them, in
+    /// order of where they are made in the new version.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The events of the old version.
+    ///
+    /// * `new` - The events of the new version.
+    ///
+    /// * `start` - The record timecode footages are counted from, like the first frame of the
+    ///   reel.
+    ///
+    /// * `format` - The film format footages are counted in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if the record range of any event is not at the rate
+    /// of `start`.
+    pub fn compare(
+        old: &[Clip],
+        new: &[Clip],
+        start: Timecode,
+        format: FilmFormat,
+    ) -> Result<ChangeList, TimecodeError> {
+        let rate = start.rate();
+        for clip in old.iter().chain(new) {
+            TimecodeError::check_rate(clip.record().rate(), rate)?;
+        }
+
+        let zero = Timecode::with_i64_frames(0, rate);
+        let pieces = match_pieces(old, new);
+        let kept = longest_in_order(&pieces);
+
+        let mut changes = Vec::new();
+        let mut old_claimed: Vec<Vec<TimecodeRange>> = vec![Vec::new(); old.len()];
+        let mut new_claimed: Vec<Vec<TimecodeRange>> = vec![Vec::new(); new.len()];
+        let mut moved: Vec<TimecodeRange> = Vec::new();
+
+        for (index, piece) in pieces.iter().enumerate() {
+            new_claimed[piece.new_clip].push(piece.new);
+            if kept[index] {
+                old_claimed[piece.old_clip].push(piece.old);
+                continue;
+            }
+
+            // Material already used in place or moved elsewhere is a repeat, so it is new here.
+            let repeated = pieces
+                .iter()
+                .zip(&kept)
+                .any(|(other, is_kept)| *is_kept && other.old.overlaps(&piece.old))
+                || moved.iter().any(|other| other.overlaps(&piece.old));
+            if repeated {
+                new_claimed[piece.new_clip].pop();
+                continue;
+            }
+
+            old_claimed[piece.old_clip].push(piece.old);
+            moved.push(piece.old);
+            let clip = &new[piece.new_clip];
+            changes.push(Change {
+                kind: ChangeKind::MoveFrom,
+                reel: clip.reel().to_string(),
+                source: source_of(clip, &piece.new),
+                old: Some(piece.old),
+                new: Some(piece.new),
+                at: removed_at(&pieces, &kept, &piece.old, start),
+                length: -piece.old.duration(),
+                total: zero,
+            });
+            changes.push(Change {
+                kind: ChangeKind::MoveTo,
+                reel: clip.reel().to_string(),
+                source: source_of(clip, &piece.new),
+                old: Some(piece.old),
+                new: Some(piece.new),
+                at: piece.new.start(),
+                length: piece.new.duration(),
+                total: zero,
+            });
+        }
+
+        let kept_old: Vec<(usize, TimecodeRange)> = pieces
+            .iter()
+            .zip(&kept)
+            .filter(|(_, is_kept)| **is_kept)
+            .map(|(piece, _)| (piece.old_clip, piece.old))
+            .collect();
+        let kept_new: Vec<(usize, TimecodeRange)> = pieces
+            .iter()
+            .zip(&kept)
+            .filter(|(_, is_kept)| **is_kept)
+            .map(|(piece, _)| (piece.new_clip, piece.new))
+            .collect();
+
+        for (index, clip) in old.iter().enumerate() {
+            for removed in subtract(&clip.record(), &old_claimed[index]) {
+                let kind = if touches(index, &removed, &kept_old) {
+                    ChangeKind::Trim
+                } else {
+                    ChangeKind::Delete
+                };
+                changes.push(Change {
+                    kind,
+                    reel: clip.reel().to_string(),
+                    source: source_of(clip, &removed),
+                    old: Some(removed),
+                    new: None,
+                    at: removed_at(&pieces, &kept, &removed, start),
+                    length: -removed.duration(),
+                    total: zero,
+                });
+            }
+        }
+
+        for (index, clip) in new.iter().enumerate() {
+            for added in subtract(&clip.record(), &new_claimed[index]) {
+                let kind = if touches(index, &added, &kept_new) {
+                    ChangeKind::Trim
+                } else {
+                    ChangeKind::Insert
+                };
+                changes.push(Change {
+                    kind,
+                    reel: clip.reel().to_string(),
+                    source: source_of(clip, &added),
+                    old: None,
+                    new: Some(added),
+                    at: added.start(),
+                    length: added.duration(),
+                    total: zero,
+                });
+            }
+        }
+
+        // Removals come before additions at the same point, so replaced material is taken out
+        // before its replacement goes in.
+        changes.sort_by_key(|change| {
+            let removed = change.length.frames() < 0;
+            let position = if removed { change.old } else { change.new };
+            (change.at, !removed, position.map(|range| range.start()))
+        });
+
+        let mut total = zero;
+        for change in &mut changes {
+            total += change.length;
+            change.total = total;
+        }
+
+        Ok(ChangeList {
+            start,
+            format,
+            old_length: length(old, rate),
+            new_length: length(new, rate),
+            changes,
+        })
+    }
+
+    /// Returns the changes, in order of where they are made in the new version.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns the record timecode footages are counted from.
+    pub fn start(&self) -> Timecode {
+        self.start
+    }
+
+    /// Returns the film format footages are counted in.
+    pub fn format(&self) -> FilmFormat {
+        self.format
+    }
+
+    /// Returns the running footage of record timecode `tc`, counted from the start.
+    pub fn footage(&self, tc: &Timecode) -> String {
+        (*tc - self.start).feet_and_frames(self.format)
+    }
+
+    /// Returns the change list as a plain-text report in the style of an Avid Change List,
+    /// with the old and new lengths followed by one line per change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{ChangeList, Clip, FilmFormat, Timecode, TimecodeRange, rates};
+    ///
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+    ///
+    /// let old = vec![Clip::new(
+    ///     "A001",
+    ///     range("14:00:00:00", "14:00:10:00"),
+    ///     range("01:00:00:00", "01:00:10:00"),
+    /// )];
+    /// let new = vec![Clip::new(
+    ///     "A001",
+    ///     range("14:00:00:00", "14:00:08:00"),
+    ///     range("01:00:00:00", "01:00:08:00"),
+    /// )];
+    ///
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let changes = ChangeList::compare(&old, &new, start, FilmFormat::FF35mm4perf).unwrap();
+    ///
+    /// let report = changes.report();
+    /// assert!(report.contains("OLD LENGTH: 15+00"));
+    /// assert!(report.contains("TOTAL CHANGE: -3+00"));
+    /// ```
+    pub fn report(&self) -> String {
+        let total = self.new_length - self.old_length;
+        let mut report = String::from("CHANGE LIST\n");
+        report.push_str(&format!(
+            "START: {}  OLD LENGTH: {}  NEW LENGTH: {}  TOTAL CHANGE: {}\n\n",
+            self.start.timecode(),
+            self.old_length.feet_and_frames(self.format),
+            self.new_length.feet_and_frames(self.format),
+            total.feet_and_frames(self.format),
+        ));
+
+        report.push_str(&format!(
+            "{:>4}  {:<10}{:>10}  {:<13}{:>10}{:>10}  {:<20}{:<20}{:<12}{}\n",
+            "#", "CHANGE", "AT", "AT TC", "LENGTH", "TOTAL", "OLD", "NEW", "REEL", "SOURCE"
+        ));
+
+        let footages = |range: &Option<TimecodeRange>| match range {
+            Some(range) => format!(
+                "{} - {}",
+                self.footage(&range.start()),
+                self.footage(&range.end())
+            ),
+            None => String::new(),
+        };
+
+        for (index, change) in self.changes.iter().enumerate() {
+            report.push_str(&format!(
+                "{:>4}  {:<10}{:>10}  {:<13}{:>10}{:>10}  {:<20}{:<20}{:<12}{}\n",
+                index + 1,
+                change.kind.name(),
+                self.footage(&change.at),
+                change.at.timecode(),
+                change.length.feet_and_frames(self.format),
+                change.total.feet_and_frames(self.format),
+                footages(&change.old),
+                footages(&change.new),
+                change.reel,
+                change.source,
+            ));
+        }
+
+        report
+    }
+}
+
+/// Material found in both versions.
+#[derive(Clone, Copy, Debug)]
+struct Piece {
+    old_clip: usize,
+    new_clip: usize,
+    old: TimecodeRange,
+    new: TimecodeRange,
+}
+
+/// Returns the material found in both versions, sorted by where it is in the new version.
+/// Normal-speed events match wherever their source overlaps; other events only match an event
+/// with the same source, speed and length.
+fn match_pieces(old: &[Clip], new: &[Clip]) -> Vec<Piece> {
+    let normal = Rational64::from_integer(1);
+    let mut pieces: Vec<Piece> = Vec::new();
+
+    for (new_index, new_clip) in new.iter().enumerate() {
+        for (old_index, old_clip) in old.iter().enumerate() {
+            if old_clip.reel() != new_clip.reel()
+                || old_clip.source().rate() != new_clip.source().rate()
+            {
+                continue;
+            }
+
+            let piece = if old_clip.speed() == normal && new_clip.speed() == normal {
+                old_clip
+                    .source()
+                    .intersection(&new_clip.source())
+                    .map(|shared| Piece {
+                        old_clip: old_index,
+                        new_clip: new_index,
+                        old: record_of(old_clip, &shared),
+                        new: record_of(new_clip, &shared),
+                    })
+            } else if old_clip.source() == new_clip.source()
+                && old_clip.speed() == new_clip.speed()
+                && old_clip.record().duration() == new_clip.record().duration()
+            {
+                Some(Piece {
+                    old_clip: old_index,
+                    new_clip: new_index,
+                    old: old_clip.record(),
+                    new: new_clip.record(),
+                })
+            } else {
+                None
+            };
+
+            // The old version may use the same source twice, so only the first match of any
+            // new frame counts.
+            if let Some(piece) = piece {
+                let claimed = pieces
+                    .iter()
+                    .any(|other| other.new_clip == new_index && other.new.overlaps(&piece.new));
+                if !piece.new.is_empty() && !claimed {
+                    pieces.push(piece);
+                }
+            }
+        }
+    }
+
+    pieces.sort_by_key(|piece| (piece.new.start(), piece.old.start()));
+    pieces
+}
+
+/// Returns which pieces make up the longest run of material, by duration, which is in the
+/// same order in both versions. `pieces` must be sorted by where they are in the new version.
+fn longest_in_order(pieces: &[Piece]) -> Vec<bool> {
+    let mut best: Vec<i64> = Vec::with_capacity(pieces.len());
+    let mut previous: Vec<Option<usize>> = Vec::with_capacity(pieces.len());
+
+    for (index, piece) in pieces.iter().enumerate() {
+        let (length, from) = (0..index)
+            .filter(|&before| pieces[before].old.end() <= piece.old.start())
+            .map(|before| (best[before], Some(before)))
+            .max()
+            .unwrap_or((0, None));
+        best.push(length + piece.new.duration().frames());
+        previous.push(from);
+    }
+
+    let mut kept = vec![false; pieces.len()];
+    let mut next = (0..pieces.len()).max_by_key(|&index| (best[index], std::cmp::Reverse(index)));
+    while let Some(index) = next {
+        kept[index] = true;
+        next = previous[index];
+    }
+
+    kept
+}
+
+/// Returns the point in the new version old material at `removed` was taken from: the end
+/// of the last unchanged material before it, or `start` if there is none.
+fn removed_at(
+    pieces: &[Piece],
+    kept: &[bool],
+    removed: &TimecodeRange,
+    start: Timecode,
+) -> Timecode {
+    pieces
+        .iter()
+        .zip(kept)
+        .filter(|(piece, is_kept)| **is_kept && piece.old.end() <= removed.start())
+        .max_by_key(|(piece, _)| piece.old.end())
+        .map(|(piece, _)| piece.new.end())
+        .unwrap_or(start)
+}
+
+/// Returns whether `range` of event `clip` touches unchanged material of the same event.
+fn touches(clip: usize, range: &TimecodeRange, kept: &[(usize, TimecodeRange)]) -> bool {
+    kept.iter().any(|(kept_clip, kept_range)| {
+        *kept_clip == clip
+            && (kept_range.end() == range.start() || range.end() == kept_range.start())
+    })
+}
+
+/// Returns the record range of `clip` showing the normal-speed `source` range.
+fn record_of(clip: &Clip, source: &TimecodeRange) -> TimecodeRange {
+    let offset = source.start().frames() - clip.source().start().frames();
+    let start = Timecode::with_i64_frames(
+        clip.record().start().frames() + offset,
+        clip.record().rate(),
+    );
+    let duration = Timecode::with_i64_frames(source.duration().frames(), clip.record().rate());
+    // Both are at the record rate, and the duration is never negative.
+    TimecodeRange::with_duration(start, duration).unwrap()
+}
+
+/// Returns the source range of `clip` shown at `record`. Events which are not at normal speed
+/// are only ever changed whole, so their whole source range is returned.
+fn source_of(clip: &Clip, record: &TimecodeRange) -> TimecodeRange {
+    if clip.speed() != Rational64::from_integer(1) {
+        return clip.source();
+    }
+
+    let offset = record.start().frames() - clip.record().start().frames();
+    let start = Timecode::with_i64_frames(
+        clip.source().start().frames() + offset,
+        clip.source().rate(),
+    );
+    let duration = Timecode::with_i64_frames(record.duration().frames(), clip.source().rate());
+    // Both are at the source rate, and the duration is never negative.
+    TimecodeRange::with_duration(start, duration).unwrap()
+}
+
+/// Returns the parts of `range` not covered by any of `covered`, in order.
+fn subtract(range: &TimecodeRange, covered: &[TimecodeRange]) -> Vec<TimecodeRange> {
+    let mut covered: Vec<TimecodeRange> = covered
+        .iter()
+        .filter_map(|other| range.intersection(other))
+        .collect();
+    covered.sort_by_key(|other| other.start());
+
+    let mut remaining = Vec::new();
+    let mut cursor = range.start();
+    for other in covered {
+        if other.start() > cursor {
+            // Both are inside `range`, and the end is after the start.
+            remaining.push(TimecodeRange::new(cursor, other.start()).unwrap());
+        }
+        cursor = cursor.max(other.end());
+    }
+    if cursor < range.end() {
+        remaining.push(TimecodeRange::new(cursor, range.end()).unwrap());
+    }
+
+    remaining
+}
+
+/// Returns the total record duration of `clips`.
+fn length(clips: &[Clip], rate: Framerate) -> Timecode {
+    clips
+        .iter()
+        .fold(Timecode::with_i64_frames(0, rate), |total, clip| {
+            total + clip.record().duration()
+        })
+}
@@ -0,0 +1,295 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::{range, range_at, tc};
+    use crate::{
+        rates, ChangeKind, ChangeList, Clip, FilmFormat, Timecode, TimecodeError, TimecodeRange,
+    };
+    use rstest::rstest;
+
+    /// Cuts `events` of (reel, source in, seconds) back to back from 01:00:00:00.
+    fn cut(events: &[(&str, &str, i64)]) -> Vec<Clip> {
+        let mut record_in = tc("01:00:00:00");
+        events
+            .iter()
+            .map(|(reel, source_in, seconds)| {
+                let duration = Timecode::with_i64_frames(seconds * 24, rates::F24);
+                let source = TimecodeRange::with_duration(tc(source_in), duration).unwrap();
+                let record = TimecodeRange::with_duration(record_in, duration).unwrap();
+                record_in = record.end();
+                Clip::new(reel, source, record)
+            })
+            .collect()
+    }
+
+    /// Summarizes each change as (kind, reel, at, length, total) with footages in 35mm 4-perf.
+    fn summary(changes: &ChangeList) -> Vec<(ChangeKind, String, String, String, String)> {
+        changes
+            .changes()
+            .iter()
+            .map(|change| {
+                (
+                    change.kind,
+                    change.reel.clone(),
+                    changes.footage(&change.at),
+                    change.length.feet_and_frames(changes.format()),
+                    change.total.feet_and_frames(changes.format()),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the total record length of a cut made by `cut`.
+    fn cut_length(events: &[Clip]) -> Timecode {
+        events.iter().fold(tc("00:00:00:00"), |total, clip| {
+            total + clip.record().duration()
+        })
+    }
+
+    /// Asserts the running total of the last change is the difference in length of the cuts.
+    fn assert_total(changes: &ChangeList, old: &[Clip], new: &[Clip]) {
+        let total = changes
+            .changes()
+            .last()
+            .map_or(tc("00:00:00:00"), |change| change.total);
+        assert_eq!(cut_length(new) - cut_length(old), total, "total");
+    }
+
+    fn compare(old: &[Clip], new: &[Clip]) -> ChangeList {
+        ChangeList::compare(old, new, tc("01:00:00:00"), FilmFormat::FF35mm4perf).unwrap()
+    }
+
+    fn change(
+        kind: ChangeKind,
+        reel: &str,
+        at: &str,
+        length: &str,
+        total: &str,
+    ) -> (ChangeKind, String, String, String, String) {
+        (
+            kind,
+            reel.to_string(),
+            at.to_string(),
+            length.to_string(),
+            total.to_string(),
+        )
+    }
+
+    fn old_cut() -> Vec<Clip> {
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+        ])
+    }
+
+    #[test]
+    fn test_change_list_unchanged() {
+        let changes = compare(&old_cut(), &old_cut());
+        assert!(changes.changes().is_empty());
+    }
+
+    #[rstest]
+    #[case::insert(
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("B001", "09:00:00:00", 4),
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+        ]),
+        vec![change(ChangeKind::Insert, "B001", "15+00", "6+00", "6+00")],
+    )]
+    #[case::delete(
+        cut(&[("A001", "14:00:00:00", 10), ("A003", "16:00:00:00", 10)]),
+        vec![change(ChangeKind::Delete, "A002", "15+00", "-15+00", "-15+00")],
+    )]
+    #[case::trim_tail(
+        cut(&[
+            ("A001", "14:00:00:00", 8),
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+        ]),
+        vec![change(ChangeKind::Trim, "A001", "12+00", "-3+00", "-3+00")],
+    )]
+    #[case::trim_head(
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("A002", "15:00:02:00", 8),
+            ("A003", "16:00:00:00", 10),
+        ]),
+        vec![change(ChangeKind::Trim, "A002", "15+00", "-3+00", "-3+00")],
+    )]
+    #[case::lengthen(
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("A002", "14:59:58:00", 14),
+            ("A003", "16:00:00:00", 10),
+        ]),
+        vec![
+            change(ChangeKind::Trim, "A002", "15+00", "3+00", "3+00"),
+            change(ChangeKind::Trim, "A002", "33+00", "3+00", "6+00"),
+        ],
+    )]
+    #[case::replace(
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("B001", "09:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+        ]),
+        vec![
+            change(ChangeKind::Delete, "A002", "15+00", "-15+00", "-15+00"),
+            change(ChangeKind::Insert, "B001", "15+00", "15+00", "0+00"),
+        ],
+    )]
+    #[case::moved(
+        cut(&[
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+            ("A001", "14:00:00:00", 10),
+        ]),
+        vec![
+            change(ChangeKind::MoveFrom, "A001", "0+00", "-15+00", "-15+00"),
+            change(ChangeKind::MoveTo, "A001", "30+00", "15+00", "0+00"),
+        ],
+    )]
+    #[case::repeated(
+        cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+            ("A001", "14:00:00:00", 2),
+        ]),
+        vec![change(ChangeKind::Insert, "A001", "45+00", "3+00", "3+00")],
+    )]
+    fn test_change_list(
+        #[case] new: Vec<Clip>,
+        #[case] expected: Vec<(ChangeKind, String, String, String, String)>,
+    ) {
+        let changes = compare(&old_cut(), &new);
+        assert_eq!(expected, summary(&changes));
+        assert_total(&changes, &old_cut(), &new);
+    }
+
+    #[test]
+    fn test_change_list_moved_repeated() {
+        let old = cut(&[("A001", "14:00:00:00", 10), ("A002", "15:00:00:00", 10)]);
+        let new = cut(&[
+            ("A002", "15:00:00:00", 10),
+            ("A001", "14:00:00:00", 10),
+            ("A001", "14:00:00:00", 10),
+        ]);
+
+        let changes = compare(&old, &new);
+        assert_eq!(
+            vec![
+                change(ChangeKind::MoveFrom, "A001", "0+00", "-15+00", "-15+00"),
+                change(ChangeKind::MoveTo, "A001", "15+00", "15+00", "0+00"),
+                change(ChangeKind::Insert, "A001", "30+00", "15+00", "15+00"),
+            ],
+            summary(&changes)
+        );
+        assert_total(&changes, &old, &new);
+    }
+
+    #[test]
+    fn test_change_list_ranges() {
+        let new = cut(&[
+            ("A001", "14:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+            ("A002", "15:00:04:00", 6),
+        ]);
+        let changes = compare(&old_cut(), &new);
+
+        let moved: Vec<_> = changes
+            .changes()
+            .iter()
+            .map(|change| {
+                (
+                    change.kind,
+                    change.source.to_string(),
+                    change.old.map(|range| range.to_string()),
+                    change.new.map(|range| range.to_string()),
+                    change.at.timecode(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                (
+                    ChangeKind::Delete,
+                    "15:00:00:00 - 15:00:04:00".to_string(),
+                    Some("01:00:10:00 - 01:00:14:00".to_string()),
+                    None,
+                    "01:00:10:00".to_string(),
+                ),
+                (
+                    ChangeKind::MoveFrom,
+                    "15:00:04:00 - 15:00:10:00".to_string(),
+                    Some("01:00:14:00 - 01:00:20:00".to_string()),
+                    Some("01:00:20:00 - 01:00:26:00".to_string()),
+                    "01:00:10:00".to_string(),
+                ),
+                (
+                    ChangeKind::MoveTo,
+                    "15:00:04:00 - 15:00:10:00".to_string(),
+                    Some("01:00:14:00 - 01:00:20:00".to_string()),
+                    Some("01:00:20:00 - 01:00:26:00".to_string()),
+                    "01:00:20:00".to_string(),
+                ),
+            ],
+            moved
+        );
+    }
+
+    #[test]
+    fn test_change_list_footage_format() {
+        let new = cut(&[("A001", "14:00:00:00", 10), ("A003", "16:00:00:00", 10)]);
+        let changes =
+            ChangeList::compare(&old_cut(), &new, tc("01:00:00:00"), FilmFormat::FF16mm).unwrap();
+
+        assert_eq!(FilmFormat::FF16mm, changes.format());
+        assert_eq!(tc("01:00:00:00"), changes.start());
+        assert_eq!("12+00", changes.footage(&changes.changes()[0].at));
+    }
+
+    #[test]
+    fn test_change_list_report() {
+        let new = cut(&[
+            ("A001", "14:00:00:00", 8),
+            ("B001", "09:00:00:00", 4),
+            ("A002", "15:00:00:00", 10),
+            ("A003", "16:00:00:00", 10),
+        ]);
+        let changes = compare(&old_cut(), &new);
+
+        let expected = "\
+CHANGE LIST
+START: 01:00:00:00  OLD LENGTH: 45+00  NEW LENGTH: 48+00  TOTAL CHANGE: 3+00
+
+   #  CHANGE            AT  AT TC            LENGTH     TOTAL  OLD                 NEW                 REEL        SOURCE
+   1  Trim           12+00  01:00:08:00       -3+00     -3+00  12+00 - 15+00                           A001        14:00:08:00 - 14:00:10:00
+   2  Insert         12+00  01:00:08:00        6+00      3+00                      12+00 - 18+00       B001        09:00:00:00 - 09:00:04:00
+";
+        assert_eq!(expected, changes.report());
+    }
+
+    #[test]
+    fn test_change_list_rate_mismatch() {
+        let record = range_at("01:00:00:00", "01:00:01:00", rates::F25);
+        let new = vec![Clip::new(
+            "A001",
+            range("14:00:00:00", "14:00:01:00"),
+            record,
+        )];
+
+        let err = ChangeList::compare(&old_cut(), &new, tc("01:00:00:00"), FilmFormat::FF35mm4perf)
+            .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+}
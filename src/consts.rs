@@ -16,6 +16,18 @@ pub(super) const PERFS_PER_FOOT_35: i64 = 64;
 /// and there are 40 frames in a 16mm foot.
 pub(super) const PERFS_PER_6INCHES_16: i64 = 20;
 
+/// The number of performations in a foot of 65mm film.
+pub(super) const PERFS_PER_FOOT_65: i64 = 64;
+
+/// The number of performations in a foot of Super 8 film.
+pub(super) const PERFS_PER_FOOT_SUPER8: i64 = 72;
+
+/// The number of performations in a foot of 9.5mm film.
+pub(super) const PERFS_PER_FOOT_9_5: i64 = 40;
+
+/// The frame number VFX plates conventionally start at, leaving room for pre-roll.
+pub(super) const VFX_START_FRAME: i64 = 1001;
+
 /// The number of ticks Adobe Premiere Pro breaks a second ratio.
 pub(super) const PREMIERE_TICKS_PER_SECOND: Ratio<i128> = Ratio::<i128>::new_raw(254016000000, 1);
 
@@ -24,6 +36,10 @@ pub(super) const SECONDS_PER_MINUTE_I64: i64 = 60;
 /// The number of seconds in an hour as an i64.
 pub(super) const SECONDS_PER_HOUR_I64: i64 = SECONDS_PER_MINUTE_I64 * 60;
 
+/// The most decimal places an ISO 8601 duration is written with. Any i64 seconds value scaled
+/// by `10^18` still fits in an i128.
+pub(super) const ISO8601_MAX_PRECISION: usize = 18;
+
 lazy_static! {
     /// TIMECODE_REGEX is a regex for parsing timecode values.
     pub(super) static ref TIMECODE_REGEX: Regex = regex::Regex::new(
@@ -34,7 +50,7 @@ lazy_static! {
 lazy_static! {
     /// TIMECODE_REGEX is a regex for parsing timecode values.
     pub(super) static ref FEET_AND_FRAMES_REGEX: Regex = regex::Regex::new(
-        r"^(?P<negative>-)?(?P<feet>[0-9]+)\+(?P<frames>[0-9]+)(\.(?P<perf>[0-9]))?$",
+        r"^(?P<negative>-)?(?P<feet>[0-9]+)\+(?P<frames>[0-9]+)(\.(?P<perf>[0-9]+))?$",
     ).unwrap();
 }
 
@@ -44,3 +60,26 @@ lazy_static! {
         r"^(?P<negative>-)?((?P<section1>[0-9]+)[:|;])?((?P<section2>[0-9]+)[:|;])?(?P<seconds>[0-9]+(\.[0-9]+)?)$",
     ).unwrap();
 }
+
+lazy_static! {
+    /// DURATION_UNITS_REGEX is a regex for parsing durations written with unit suffixes, like
+    /// '1h 2m 3s 4f'.
+    pub(super) static ref DURATION_UNITS_REGEX: Regex = regex::Regex::new(
+        r"(?i)^(?P<negative>-)?\s*((?P<hours>[0-9]+(\.[0-9]+)?)\s*(hours|hour|hrs|hr|h)\s*)?((?P<minutes>[0-9]+(\.[0-9]+)?)\s*(minutes|minute|mins|min|m)\s*)?((?P<seconds>[0-9]+(\.[0-9]+)?)\s*(seconds|second|secs|sec|s)\s*)?((?P<frames>[0-9]+)\s*(frames|frame|fr|f))?$",
+    ).unwrap();
+}
+
+lazy_static! {
+    /// DURATION_CLOCK_REGEX is a regex for parsing clock-style durations with a unit suffix
+    /// naming their first section, like '2:30 min'.
+    pub(super) static ref DURATION_CLOCK_REGEX: Regex = regex::Regex::new(
+        r"(?i)^(?P<negative>-)?(?P<clock>[0-9]+(:[0-9]+){1,2}(\.[0-9]+)?)\s*((?P<hours>hours|hour|hrs|hr|h)|(?P<minutes>minutes|minute|mins|min|m))$",
+    ).unwrap();
+}
+
+lazy_static! {
+    /// ISO_8601_DURATION_REGEX is a regex for parsing ISO 8601 time durations, like 'PT1H2M3.5S'.
+    pub(super) static ref ISO_8601_DURATION_REGEX: Regex = regex::Regex::new(
+        r"^(?P<negative>-)?P((?P<days>[0-9]+)D)?(?P<time>T((?P<hours>[0-9]+([.,][0-9]+)?)H)?((?P<minutes>[0-9]+([.,][0-9]+)?)M)?((?P<seconds>[0-9]+([.,][0-9]+)?)S)?)?$",
+    ).unwrap();
+}
@@ -0,0 +1,70 @@
+use num::integer::div_rem;
+
+use crate::consts::SECONDS_PER_MINUTE_I64;
+
+/// The number of minutes between minutes which do not drop frames.
+const MINUTES_PER_DROP_CYCLE: i64 = 10;
+
+/// Returns the number of frame numbers skipped at the start of each minute that is not a multiple
+/// of 10 for a drop-frame `timebase`.
+///
+/// Drop-frame timebases are always multiples of 30, and skip 2 frame numbers for every 30 frames
+/// of timebase: 2 for 29.97, 4 for 59.94, 8 for 119.88 and so on.
+pub(crate) fn dropped_per_minute(timebase: i64) -> i64 {
+    timebase / 15
+}
+
+/// Returns the number of real frames in a minute which drops frame numbers.
+fn frames_per_drop_minute(timebase: i64) -> i64 {
+    timebase * SECONDS_PER_MINUTE_I64 - dropped_per_minute(timebase)
+}
+
+/// Returns the number of real frames in a ten minute cycle, made up of one minute which does not
+/// drop frame numbers, and nine which do.
+fn frames_per_drop_cycle(timebase: i64) -> i64 {
+    timebase * SECONDS_PER_MINUTE_I64 * MINUTES_PER_DROP_CYCLE
+        - dropped_per_minute(timebase) * (MINUTES_PER_DROP_CYCLE - 1)
+}
+
+/// Converts a real, positive frame number into a label frame number: the frame number a
+/// non-drop timecode would need to display the same `HH:MM:SS:FF` label as the drop-frame
+/// timecode.
+///
+/// Algorithm adapted from: https://www.davidheidelberger.com/2010/06/10/drop-frame-timecode/
+///
+/// All math is done on integers, so this is exact for any drop-frame timebase.
+pub(crate) fn frames_to_label(frame_number: i64, timebase: i64) -> i64 {
+    let dropped = dropped_per_minute(timebase);
+
+    let (cycles, remaining) = div_rem(frame_number, frames_per_drop_cycle(timebase));
+    let mut label = frame_number + dropped * (MINUTES_PER_DROP_CYCLE - 1) * cycles;
+
+    // The first minute of each cycle does not drop frames. Every full drop-minute after that
+    // adds another set of dropped frame numbers.
+    if remaining > dropped {
+        label += dropped * ((remaining - dropped) / frames_per_drop_minute(timebase));
+    }
+
+    label
+}
+
+/// Converts a positive label frame number, as returned by [frames_to_label], back into a real frame
+/// number.
+///
+/// Label frame numbers which fall on a dropped frame number map to the following real frame.
+pub(crate) fn label_to_frames(label: i64, timebase: i64) -> i64 {
+    let total_minutes = label / (timebase * SECONDS_PER_MINUTE_I64);
+    label - dropped_minutes_adjustment(total_minutes, timebase)
+}
+
+/// Returns the number of frame numbers which have been dropped before the start of
+/// `total_minutes`.
+pub(crate) fn dropped_minutes_adjustment(total_minutes: i64, timebase: i64) -> i64 {
+    dropped_per_minute(timebase) * (total_minutes - total_minutes / MINUTES_PER_DROP_CYCLE)
+}
+
+/// Returns whether a label's frames value is skipped in drop-frame timecode.
+pub(crate) fn is_dropped_label(minutes: i64, seconds: i64, frames: i64, timebase: i64) -> bool {
+    let is_cycle_minute = minutes % MINUTES_PER_DROP_CYCLE == 0;
+    seconds == 0 && !is_cycle_minute && frames < dropped_per_minute(timebase)
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use crate::drop_frame::{frames_to_label, is_dropped_label, label_to_frames};
+    use crate::{rates, Framerate, Ntsc, Timecode, TimecodeParseError};
+    use rstest::rstest;
+
+    /// tests the number of frames dropped per minute for each drop-frame timebase.
+    #[rstest]
+    #[case(rates::F29_97_DF, 2)]
+    #[case(rates::F59_94_DF, 4)]
+    #[case(Framerate::with_timebase(120, Ntsc::DropFrame).unwrap(), 8)]
+    #[case(Framerate::with_timebase(240, Ntsc::DropFrame).unwrap(), 16)]
+    #[case(rates::F29_97_NDF, -1)]
+    #[case(rates::F24, -1)]
+    fn test_drop_frames_per_minute(#[case] rate: Framerate, #[case] expected: i64) {
+        let expected = if expected < 0 { None } else { Some(expected) };
+        assert_eq!(expected, rate.drop_frames_per_minute(), "{}", rate);
+    }
+
+    /// tests that every frame in 24 hours at each drop-frame timebase maps to a unique, valid
+    /// label and back again.
+    #[rstest]
+    #[case(30)]
+    #[case(60)]
+    #[case(120)]
+    fn test_exhaustive_round_trip(#[case] timebase: i64) {
+        let rate = Framerate::with_timebase(timebase, Ntsc::DropFrame).unwrap();
+        let frames_per_day = rate.frames_per_24_hours();
+        let frames_per_minute = timebase * 60;
+
+        let mut previous_label = -1;
+        for frame in 0..frames_per_day {
+            let label = frames_to_label(frame, timebase);
+            assert_eq!(frame, label_to_frames(label, timebase), "frame {}", frame);
+
+            let minutes = (label / frames_per_minute) % 60;
+            let seconds = (label % frames_per_minute) / timebase;
+            let frames = label % timebase;
+            assert!(
+                !is_dropped_label(minutes, seconds, frames, timebase),
+                "frame {} produced dropped label {}",
+                frame,
+                label
+            );
+
+            // Labels always increase, and only ever skip over dropped frame numbers.
+            let step = label - previous_label;
+            if step != 1 {
+                assert_eq!(
+                    rate.drop_frames_per_minute().unwrap() + 1,
+                    step,
+                    "frame {} skipped from label {} to {}",
+                    frame,
+                    previous_label,
+                    label
+                );
+                assert_eq!(0, seconds, "frame {} skipped mid-minute", frame);
+            }
+            previous_label = label;
+        }
+
+        assert_eq!(
+            timebase * 60 * 60 * 24,
+            frames_to_label(frames_per_day, timebase)
+        );
+    }
+
+    /// tests the frames() and timecode() round-trip through the public API over the first ten
+    /// minutes and last minute of the day, and a stride through the rest.
+    #[rstest]
+    #[case(rates::F29_97_DF)]
+    #[case(rates::F59_94_DF)]
+    #[case(Framerate::with_timebase(120, Ntsc::DropFrame).unwrap())]
+    fn test_timecode_round_trip(#[case] rate: Framerate) -> Result<(), TimecodeParseError> {
+        let frames_per_day = rate.frames_per_24_hours();
+        let cycle = rate.timebase().to_integer() * 60 * 10;
+
+        let head = 0..cycle;
+        let tail = (frames_per_day - rate.timebase().to_integer() * 60)..frames_per_day;
+        for frame in head.chain(tail) {
+            let tc = Timecode::with_frames(frame, rate)?;
+            let parsed = Timecode::with_frames(tc.timecode(), rate)?;
+            assert_eq!(frame, parsed.frames(), "{} round trip", tc.timecode());
+        }
+
+        for frame in (-frames_per_day..frames_per_day).step_by(9973) {
+            let tc = Timecode::with_frames(frame, rate)?;
+            let parsed = Timecode::with_frames(tc.timecode(), rate)?;
+            assert_eq!(frame, parsed.frames(), "{} round trip", tc.timecode());
+        }
+
+        Ok(())
+    }
+
+    /// tests known drop-frame labels, including those right before a minute drops frames.
+    #[rstest]
+    #[case(902, rates::F29_97_DF, "00:00:30;02")]
+    #[case(1799, rates::F29_97_DF, "00:00:59;29")]
+    #[case(1800, rates::F29_97_DF, "00:01:00;02")]
+    #[case(17981, rates::F29_97_DF, "00:09:59;29")]
+    #[case(17982, rates::F29_97_DF, "00:10:00;00")]
+    #[case(2589407, rates::F29_97_DF, "23:59:59;29")]
+    #[case(2589408, rates::F29_97_DF, "24:00:00;00")]
+    #[case(-1, rates::F29_97_DF, "-00:00:00;01")]
+    #[case(3599, rates::F59_94_DF, "00:00:59;59")]
+    #[case(3600, rates::F59_94_DF, "00:01:00;04")]
+    #[case(5178815, rates::F59_94_DF, "23:59:59;59")]
+    #[case(7199, Framerate::with_timebase(120, Ntsc::DropFrame).unwrap(), "00:00:59;119")]
+    #[case(7200, Framerate::with_timebase(120, Ntsc::DropFrame).unwrap(), "00:01:00;08")]
+    fn test_drop_frame_labels(
+        #[case] frames: i64,
+        #[case] rate: Framerate,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(frames, rate)?;
+        assert_eq!(expected, tc.timecode());
+        assert_eq!(frames, Timecode::with_frames(expected, rate)?.frames());
+        Ok(())
+    }
+}
@@ -1,26 +1,137 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Range, RangeInclusive};
+
+use crate::{Cadence, Framerate, Timecode};
+
 #[allow(unused)]
 // we need this here for the doc links, but clippy doesnt like that it isn't being used in code.
-use crate::{Framerate, Timecode};
+use crate::{EventList, FilmReel, FrameNumbering, Pulldown, TimecodeRange};
+
+/**
+The details of why a value could not be parsed, carried by every [TimecodeParseError] and
+[FramerateParseError].
+
+Diagnostics point at the offending part of the input so tools can highlight it, report the range
+of values that would have been accepted where there is one, and offer a corrected value where one
+can be worked out.
+
+# Examples
+
+```rust
+use vtc::{Timecode, rates};
+
+let err = Timecode::parse_strict("01:00:00:24", rates::F24).unwrap_err();
+let diagnostic = err.diagnostic();
+
+assert_eq!("01:00:00:24", diagnostic.input());
+assert_eq!(Some(9..11), diagnostic.span());
+assert_eq!(Some(0..=23), diagnostic.expected());
+assert_eq!("01:00:00:24 at [24]: frames must be < 24", diagnostic.message());
+assert_eq!(Some("01:00:01:00"), diagnostic.suggestion());
+
+assert_eq!(
+    "01:00:00:24 at [24]: frames must be < 24 (did you mean '01:00:01:00'?)",
+    err.to_string(),
+);
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseDiagnostic {
+    input: String,
+    span: Option<Range<usize>>,
+    expected: Option<RangeInclusive<i64>>,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl ParseDiagnostic {
+    /// Creates a new diagnostic for `input` with no span, expected range or suggestion.
+    pub(crate) fn new<T: Into<String>>(input: T, message: String) -> Self {
+        ParseDiagnostic {
+            input: input.into(),
+            span: None,
+            expected: None,
+            message,
+            suggestion: None,
+        }
+    }
+
+    /// Sets the byte span of the input the diagnostic points at.
+    pub(crate) fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Sets the range of values which would have been accepted.
+    pub(crate) fn with_expected(mut self, expected: RangeInclusive<i64>) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    /// Sets a corrected value the caller may have meant.
+    pub(crate) fn with_suggestion<T: Into<String>>(mut self, suggestion: T) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Returns the input which could not be parsed. Values which are not strings are rendered
+    /// as one.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte span of [ParseDiagnostic::input] which caused the error, if the error
+    /// can be narrowed to one part of the input.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Returns the range of values which would have been accepted for the part of the input
+    /// under [ParseDiagnostic::span], if there is one.
+    pub fn expected(&self) -> Option<RangeInclusive<i64>> {
+        self.expected.clone()
+    }
+
+    /// Returns a human-readable description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a corrected value the caller may have meant, if one could be worked out.
+    pub fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+}
+
+impl Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean '{}'?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
 
 /// Returned from [Framerate::with_timebase] and [Framerate::with_playback] when there is an
 /// error parsing a [Framerate].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FramerateParseError {
     /// Returned when a bad NTSC playback or timebase rate is given.
-    Ntsc(String),
+    Ntsc(ParseDiagnostic),
     /// Returned when a bad Drop-frame playback or timebase rate is given.
-    DropFrame(String),
+    DropFrame(ParseDiagnostic),
     /// Returned when a negative value is attempted to be converted to a Framerate.
-    Negative(String),
+    Negative(ParseDiagnostic),
     /// Returned when a value is not precise enough to be cast to a non-ntsc value, such as
     /// floating-point values.
     ///
     /// NTSC values have known denominators they must adhere to, and therefore can be coerced from
     /// imprecise values. No such coercion can be done for non-NTSC values.
-    Imprecise(String),
+    Imprecise(ParseDiagnostic),
     /// Returned when there is an error doing an internal type conversion to create a new Framerate,
     /// such as a u64 value overflowing a [num::Rational64].
-    Conversion(String),
+    Conversion(ParseDiagnostic),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -29,10 +140,252 @@ pub enum FramerateParseError {
 pub enum TimecodeParseError {
     /// Returned when there is an error doing an internal type conversion to create a new Timecode,
     /// such as a u64 value overflowing a [num::Rational64].
-    Conversion(String),
+    Conversion(ParseDiagnostic),
     /// Returned when a string does not match any known Timecode format.
-    UnknownStrFormat(String),
+    UnknownStrFormat(ParseDiagnostic),
     /// Returned when a drop-frame tc-string has a frames value that should have been dropped.
     /// ex: '00:01:00:01'.
-    DropFrameValue(String),
+    DropFrameValue(ParseDiagnostic),
+    /// Returned by [Timecode::parse_strict] when a tc-string has leading, trailing or inner
+    /// whitespace. ex: ' 01:00:00:00'.
+    Whitespace(ParseDiagnostic),
+    /// Returned by [Timecode::parse_strict] when a tc-string is missing one of its sections.
+    /// ex: '01:00:00' or '01::00:00'.
+    MissingSection(ParseDiagnostic),
+    /// Returned by [Timecode::parse_strict] when a tc-string section has the wrong number of
+    /// digits, or there are too many sections. ex: '1:00:00:00'.
+    MalformedSection(ParseDiagnostic),
+    /// Returned by [Timecode::parse_strict] when a tc-string section is larger than its place
+    /// allows. ex: '00:75:00:00' or '00:00:00:24' at 24 fps.
+    SectionOutOfRange(ParseDiagnostic),
+    /// Returned by [Timecode::parse_strict] when a tc-string uses a separator which is not
+    /// allowed, or does not match the drop-frame setting of the rate. ex: '01:00:00;00' at
+    /// 24 fps.
+    SeparatorMismatch(ParseDiagnostic),
+    /// Returned by [Timecode::parse_any] when a string could be more than one representation.
+    /// ex: '1:12', which could be partial timecode or a runtime.
+    Ambiguous(ParseDiagnostic),
+}
+
+impl FramerateParseError {
+    /// Returns the [ParseDiagnostic] describing the error.
+    pub fn diagnostic(&self) -> &ParseDiagnostic {
+        match self {
+            FramerateParseError::Ntsc(diagnostic)
+            | FramerateParseError::DropFrame(diagnostic)
+            | FramerateParseError::Negative(diagnostic)
+            | FramerateParseError::Imprecise(diagnostic)
+            | FramerateParseError::Conversion(diagnostic) => diagnostic,
+        }
+    }
+}
+
+impl Display for FramerateParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.diagnostic().fmt(f)
+    }
+}
+
+impl std::error::Error for FramerateParseError {}
+
+impl TimecodeParseError {
+    /// Returns the [ParseDiagnostic] describing the error.
+    pub fn diagnostic(&self) -> &ParseDiagnostic {
+        match self {
+            TimecodeParseError::Conversion(diagnostic)
+            | TimecodeParseError::UnknownStrFormat(diagnostic)
+            | TimecodeParseError::DropFrameValue(diagnostic)
+            | TimecodeParseError::Whitespace(diagnostic)
+            | TimecodeParseError::MissingSection(diagnostic)
+            | TimecodeParseError::MalformedSection(diagnostic)
+            | TimecodeParseError::SectionOutOfRange(diagnostic)
+            | TimecodeParseError::SeparatorMismatch(diagnostic)
+            | TimecodeParseError::Ambiguous(diagnostic) => diagnostic,
+        }
+    }
+}
+
+impl TimecodeParseError {
+    /// Sets the suggestion of the error's diagnostic, if it does not already have one.
+    pub(crate) fn with_default_suggestion(mut self, suggestion: Option<String>) -> Self {
+        let diagnostic = match &mut self {
+            TimecodeParseError::Conversion(diagnostic)
+            | TimecodeParseError::UnknownStrFormat(diagnostic)
+            | TimecodeParseError::DropFrameValue(diagnostic)
+            | TimecodeParseError::Whitespace(diagnostic)
+            | TimecodeParseError::MissingSection(diagnostic)
+            | TimecodeParseError::MalformedSection(diagnostic)
+            | TimecodeParseError::SectionOutOfRange(diagnostic)
+            | TimecodeParseError::SeparatorMismatch(diagnostic)
+            | TimecodeParseError::Ambiguous(diagnostic) => diagnostic,
+        };
+        if diagnostic.suggestion.is_none() {
+            diagnostic.suggestion = suggestion;
+        }
+        self
+    }
+}
+
+impl Display for TimecodeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.diagnostic().fmt(f)
+    }
+}
+
+impl std::error::Error for TimecodeParseError {}
+
+/**
+Returned by operations which work on [Timecode] values that have already been made, like
+[Pulldown::video], when the values do not fit together. Unlike [TimecodeParseError], nothing is
+being parsed, so there is no input to point at.
+
+# Examples
+
+```rust
+use vtc::{Cadence, Pulldown, Timecode, TimecodeError, rates};
+
+let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+let film = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+let err = pulldown.video(&film).unwrap_err();
+
+assert_eq!(
+    TimecodeError::RateMismatch {
+        found: rates::F24,
+        expected: rates::F23_98,
+    },
+    err,
+);
+assert_eq!(
+    "[24] is not the expected rate of [23.98 NTSC NDF]",
+    err.to_string(),
+);
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimecodeError {
+    /// Returned when a value is not at the [Framerate] the operation works at. ex: 24 fps film
+    /// frames given to a 23.98 fps [Pulldown].
+    RateMismatch {
+        /// The rate of the value given.
+        found: Framerate,
+        /// The rate the operation works at.
+        expected: Framerate,
+    },
+    /// Returned when a [TimecodeRange] would end before it starts.
+    EndBeforeStart {
+        /// The start of the range.
+        start: Timecode,
+        /// The end of the range, which is before `start`.
+        end: Timecode,
+    },
+    /// Returned when one cycle of a [Cadence] does not take the same time at a film and video
+    /// rate. ex: 2:3 pulldown from 24 fps to 25 fps.
+    CadenceMismatch {
+        /// The cadence which was asked for.
+        cadence: Cadence,
+        /// The film rate.
+        film: Framerate,
+        /// The video rate.
+        video: Framerate,
+    },
+    /// Returned by [FilmReel::plan] when the longest a reel may be leaves no room for picture
+    /// after the leader.
+    ReelTooShort {
+        /// The longest a reel may be.
+        max_length: Timecode,
+        /// The length of the leader.
+        leader: Timecode,
+    },
+    /// Returned when two events of an [EventList] would overlap.
+    Overlap {
+        /// The record in of the earlier event.
+        first: Timecode,
+        /// The record in of the later event, which is before the earlier event ends.
+        second: Timecode,
+    },
+    /// Returned when an [EventList] edit names an event the list does not have.
+    MissingEvent {
+        /// The index of the event which was asked for.
+        index: usize,
+        /// The number of events in the list.
+        count: usize,
+    },
+    /// Returned when a value the operation makes along the way is too large to count in
+    /// frames. ex: a [FrameNumbering] frame number too far from its start.
+    Overflow,
+    /// Returned when a [Timecode] the operation makes along the way cannot be parsed, like a
+    /// label which does not exist at a new rate.
+    Parse(Box<TimecodeParseError>),
+}
+
+impl TimecodeError {
+    /// Returns [TimecodeError::RateMismatch] if `found` is not `expected`.
+    pub(crate) fn check_rate(found: Framerate, expected: Framerate) -> Result<(), TimecodeError> {
+        if found == expected {
+            Ok(())
+        } else {
+            Err(TimecodeError::RateMismatch { found, expected })
+        }
+    }
+}
+
+impl Display for TimecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimecodeError::RateMismatch { found, expected } => {
+                write!(f, "{} is not the expected rate of {}", found, expected)
+            }
+            TimecodeError::EndBeforeStart { start, end } => write!(
+                f,
+                "range end {} is before its start {}",
+                end.timecode(),
+                start.timecode()
+            ),
+            TimecodeError::CadenceMismatch {
+                cadence,
+                film,
+                video,
+            } => write!(
+                f,
+                "{:?} cadence cannot transfer {} film to {} video",
+                cadence, film, video
+            ),
+            TimecodeError::ReelTooShort { max_length, leader } => write!(
+                f,
+                "maximum reel length {} has no room for picture after the {} leader",
+                max_length.timecode(),
+                leader.timecode()
+            ),
+            TimecodeError::Overlap { first, second } => write!(
+                f,
+                "the event at {} overlaps the event at {}",
+                second.timecode(),
+                first.timecode()
+            ),
+            TimecodeError::MissingEvent { index, count } => write!(
+                f,
+                "event {} does not exist in a list of {} events",
+                index, count
+            ),
+            TimecodeError::Overflow => write!(f, "value is too large to count in frames"),
+            TimecodeError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TimecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TimecodeError::Parse(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<TimecodeParseError> for TimecodeError {
+    fn from(err: TimecodeParseError) -> Self {
+        TimecodeError::Parse(Box::new(err))
+    }
 }
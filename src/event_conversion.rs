@@ -0,0 +1,299 @@
+use num::Rational64;
+
+use crate::{
+    Clip, Framerate, RoundingMode, Timecode, TimecodeError, TimecodeParseResult, TimecodeRange,
+};
+
+/// How a [Timecode] is moved to a new [Framerate]. See
+/// [Changing Framerates](Timecode#change-framerates).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RateConversion {
+    /// Keeps the frame count, like [Timecode::rebase].
+    Rebase,
+    /// Keeps the real-world time, snapped to a frame with the [RoundingMode], like
+    /// [Timecode::convert_rate].
+    Convert(RoundingMode),
+    /// Keeps the `HH:MM:SS:FF` label, like [Timecode::relabel].
+    Relabel,
+}
+
+impl RateConversion {
+    /// Returns `tc` moved to `rate`.
+    ///
+    /// # Errors
+    ///
+    /// [RateConversion::Relabel] returns an error if the label of `tc` does not exist at
+    /// `rate`. The other conversions never fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{RateConversion, RoundingMode, Timecode, rates};
+    ///
+    /// let tc = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+    ///
+    /// let rebased = RateConversion::Rebase.apply(&tc, rates::F24).unwrap();
+    /// assert_eq!("01:00:00:00", rebased.timecode());
+    ///
+    /// let converted = RateConversion::Convert(RoundingMode::HalfUp)
+    ///     .apply(&tc, rates::F24)
+    ///     .unwrap();
+    /// assert_eq!("01:00:03:14", converted.timecode());
+    /// ```
+    pub fn apply(&self, tc: &Timecode, rate: Framerate) -> TimecodeParseResult {
+        match self {
+            RateConversion::Rebase => Ok(tc.rebase(rate)),
+            RateConversion::Convert(mode) => Ok(tc.convert_rate(rate, *mode)),
+            RateConversion::Relabel => tc.relabel(rate),
+        }
+    }
+
+    /// Returns the duration an event lasting `duration` should have at `rate`. Durations
+    /// whose label does not exist at `rate` keep their frame count when relabeled.
+    fn expected_duration(&self, duration: &Timecode, rate: Framerate) -> Timecode {
+        self.apply(duration, rate)
+            .unwrap_or_else(|_| duration.rebase(rate))
+    }
+}
+
+/// Which side of an event a [DurationChange] is on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventSide {
+    /// The record in and out of the event.
+    Record,
+    /// The source in and out of the event.
+    Source,
+}
+
+/// An event whose duration did not survive a conversion, returned by
+/// [EventConversion::convert_ranges] and [EventConversion::convert_clips].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DurationChange {
+    /// The index of the event in the list.
+    pub index: usize,
+    /// The side of the event whose duration changed.
+    pub side: EventSide,
+    /// The duration before the conversion.
+    pub before: Timecode,
+    /// The duration the event would have if the duration itself were converted.
+    pub expected: Timecode,
+    /// The duration after converting the in and out.
+    pub after: Timecode,
+    /// Whether the event had frames before the conversion and has none after.
+    pub collapsed: bool,
+}
+
+/// The events of an [EventConversion], and the events whose durations changed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConvertedEvents<T> {
+    /// The converted events, in the order given.
+    pub events: Vec<T>,
+    /// The events whose duration changed or collapsed because of rounding, in order.
+    pub changes: Vec<DurationChange>,
+}
+
+/**
+Converts event lists, like EDL events or markers, to a new [Framerate].
+
+In and out points are converted separately, so rounding can change an event's duration, or
+collapse a short event to nothing. These events are reported alongside the converted list.
+
+# Examples
+
+```rust
+use vtc::{EventConversion, RateConversion, RoundingMode, TimecodeRange, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F23_98).unwrap();
+
+let markers = vec![
+    range("01:00:00:00", "01:00:02:00"),
+    range("01:00:10:05", "01:00:10:06"),
+];
+
+let conversion = EventConversion::new(rates::F25, RateConversion::Convert(RoundingMode::HalfUp));
+let converted = conversion.convert_ranges(&markers).unwrap();
+
+assert_eq!("01:00:03:15 - 01:00:05:15", converted.events[0].to_string());
+assert_eq!(1, converted.changes.len());
+assert_eq!(1, converted.changes[0].index);
+assert_eq!(2, converted.changes[0].after.frames());
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EventConversion {
+    record_rate: Framerate,
+    record_mode: RateConversion,
+    source_rate: Framerate,
+    source_mode: RateConversion,
+}
+
+impl EventConversion {
+    /// Creates a new [EventConversion] which moves both record and source times to `rate`
+    /// using `mode`.
+    pub fn new(rate: Framerate, mode: RateConversion) -> Self {
+        EventConversion {
+            record_rate: rate,
+            record_mode: mode,
+            source_rate: rate,
+            source_mode: mode,
+        }
+    }
+
+    /// Returns the conversion with source times moved to `rate` using `mode` instead, like
+    /// keeping source labels while converting the record side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{Clip, EventConversion, RateConversion, TimecodeRange, rates};
+    ///
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F23_98).unwrap();
+    ///
+    /// let clip = Clip::new(
+    ///     "A001",
+    ///     range("14:21:00:00", "14:21:02:00"),
+    ///     range("01:00:00:00", "01:00:02:00"),
+    /// );
+    ///
+    /// let conversion = EventConversion::new(rates::F24, RateConversion::Rebase)
+    ///     .source(rates::F23_98, RateConversion::Rebase);
+    /// let converted = conversion.convert_clips(&[clip]).unwrap();
+    ///
+    /// let clip = &converted.events[0];
+    /// assert_eq!(rates::F24, clip.record().rate());
+    /// assert_eq!(rates::F23_98, clip.source().rate());
+    /// ```
+    pub fn source(mut self, rate: Framerate, mode: RateConversion) -> Self {
+        self.source_rate = rate;
+        self.source_mode = mode;
+        self
+    }
+
+    /// Returns the rate record times are converted to.
+    pub fn record_rate(&self) -> Framerate {
+        self.record_rate
+    }
+
+    /// Returns the rate source times are converted to.
+    pub fn source_rate(&self) -> Framerate {
+        self.source_rate
+    }
+
+    /// Converts `ranges`, like marker or subtitle events, with the record rate and mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Parse] if any in or out has no label at the new rate with
+    /// [RateConversion::Relabel].
+    pub fn convert_ranges(
+        &self,
+        ranges: &[TimecodeRange],
+    ) -> Result<ConvertedEvents<TimecodeRange>, TimecodeError> {
+        let mut converted = ConvertedEvents {
+            events: Vec::with_capacity(ranges.len()),
+            changes: Vec::new(),
+        };
+
+        for (index, range) in ranges.iter().enumerate() {
+            let (event, change) = convert_range(
+                index,
+                EventSide::Record,
+                range,
+                self.record_rate,
+                self.record_mode,
+            )?;
+            converted.events.push(event);
+            converted.changes.extend(change);
+        }
+
+        Ok(converted)
+    }
+
+    /// Converts `clips`, like EDL events, with record times at the record rate and mode, and
+    /// source times at the source rate and mode. Each speed is scaled by how much the source
+    /// and record durations changed, so it matches the converted ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Parse] if any in or out has no label at the new rate with
+    /// [RateConversion::Relabel].
+    pub fn convert_clips(&self, clips: &[Clip]) -> Result<ConvertedEvents<Clip>, TimecodeError> {
+        let mut converted = ConvertedEvents {
+            events: Vec::with_capacity(clips.len()),
+            changes: Vec::new(),
+        };
+
+        for (index, clip) in clips.iter().enumerate() {
+            let (source, source_change) = convert_range(
+                index,
+                EventSide::Source,
+                &clip.source(),
+                self.source_rate,
+                self.source_mode,
+            )?;
+            let (record, record_change) = convert_range(
+                index,
+                EventSide::Record,
+                &clip.record(),
+                self.record_rate,
+                self.record_mode,
+            )?;
+
+            let speed = clip.speed() * duration_scale(&clip.source(), &source)
+                / duration_scale(&clip.record(), &record);
+            converted
+                .events
+                .push(Clip::new(clip.reel(), source, record).with_speed(speed));
+            converted.changes.extend(record_change);
+            converted.changes.extend(source_change);
+        }
+
+        Ok(converted)
+    }
+}
+
+/// Returns how many times longer `after` is than `before`, or 1 if either is empty.
+fn duration_scale(before: &TimecodeRange, after: &TimecodeRange) -> Rational64 {
+    let before = before.duration().frames();
+    let after = after.duration().frames();
+    if before == 0 || after == 0 {
+        Rational64::from_integer(1)
+    } else {
+        Rational64::new(after, before)
+    }
+}
+
+/// Converts a single `range` of event `index`, and returns the change to its duration if
+/// there is one.
+fn convert_range(
+    index: usize,
+    side: EventSide,
+    range: &TimecodeRange,
+    rate: Framerate,
+    mode: RateConversion,
+) -> Result<(TimecodeRange, Option<DurationChange>), TimecodeError> {
+    let converted = TimecodeRange::new(
+        mode.apply(&range.start(), rate)?,
+        mode.apply(&range.end(), rate)?,
+    )?;
+
+    let before = range.duration();
+    let expected = mode.expected_duration(&before, rate);
+    let after = converted.duration();
+    let collapsed = !range.is_empty() && converted.is_empty();
+
+    let change = if collapsed || after != expected {
+        Some(DurationChange {
+            index,
+            side,
+            before,
+            expected,
+            after,
+            collapsed,
+        })
+    } else {
+        None
+    };
+
+    Ok((converted, change))
+}
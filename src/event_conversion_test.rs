@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::range_at;
+    use crate::{
+        rates, Clip, EventConversion, EventSide, RateConversion, RoundingMode, Timecode,
+        TimecodeError, TimecodeParseError, TimecodeRange,
+    };
+    use num::Rational64;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::rebase(RateConversion::Rebase, "01:00:00:00 - 01:00:02:00")]
+    #[case::convert(
+        RateConversion::Convert(RoundingMode::HalfUp),
+        "01:00:03:14 - 01:00:05:14"
+    )]
+    #[case::relabel(RateConversion::Relabel, "01:00:00:00 - 01:00:02:00")]
+    fn test_convert_ranges_modes(#[case] mode: RateConversion, #[case] expected: &str) {
+        let ranges = vec![range_at("01:00:00:00", "01:00:02:00", rates::F23_98)];
+        let converted = EventConversion::new(rates::F24, mode)
+            .convert_ranges(&ranges)
+            .unwrap();
+
+        assert_eq!(expected, converted.events[0].to_string());
+        assert_eq!(rates::F24, converted.events[0].rate());
+        assert!(converted.changes.is_empty(), "{:?}", converted.changes);
+    }
+
+    #[test]
+    fn test_convert_ranges_rebase_keeps_durations() {
+        let ranges: Vec<TimecodeRange> = (0..100)
+            .map(|start| {
+                let start = 86400 + start * 7;
+                range_at(start, start + 1, rates::F29_97_NDF)
+            })
+            .collect();
+
+        let converted = EventConversion::new(rates::F25, RateConversion::Rebase)
+            .convert_ranges(&ranges)
+            .unwrap();
+        assert!(converted.changes.is_empty());
+    }
+
+    #[test]
+    fn test_convert_ranges_collapsed() {
+        let ranges: Vec<TimecodeRange> = (0..5)
+            .map(|start| range_at(108000 + start, 108001 + start, rates::F30))
+            .collect();
+
+        let converted =
+            EventConversion::new(rates::F24, RateConversion::Convert(RoundingMode::HalfUp))
+                .convert_ranges(&ranges)
+                .unwrap();
+
+        // Five 30 fps frames span four 24 fps frames, so one event must collapse.
+        let collapsed: Vec<usize> = converted
+            .changes
+            .iter()
+            .filter(|change| change.collapsed)
+            .map(|change| change.index)
+            .collect();
+        assert_eq!(1, collapsed.len(), "{:?}", converted.changes);
+
+        let change = converted.changes[0];
+        assert_eq!(EventSide::Record, change.side);
+        assert_eq!(1, change.before.frames());
+        assert_eq!(1, change.expected.frames());
+        assert_eq!(0, change.after.frames());
+        assert!(converted.events[collapsed[0]].is_empty());
+    }
+
+    #[test]
+    fn test_convert_ranges_relabel_changed() {
+        let ranges = vec![
+            range_at("00:00:00:20", "00:00:01:05", rates::F25),
+            range_at("00:00:01:00", "00:00:02:00", rates::F25),
+        ];
+
+        let converted = EventConversion::new(rates::F24, RateConversion::Relabel)
+            .convert_ranges(&ranges)
+            .unwrap();
+
+        assert_eq!("00:00:00:20 - 00:00:01:05", converted.events[0].to_string());
+        assert_eq!(1, converted.changes.len());
+
+        let change = converted.changes[0];
+        assert_eq!(0, change.index);
+        assert_eq!(10, change.before.frames());
+        assert_eq!(10, change.expected.frames());
+        assert_eq!(9, change.after.frames());
+        assert!(!change.collapsed);
+    }
+
+    #[test]
+    fn test_convert_ranges_relabel_error() {
+        let ranges = vec![range_at("00:00:00:24", "00:00:01:05", rates::F25)];
+
+        let err = EventConversion::new(rates::F24, RateConversion::Relabel)
+            .convert_ranges(&ranges)
+            .unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                TimecodeError::Parse(parse) if matches!(**parse, TimecodeParseError::SectionOutOfRange(_))
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_convert_clips() {
+        let clips = vec![
+            Clip::new(
+                "A001",
+                range_at("01:00:10:05", "01:00:10:06", rates::F23_98),
+                range_at("01:00:10:05", "01:00:10:06", rates::F23_98),
+            ),
+            Clip::new(
+                "A002",
+                range_at("15:00:00:00", "15:00:01:00", rates::F23_98),
+                range_at("01:00:10:06", "01:00:12:06", rates::F23_98),
+            ),
+        ];
+
+        let conversion = EventConversion::new(rates::F24, RateConversion::Relabel)
+            .source(rates::F25, RateConversion::Convert(RoundingMode::HalfUp));
+        assert_eq!(rates::F24, conversion.record_rate());
+        assert_eq!(rates::F25, conversion.source_rate());
+
+        let converted = conversion.convert_clips(&clips).unwrap();
+
+        let first = &converted.events[0];
+        assert_eq!("A001", first.reel());
+        assert_eq!("01:00:10:05 - 01:00:10:06", first.record().to_string());
+        assert_eq!(rates::F25, first.source().rate());
+        assert_eq!(
+            Rational64::from_integer(2),
+            first.speed(),
+            "speed matches the rounded source"
+        );
+
+        let second = &converted.events[1];
+        assert_eq!("15:00:54:00 - 15:00:55:00", second.source().to_string());
+        assert_eq!(
+            Rational64::new(25, 48),
+            second.speed(),
+            "speed matches the converted durations"
+        );
+
+        let changed: Vec<(usize, EventSide)> = converted
+            .changes
+            .iter()
+            .map(|change| (change.index, change.side))
+            .collect();
+        assert_eq!(vec![(0, EventSide::Source)], changed);
+        assert_eq!(2, converted.changes[0].after.frames());
+    }
+
+    #[test]
+    fn test_convert_clips_speed() {
+        let source = range_at("15:00:00:00", "15:00:00:00", rates::F24);
+        let record = range_at("01:00:00:00", "01:00:02:00", rates::F24);
+        let clips = vec![
+            Clip::new("A001", source, record).with_speed(Rational64::from_integer(0)),
+            Clip::new("A002", source, record).with_speed(Rational64::new(1, 2)),
+            Clip::new(
+                "A003",
+                range_at("15:00:00:00", "15:00:04:00", rates::F24),
+                record,
+            ),
+        ];
+
+        let conversion =
+            EventConversion::new(rates::F48, RateConversion::Convert(RoundingMode::HalfUp))
+                .source(rates::F24, RateConversion::Rebase);
+        let converted = conversion.convert_clips(&clips).unwrap();
+
+        let speeds: Vec<Rational64> = converted.events.iter().map(|clip| clip.speed()).collect();
+        assert_eq!(
+            vec![
+                Rational64::from_integer(0),
+                Rational64::new(1, 4),
+                Rational64::from_integer(1),
+            ],
+            speeds
+        );
+
+        let record = Timecode::with_frames("01:00:01:00", rates::F48).unwrap();
+        assert_eq!(
+            "15:00:02:00",
+            converted.events[2].source_at(&record).unwrap().timecode()
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use num::Rational64;
+
+use crate::{Clip, Framerate, Timecode, TimecodeError, TimecodeRange};
+
+/**
+A single track of non-overlapping events in record order, for scripting edits outside an NLE.
+
+Each edit returns a new [EventList] and a record of the events it moved, trimmed or removed, and
+leaves the original untouched. All edits use [Timecode] arithmetic, so NTSC and drop-frame rates
+behave the same as any other.
+
+# Examples
+
+```rust
+use vtc::{Clip, EventList, TimecodeRange, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F29_97_DF).unwrap();
+
+let events = EventList::new(
+    rates::F29_97_DF,
+    vec![
+        Clip::new("A001", range("14:00:00;00", "14:00:10;00"), range("01:00:00;00", "01:00:10;00")),
+        Clip::new("A002", range("15:00:00;00", "15:00:10;00"), range("01:00:10;00", "01:00:20;00")),
+    ],
+)
+.unwrap();
+
+let insert = Clip::new("B001", range("09:00:00;00", "09:00:02;00"), range("01:00:05;00", "01:00:07;00"));
+let edit = events.insert(insert).unwrap();
+
+let records: Vec<String> = edit.events.events().iter().map(|e| e.record().to_string()).collect();
+assert_eq!(
+    vec![
+        "01:00:00;00 - 01:00:05;00",
+        "01:00:05;00 - 01:00:07;00",
+        "01:00:07;00 - 01:00:12;00",
+        "01:00:12;00 - 01:00:22;00",
+    ],
+    records
+);
+assert_eq!(3, edit.moved.len());
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventList {
+    rate: Framerate,
+    events: Vec<Clip>,
+}
+
+/// An edited [EventList], returned by each edit of an [EventList].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edit {
+    /// The event list after the edit.
+    pub events: EventList,
+    /// The events of the original list which the edit moved, trimmed or removed, in original
+    /// order. An event split in two by the edit is listed once for each part.
+    pub moved: Vec<MovedEvent>,
+}
+
+/// An event an edit moved, trimmed or removed, returned in [Edit::moved].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MovedEvent {
+    /// The index of the event in the original list.
+    pub from: usize,
+    /// The index of the event in the edited list, or [None] if it was removed.
+    pub to: Option<usize>,
+    /// The event before the edit.
+    pub before: Clip,
+    /// The event after the edit, or [None] if it was removed.
+    pub after: Option<Clip>,
+}
+
+impl EventList {
+    /// Creates a new [EventList] at the record rate `rate`. Events are sorted by record in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if the record range of any event is not at
+    /// `rate`, or [TimecodeError::Overlap] if two events overlap.
+    pub fn new(rate: Framerate, mut events: Vec<Clip>) -> Result<Self, TimecodeError> {
+        for event in &events {
+            TimecodeError::check_rate(event.record().rate(), rate)?;
+        }
+
+        events.sort_by_key(|event| (event.record().start(), event.record().end()));
+        for pair in events.windows(2) {
+            if pair[0].record().overlaps(&pair[1].record()) {
+                return Err(TimecodeError::Overlap {
+                    first: pair[0].record().start(),
+                    second: pair[1].record().start(),
+                });
+            }
+        }
+
+        Ok(EventList { rate, events })
+    }
+
+    /// Returns the record rate of the list.
+    pub fn rate(&self) -> Framerate {
+        self.rate
+    }
+
+    /// Returns the events, sorted by record in.
+    pub fn events(&self) -> &[Clip] {
+        &self.events
+    }
+
+    /// Inserts `clip` at its record in, splitting any event it lands inside and rippling every
+    /// later event by the clip's duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `clip` is not at the record rate.
+    pub fn insert(&self, clip: Clip) -> Result<Edit, TimecodeError> {
+        TimecodeError::check_rate(clip.record().rate(), self.rate)?;
+        let at = clip.record().start();
+        let duration = clip.record().duration();
+
+        let mut pieces = Vec::new();
+        for (index, event) in self.events.iter().enumerate() {
+            let record = event.record();
+            if record.end() <= at {
+                pieces.push((Some(index), event.clone()));
+            } else if record.start() >= at {
+                pieces.push((Some(index), shift(event, duration)));
+            } else {
+                pieces.push((Some(index), reframe(event, record.start(), at)?));
+                let tail = reframe(event, at, record.end())?;
+                pieces.push((Some(index), shift(&tail, duration)));
+            }
+        }
+        pieces.push((None, clip));
+
+        self.edit(pieces)
+    }
+
+    /// Cuts `clip` in over its record range, trimming or splitting the events under it.
+    /// Nothing else moves.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `clip` is not at the record rate.
+    pub fn overwrite(&self, clip: Clip) -> Result<Edit, TimecodeError> {
+        TimecodeError::check_rate(clip.record().rate(), self.rate)?;
+        let mut pieces = self.remove(&clip.record(), false)?;
+        pieces.push((None, clip));
+        self.edit(pieces)
+    }
+
+    /// Removes the material in `range`, leaving a gap. Nothing else moves.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `range` is not at the record rate.
+    pub fn lift(&self, range: &TimecodeRange) -> Result<Edit, TimecodeError> {
+        TimecodeError::check_rate(range.rate(), self.rate)?;
+        let pieces = self.remove(range, false)?;
+        self.edit(pieces)
+    }
+
+    /// Removes the material in `range` and ripples every later event back to close the gap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `range` is not at the record rate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{Clip, EventList, TimecodeRange, rates};
+    ///
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+    ///
+    /// let events = EventList::new(
+    ///     rates::F24,
+    ///     vec![
+    ///         Clip::new("A001", range("14:00:00:00", "14:00:10:00"), range("01:00:00:00", "01:00:10:00")),
+    ///         Clip::new("A002", range("15:00:00:00", "15:00:10:00"), range("01:00:10:00", "01:00:20:00")),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let edit = events.extract(&range("01:00:08:00", "01:00:12:00")).unwrap();
+    /// let first = &edit.events.events()[0];
+    /// let second = &edit.events.events()[1];
+    ///
+    /// assert_eq!("01:00:00:00 - 01:00:08:00", first.record().to_string());
+    /// assert_eq!("01:00:08:00 - 01:00:16:00", second.record().to_string());
+    /// assert_eq!("15:00:02:00 - 15:00:10:00", second.source().to_string());
+    /// ```
+    pub fn extract(&self, range: &TimecodeRange) -> Result<Edit, TimecodeError> {
+        TimecodeError::check_rate(range.rate(), self.rate)?;
+        let pieces = self.remove(range, true)?;
+        self.edit(pieces)
+    }
+
+    /// Slips the source of event `index` by `offset`, showing earlier or later source frames
+    /// in the same record range. `offset` is counted in frames of the event's source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::MissingEvent] if there is no event `index`.
+    pub fn slip(&self, index: usize, offset: Timecode) -> Result<Edit, TimecodeError> {
+        let event = self.event(index)?;
+        let source = event.source();
+        let offset = offset.rebase(source.rate());
+        let slipped = Clip::new(
+            event.reel(),
+            TimecodeRange::new(source.start() + offset, source.end() + offset)?,
+            event.record(),
+        )
+        .with_speed(event.speed());
+
+        let mut pieces = self.unchanged();
+        pieces[index].1 = slipped;
+        self.edit(pieces)
+    }
+
+    /// Slides event `index` along the record by `offset`, keeping its source. The event
+    /// before it is lengthened or shortened at its tail, and the event after it at its head,
+    /// so the rest of the list does not move. Events with a gap on either side slide into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::MissingEvent] if there is no event `index`,
+    /// [TimecodeError::RateMismatch] if `offset` is not at the rate of the list,
+    /// [TimecodeError::EndBeforeStart] if the slide would leave a neighbouring event with a
+    /// negative length, or [TimecodeError::Overlap] if it would overlap an event beyond it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{Clip, EventList, Timecode, TimecodeRange, rates};
+    ///
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+    ///
+    /// let events = EventList::new(
+    ///     rates::F24,
+    ///     vec![
+    ///         Clip::new("A001", range("14:00:00:00", "14:00:10:00"), range("01:00:00:00", "01:00:10:00")),
+    ///         Clip::new("A002", range("15:00:00:00", "15:00:02:00"), range("01:00:10:00", "01:00:12:00")),
+    ///         Clip::new("A003", range("16:00:00:00", "16:00:10:00"), range("01:00:12:00", "01:00:22:00")),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let offset = Timecode::with_frames(12, rates::F24).unwrap();
+    /// let edit = events.slide(1, offset).unwrap();
+    /// let records: Vec<String> = edit.events.events().iter().map(|e| e.record().to_string()).collect();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "01:00:00:00 - 01:00:10:12",
+    ///         "01:00:10:12 - 01:00:12:12",
+    ///         "01:00:12:12 - 01:00:22:00",
+    ///     ],
+    ///     records
+    /// );
+    /// ```
+    pub fn slide(&self, index: usize, offset: Timecode) -> Result<Edit, TimecodeError> {
+        let event = self.event(index)?;
+        TimecodeError::check_rate(offset.rate(), self.rate)?;
+        let slid = shift(event, offset);
+
+        let mut pieces = self.unchanged();
+        pieces[index].1 = slid.clone();
+
+        if index > 0 {
+            let previous = &self.events[index - 1];
+            if previous.record().end() == event.record().start() {
+                let end = slid.record().start();
+                pieces[index - 1].1 = reframe(previous, previous.record().start(), end)?;
+            } else if slid.record().start() < previous.record().end() {
+                return Err(TimecodeError::Overlap {
+                    first: previous.record().start(),
+                    second: slid.record().start(),
+                });
+            }
+        }
+
+        if let Some(next) = self.events.get(index + 1) {
+            if next.record().start() == event.record().end() {
+                let start = slid.record().end();
+                pieces[index + 1].1 = reframe(next, start, next.record().end())?;
+            } else if slid.record().end() > next.record().start() {
+                return Err(TimecodeError::Overlap {
+                    first: slid.record().start(),
+                    second: next.record().start(),
+                });
+            }
+        }
+
+        self.edit(pieces)
+    }
+
+    /// Returns event `index`.
+    fn event(&self, index: usize) -> Result<&Clip, TimecodeError> {
+        self.events.get(index).ok_or(TimecodeError::MissingEvent {
+            index,
+            count: self.events.len(),
+        })
+    }
+
+    /// Returns every event, unchanged, tagged with its index.
+    fn unchanged(&self) -> Vec<(Option<usize>, Clip)> {
+        self.events
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, event)| (Some(index), event))
+            .collect()
+    }
+
+    /// Returns the events with the material in `range` removed, tagged with their index.
+    /// Material after `range` is rippled back by its duration if `ripple` is set.
+    fn remove(
+        &self,
+        range: &TimecodeRange,
+        ripple: bool,
+    ) -> Result<Vec<(Option<usize>, Clip)>, TimecodeError> {
+        let back = if ripple {
+            -range.duration()
+        } else {
+            Timecode::with_i64_frames(0, self.rate)
+        };
+
+        let mut pieces = Vec::new();
+        for (index, event) in self.events.iter().enumerate() {
+            let record = event.record();
+            if record.end() <= range.start() {
+                pieces.push((Some(index), event.clone()));
+                continue;
+            }
+            if record.start() >= range.end() {
+                pieces.push((Some(index), shift(event, back)));
+                continue;
+            }
+
+            if record.start() < range.start() {
+                pieces.push((Some(index), reframe(event, record.start(), range.start())?));
+            }
+            if record.end() > range.end() {
+                let tail = reframe(event, range.end(), record.end())?;
+                pieces.push((Some(index), shift(&tail, back)));
+            }
+        }
+
+        Ok(pieces)
+    }
+
+    /// Sorts `pieces` into the edited list and records which original events changed.
+    fn edit(&self, mut pieces: Vec<(Option<usize>, Clip)>) -> Result<Edit, TimecodeError> {
+        pieces.retain(|(_, event)| !event.record().is_empty());
+        pieces.sort_by_key(|(_, event)| (event.record().start(), event.record().end()));
+
+        let mut moved = Vec::new();
+        for (to, (from, after)) in pieces.iter().enumerate() {
+            if let Some(from) = from {
+                let before = &self.events[*from];
+                if before != after {
+                    moved.push(MovedEvent {
+                        from: *from,
+                        to: Some(to),
+                        before: before.clone(),
+                        after: Some(after.clone()),
+                    });
+                }
+            }
+        }
+
+        for (from, before) in self.events.iter().enumerate() {
+            if !pieces.iter().any(|(index, _)| *index == Some(from)) {
+                moved.push(MovedEvent {
+                    from,
+                    to: None,
+                    before: before.clone(),
+                    after: None,
+                });
+            }
+        }
+        moved.sort_by_key(|event| (event.from, event.to));
+
+        let events = pieces.into_iter().map(|(_, event)| event).collect();
+        Ok(Edit {
+            events: EventList::new(self.rate, events)?,
+            moved,
+        })
+    }
+}
+
+/// Returns `event` moved along the record by `offset`, keeping its source.
+fn shift(event: &Clip, offset: Timecode) -> Clip {
+    let record = event.record();
+    // Shifting both ends by the same amount keeps the range valid.
+    let record = TimecodeRange::new(record.start() + offset, record.end() + offset).unwrap();
+    Clip::new(event.reel(), event.source(), record).with_speed(event.speed())
+}
+
+/// Returns `event` with its record range moved to `start` up to `end`, trimming or extending
+/// its source to match. Ends which do not move keep their exact source frame.
+fn reframe(event: &Clip, start: Timecode, end: Timecode) -> Result<Clip, TimecodeError> {
+    let record = event.record();
+    let source = event.source();
+
+    let source_at = |tc: Timecode| {
+        let offset = (tc - record.start()).frames();
+        let source_offset = (Rational64::from_integer(offset) * event.speed())
+            .floor()
+            .to_integer();
+        source.start() + Timecode::with_i64_frames(source_offset, source.rate())
+    };
+
+    let source_start = if start == record.start() {
+        source.start()
+    } else {
+        source_at(start)
+    };
+    let source_end = if end == record.end() {
+        source.end()
+    } else {
+        source_at(end)
+    };
+
+    Ok(Clip::new(
+        event.reel(),
+        TimecodeRange::new(source_start, source_end)?,
+        TimecodeRange::new(start, end)?,
+    )
+    .with_speed(event.speed()))
+}
@@ -0,0 +1,424 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::{range, range_at, tc, tc_at};
+    use crate::{rates, Clip, Edit, EventList, Timecode, TimecodeError, TimecodeRange};
+    use rstest::rstest;
+
+    fn clip(reel: &str, source: (&str, &str), record: (&str, &str)) -> Clip {
+        Clip::new(reel, range(source.0, source.1), range(record.0, record.1))
+    }
+
+    /// A001 01:00:00:00-01:00:10:00, a gap, then A002 01:00:12:00-01:00:20:00 and A003
+    /// 01:00:20:00-01:00:30:00.
+    fn events() -> EventList {
+        EventList::new(
+            rates::F24,
+            vec![
+                clip(
+                    "A002",
+                    ("15:00:00:00", "15:00:08:00"),
+                    ("01:00:12:00", "01:00:20:00"),
+                ),
+                clip(
+                    "A001",
+                    ("14:00:00:00", "14:00:10:00"),
+                    ("01:00:00:00", "01:00:10:00"),
+                ),
+                clip(
+                    "A003",
+                    ("16:00:00:00", "16:00:10:00"),
+                    ("01:00:20:00", "01:00:30:00"),
+                ),
+            ],
+        )
+        .unwrap()
+    }
+
+    /// Summarizes each event as "reel source record".
+    fn summary(events: &EventList) -> Vec<String> {
+        events
+            .events()
+            .iter()
+            .map(|event| format!("{} {} {}", event.reel(), event.source(), event.record()))
+            .collect()
+    }
+
+    fn moved(edit: &Edit) -> Vec<(usize, Option<usize>)> {
+        edit.moved
+            .iter()
+            .map(|event| (event.from, event.to))
+            .collect()
+    }
+
+    #[test]
+    fn test_event_list_new() {
+        let events = events();
+        assert_eq!(rates::F24, events.rate());
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:12:00 - 01:00:20:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:20:00 - 01:00:30:00",
+            ],
+            summary(&events)
+        );
+    }
+
+    #[rstest]
+    #[case::overlap(
+        vec![
+            clip("A001", ("14:00:00:00", "14:00:10:00"), ("01:00:00:00", "01:00:10:00")),
+            clip("A002", ("15:00:00:00", "15:00:10:00"), ("01:00:09:00", "01:00:19:00")),
+        ],
+        TimecodeError::Overlap {
+            first: tc("01:00:00:00"),
+            second: tc("01:00:09:00"),
+        },
+    )]
+    #[case::rate(
+        vec![Clip::new(
+            "A001",
+            range("14:00:00:00", "14:00:10:00"),
+            range_at("01:00:00:00", "01:00:10:00", rates::F25),
+        )],
+        TimecodeError::RateMismatch {
+            found: rates::F25,
+            expected: rates::F24,
+        },
+    )]
+    fn test_event_list_new_errors(#[case] events: Vec<Clip>, #[case] expected: TimecodeError) {
+        let err = EventList::new(rates::F24, events).unwrap_err();
+        assert_eq!(expected, err);
+    }
+
+    #[test]
+    fn test_insert_split() {
+        let edit = events()
+            .insert(clip(
+                "B001",
+                ("09:00:00:00", "09:00:02:00"),
+                ("01:00:04:00", "01:00:06:00"),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:04:00 01:00:00:00 - 01:00:04:00",
+                "B001 09:00:00:00 - 09:00:02:00 01:00:04:00 - 01:00:06:00",
+                "A001 14:00:04:00 - 14:00:10:00 01:00:06:00 - 01:00:12:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:14:00 - 01:00:22:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:22:00 - 01:00:32:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(
+            vec![(0, Some(0)), (0, Some(2)), (1, Some(3)), (2, Some(4))],
+            moved(&edit)
+        );
+    }
+
+    #[test]
+    fn test_insert_at_cut() {
+        let edit = events()
+            .insert(clip(
+                "B001",
+                ("09:00:00:00", "09:00:02:00"),
+                ("01:00:20:00", "01:00:22:00"),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:12:00 - 01:00:20:00",
+                "B001 09:00:00:00 - 09:00:02:00 01:00:20:00 - 01:00:22:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:22:00 - 01:00:32:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(2, Some(3))], moved(&edit));
+    }
+
+    #[test]
+    fn test_overwrite() {
+        let edit = events()
+            .overwrite(clip(
+                "B001",
+                ("09:00:00:00", "09:00:04:00"),
+                ("01:00:08:00", "01:00:14:00"),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:08:00 01:00:00:00 - 01:00:08:00",
+                "B001 09:00:00:00 - 09:00:04:00 01:00:08:00 - 01:00:14:00",
+                "A002 15:00:02:00 - 15:00:08:00 01:00:14:00 - 01:00:20:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:20:00 - 01:00:30:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(0, Some(0)), (1, Some(2))], moved(&edit));
+    }
+
+    #[test]
+    fn test_overwrite_covers_event() {
+        let edit = events()
+            .overwrite(clip(
+                "B001",
+                ("09:00:00:00", "09:00:20:00"),
+                ("01:00:11:00", "01:00:21:00"),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "B001 09:00:00:00 - 09:00:20:00 01:00:11:00 - 01:00:21:00",
+                "A003 16:00:01:00 - 16:00:10:00 01:00:21:00 - 01:00:30:00",
+            ],
+            summary(&edit.events)
+        );
+
+        assert_eq!(vec![(1, None), (2, Some(2))], moved(&edit));
+        assert_eq!(None, edit.moved[0].after);
+        assert_eq!("A002", edit.moved[0].before.reel());
+    }
+
+    #[test]
+    fn test_lift() {
+        let edit = events().lift(&range("01:00:18:00", "01:00:22:00")).unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:06:00 01:00:12:00 - 01:00:18:00",
+                "A003 16:00:02:00 - 16:00:10:00 01:00:22:00 - 01:00:30:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(1, Some(1)), (2, Some(2))], moved(&edit));
+    }
+
+    #[test]
+    fn test_lift_inside_event() {
+        let edit = events().lift(&range("01:00:02:00", "01:00:04:00")).unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:02:00 01:00:00:00 - 01:00:02:00",
+                "A001 14:00:04:00 - 14:00:10:00 01:00:04:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:12:00 - 01:00:20:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:20:00 - 01:00:30:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(0, Some(0)), (0, Some(1))], moved(&edit));
+    }
+
+    #[test]
+    fn test_extract() {
+        let edit = events()
+            .extract(&range("01:00:08:00", "01:00:14:00"))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:08:00 01:00:00:00 - 01:00:08:00",
+                "A002 15:00:02:00 - 15:00:08:00 01:00:08:00 - 01:00:14:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:14:00 - 01:00:24:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(0, Some(0)), (1, Some(1)), (2, Some(2))], moved(&edit));
+    }
+
+    #[test]
+    fn test_extract_gap() {
+        let edit = events()
+            .extract(&range("01:00:10:00", "01:00:12:00"))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:10:00 - 01:00:18:00",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:18:00 - 01:00:28:00",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(1, Some(1)), (2, Some(2))], moved(&edit));
+    }
+
+    #[test]
+    fn test_slip() {
+        let offset = -Timecode::with_frames(12, rates::F24).unwrap();
+        let edit = events().slip(1, offset).unwrap();
+
+        assert_eq!(
+            "A002 14:59:59:12 - 15:00:07:12 01:00:12:00 - 01:00:20:00",
+            summary(&edit.events)[1]
+        );
+        assert_eq!(vec![(1, Some(1))], moved(&edit));
+    }
+
+    #[test]
+    fn test_slide() {
+        let offset = -Timecode::with_frames(12, rates::F24).unwrap();
+        let edit = events().slide(2, offset).unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:07:12 01:00:12:00 - 01:00:19:12",
+                "A003 16:00:00:00 - 16:00:10:00 01:00:19:12 - 01:00:29:12",
+            ],
+            summary(&edit.events)
+        );
+        assert_eq!(vec![(1, Some(1)), (2, Some(2))], moved(&edit));
+    }
+
+    #[test]
+    fn test_slide_into_gap() {
+        let offset = -Timecode::with_frames(24, rates::F24).unwrap();
+        let edit = events().slide(1, offset).unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00:00 - 14:00:10:00 01:00:00:00 - 01:00:10:00",
+                "A002 15:00:00:00 - 15:00:08:00 01:00:11:00 - 01:00:19:00",
+                "A003 15:59:59:00 - 16:00:10:00 01:00:19:00 - 01:00:30:00",
+            ],
+            summary(&edit.events)
+        );
+    }
+
+    #[rstest]
+    #[case::overlap_gap(
+        1,
+        -72,
+        TimecodeError::Overlap {
+            first: tc("01:00:00:00"),
+            second: tc("01:00:09:00"),
+        },
+    )]
+    #[case::missing(3, 1, TimecodeError::MissingEvent { index: 3, count: 3 })]
+    fn test_slide_errors(
+        #[case] index: usize,
+        #[case] frames: i64,
+        #[case] expected: TimecodeError,
+    ) {
+        let offset = Timecode::with_i64_frames(frames, rates::F24);
+        let err = events().slide(index, offset).unwrap_err();
+        assert_eq!(expected, err);
+    }
+
+    #[test]
+    fn test_slide_past_next() {
+        let offset = Timecode::with_i64_frames(10 * 24 + 1, rates::F24);
+        let err = events().slide(1, offset).unwrap_err();
+
+        // The next event would be trimmed past the end of its source.
+        assert_eq!(
+            TimecodeError::EndBeforeStart {
+                start: tc("16:00:10:01"),
+                end: tc("16:00:10:00"),
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_edit_rate_mismatch() {
+        let events = events();
+
+        let err = events
+            .lift(&range_at("01:00:00:00", "01:00:01:00", rates::F25))
+            .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+
+        let offset = Timecode::with_i64_frames(12, rates::F25);
+        let err = events.slide(1, offset).unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+
+        let err = events
+            .insert(Clip::new(
+                "B001",
+                range("09:00:00:00", "09:00:01:00"),
+                range_at("01:00:00:00", "01:00:01:00", rates::F25),
+            ))
+            .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_edit_drop_frame() {
+        let rate = rates::F29_97_DF;
+        let events = EventList::new(
+            rate,
+            vec![Clip::new(
+                "A001",
+                range_at("14:00:00;00", "14:10:00;00", rate),
+                range_at("01:00:00;00", "01:10:00;00", rate),
+            )],
+        )
+        .unwrap();
+
+        // 300 frames inserted just before a dropped minute boundary.
+        let inserted = Clip::new(
+            "B001",
+            range_at("09:00:00;00", "09:00:10;02", rate),
+            TimecodeRange::with_duration(
+                tc_at("01:00:59;20", rate),
+                Timecode::with_i64_frames(300, rate),
+            )
+            .unwrap(),
+        );
+        let edit = events.insert(inserted).unwrap();
+
+        assert_eq!(
+            vec![
+                "A001 14:00:00;00 - 14:00:59;20 01:00:00;00 - 01:00:59;20",
+                "B001 09:00:00;00 - 09:00:10;02 01:00:59;20 - 01:01:09;22",
+                "A001 14:00:59;20 - 14:10:00;00 01:01:09;22 - 01:10:10;00",
+            ],
+            summary(&edit.events)
+        );
+
+        let extracted = edit
+            .events
+            .extract(&edit.events.events()[1].record())
+            .unwrap();
+        let records: Vec<TimecodeRange> = extracted
+            .events
+            .events()
+            .iter()
+            .map(|event| event.record())
+            .collect();
+        assert_eq!(
+            vec![
+                range_at("01:00:00;00", "01:00:59;20", rate),
+                range_at("01:00:59;20", "01:10:00;00", rate),
+            ],
+            records
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{FilmFormat, Framerate, FramesSource, FramesSourceResult, SignStyle};
+
+#[allow(unused)]
+// we need this here for the doc links, but clippy doesnt like that it isn't being used in code.
+use crate::{FeetFramesStr, Timecode};
+
+/// How a [FeetFramesFormat] shows the perf marker of formats which have one, like 3-perf.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PerfMarkerStyle {
+    /// The marker is appended as a number after a period, as on Avid cut lists: `13+01.1`.
+    #[default]
+    Number,
+    /// The marker is appended as that many dot symbols, as on Evertz window burns: `13+01•`.
+    /// A marker of `0` shows no dots.
+    Dots,
+    /// The perf marker is not shown.
+    Hidden,
+}
+
+/**
+Holds the individual pieces of a feet and frames footage, for lab paperwork and custom
+formatting.
+
+Returned by [Timecode::footage] and [FeetFramesStr::parse].
+
+# Examples
+
+```rust
+use vtc::{FeetFrames, FilmFormat, Timecode, rates};
+
+let tc = Timecode::with_frames(-45, rates::F24).unwrap();
+let footage = tc.footage(FilmFormat::FF35mm3perf);
+
+assert!(footage.negative());
+assert_eq!(2, footage.feet());
+assert_eq!(3, footage.frames());
+assert_eq!(Some(2), footage.perf_marker());
+assert_eq!(135, footage.total_perfs());
+assert_eq!(-45, footage.frame_count());
+assert_eq!("-2+03.2", footage.to_string());
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FeetFrames {
+    negative: bool,
+    feet: i64,
+    frames: i64,
+    perf_marker: Option<i64>,
+    total_perfs: i64,
+    format: FilmFormat,
+}
+
+impl FeetFrames {
+    /// Returns the footage of `frame_count` frames in `format`.
+    pub(crate) fn with_frames(frame_count: i64, format: FilmFormat) -> Self {
+        let total_frames = frame_count.abs();
+        let total_perfs = total_frames * format.perfs_per_frame();
+
+        // The foot a frame lies in, by convention, is where the frame _ends_,
+        // not where it begins. If we add format.perfs_per_frame() -1 to the perf
+        // count, we get the last perf of the frame we are on.
+        //
+        // Once we have that, the foot we are on can be obtained with integer
+        // division.
+        let last_perf = total_perfs + format.perfs_per_frame() - 1;
+        let feet = last_perf / format.perfs_per_foot();
+        let frames = (last_perf % format.perfs_per_foot()) / format.perfs_per_frame();
+
+        // The perf marker is simply the modulo of the footage count and the
+        // number of feet in a footage modulus.
+        //
+        // For almost all formats this is always zero, but not in the case of
+        // 3-perf.
+        //
+        // The meaning of the perf marker is obscure but it indicates the
+        // position of the Kodak KeyKode "black dot," a dot that appears
+        // every 32 perfs (6 inches) and witnesses an associated KeyKode latent
+        // edge number. On an Avid the perf marker is represented as a number,
+        // However on window dubs from Evertz TCGs this would appear as a visual
+        // symbol,
+        let perf_marker = if format.allows_perf_field() {
+            Some(feet % format.footage_modulus_footage_count())
+        } else {
+            None
+        };
+
+        FeetFrames {
+            negative: frame_count < 0,
+            feet,
+            frames,
+            perf_marker,
+            total_perfs,
+            format,
+        }
+    }
+
+    /// Returns whether the footage is a negative value.
+    pub fn negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the feet place value.
+    pub fn feet(&self) -> i64 {
+        self.feet
+    }
+
+    /// Returns the frames place value, counted from the first frame which ends in the foot.
+    pub fn frames(&self) -> i64 {
+        self.frames
+    }
+
+    /// Returns the position of the Kodak KeyKode "black dot" for formats where frames do not
+    /// fit evenly into a foot, like 3-perf. [None] for formats without a perf field.
+    pub fn perf_marker(&self) -> Option<i64> {
+        self.perf_marker
+    }
+
+    /// Returns the number of perfs from zero to the start of the frame.
+    pub fn total_perfs(&self) -> i64 {
+        self.total_perfs
+    }
+
+    /// Returns the film format of the footage.
+    pub fn film_format(&self) -> FilmFormat {
+        self.format
+    }
+
+    /// Returns the signed number of frames the footage represents.
+    pub fn frame_count(&self) -> i64 {
+        let frames = self.total_perfs / self.format.perfs_per_frame();
+        if self.negative {
+            -frames
+        } else {
+            frames
+        }
+    }
+
+    /// Formats the footage using `format`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{FeetFramesFormat, FilmFormat, PerfMarkerStyle, SignStyle, Timecode, rates};
+    ///
+    /// let tc = Timecode::with_frames(25, rates::F24).unwrap();
+    /// let footage = tc.footage(FilmFormat::FF35mm3perf);
+    ///
+    /// assert_eq!("1+04.1", footage.format(&FeetFramesFormat::default()));
+    ///
+    /// let evertz = FeetFramesFormat::default().perf_marker(PerfMarkerStyle::Dots);
+    /// assert_eq!("1+04•", footage.format(&evertz));
+    ///
+    /// let signed = FeetFramesFormat::default()
+    ///     .perf_marker(PerfMarkerStyle::Hidden)
+    ///     .frame_digits(1)
+    ///     .sign(SignStyle::Always);
+    /// assert_eq!("+1+4", footage.format(&signed));
+    /// ```
+    pub fn format(&self, format: &FeetFramesFormat) -> String {
+        format.apply(self)
+    }
+}
+
+impl Display for FeetFrames {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(&FeetFramesFormat::default()))
+    }
+}
+
+impl FramesSource for FeetFrames {
+    fn to_frames(&self, _rate: Framerate) -> FramesSourceResult {
+        Ok(self.frame_count())
+    }
+}
+
+/**
+Builds a custom feet and frames format, for use with [FeetFrames::format].
+
+The default [FeetFramesFormat] matches [Timecode::feet_and_frames].
+
+# Examples
+
+```rust
+use vtc::{FeetFramesFormat, FilmFormat, SignStyle, Timecode, rates};
+
+let tc = Timecode::with_frames(-40, rates::F24).unwrap();
+let footage = tc.footage(FilmFormat::FF35mm4perf);
+
+assert_eq!("-2+08", footage.format(&FeetFramesFormat::default()));
+
+let format = FeetFramesFormat::default()
+    .frame_digits(3)
+    .sign(SignStyle::Parentheses);
+assert_eq!("(2+008)", footage.format(&format));
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeetFramesFormat {
+    frame_digits: usize,
+    perf_marker: PerfMarkerStyle,
+    perf_marker_symbol: char,
+    sign: SignStyle,
+}
+
+impl Default for FeetFramesFormat {
+    fn default() -> Self {
+        FeetFramesFormat {
+            frame_digits: 2,
+            perf_marker: PerfMarkerStyle::Number,
+            perf_marker_symbol: '•',
+            sign: SignStyle::NegativeOnly,
+        }
+    }
+}
+
+impl FeetFramesFormat {
+    /// Sets the number of digits the frames are zero-padded to. Defaults to `2`.
+    pub fn frame_digits(mut self, digits: usize) -> Self {
+        self.frame_digits = digits;
+        self
+    }
+
+    /// Sets how the perf marker is shown. Defaults to [PerfMarkerStyle::Number].
+    pub fn perf_marker(mut self, style: PerfMarkerStyle) -> Self {
+        self.perf_marker = style;
+        self
+    }
+
+    /// Sets the symbol repeated for [PerfMarkerStyle::Dots]. Defaults to `•`.
+    pub fn perf_marker_symbol(mut self, symbol: char) -> Self {
+        self.perf_marker_symbol = symbol;
+        self
+    }
+
+    /// Sets how the sign of the footage is shown. Defaults to [SignStyle::NegativeOnly].
+    pub fn sign(mut self, sign: SignStyle) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Formats `footage` in this format. See [FeetFrames::format].
+    pub(crate) fn apply(&self, footage: &FeetFrames) -> String {
+        let mut formatted = format!(
+            "{}+{:0width$}",
+            footage.feet,
+            footage.frames,
+            width = self.frame_digits
+        );
+
+        match (self.perf_marker, footage.perf_marker) {
+            (PerfMarkerStyle::Number, Some(marker)) => {
+                formatted.push_str(&format!(".{}", marker));
+            }
+            (PerfMarkerStyle::Dots, Some(marker)) => {
+                formatted.extend((0..marker).map(|_| self.perf_marker_symbol));
+            }
+            _ => {}
+        }
+
+        let is_zero = footage.total_perfs == 0;
+        match (self.sign, footage.negative) {
+            (SignStyle::NegativeOnly, true) | (SignStyle::Always, true) => {
+                format!("-{}", formatted)
+            }
+            (SignStyle::Always, false) if !is_zero => format!("+{}", formatted),
+            (SignStyle::Parentheses, true) => format!("({})", formatted),
+            _ => formatted,
+        }
+    }
+}
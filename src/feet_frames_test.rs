@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, FeetFrames, FeetFramesFormat, FeetFramesStr, FilmFormat, PerfMarkerStyle, SignStyle,
+        Timecode, TimecodeParseError,
+    };
+    use rstest::rstest;
+
+    /// Returns the sign, feet, frames, perf marker and total perfs of `footage`.
+    fn pieces(footage: &FeetFrames) -> (bool, i64, i64, Option<i64>, i64) {
+        (
+            footage.negative(),
+            footage.feet(),
+            footage.frames(),
+            footage.perf_marker(),
+            footage.total_perfs(),
+        )
+    }
+
+    /// tests the pieces of a footage for each format.
+    #[rstest]
+    #[case::four_perf(FilmFormat::FF35mm4perf, 40, (false, 2, 8, None, 160))]
+    #[case::three_perf(FilmFormat::FF35mm3perf, 45, (false, 2, 3, Some(2), 135))]
+    #[case::three_perf_negative(FilmFormat::FF35mm3perf, -21, (true, 1, 0, Some(1), 63))]
+    #[case::sixteen(FilmFormat::FF16mm, 45, (false, 2, 5, None, 45))]
+    #[case::zero(FilmFormat::FF35mm4perf, 0, (false, 0, 0, None, 0))]
+    fn test_footage(
+        #[case] format: FilmFormat,
+        #[case] frames: i64,
+        #[case] expected: (bool, i64, i64, Option<i64>, i64),
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(frames, rates::F24)?;
+        let footage = tc.footage(format);
+
+        assert_eq!(expected, pieces(&footage), "footage");
+        assert_eq!(format, footage.film_format(), "film format");
+        assert_eq!(frames, footage.frame_count(), "frame count");
+        assert_eq!(tc.feet_and_frames(format), footage.to_string(), "to string");
+
+        let parsed = FeetFramesStr::new(&footage.to_string(), format).parse()?;
+        assert_eq!(footage, parsed, "parsed");
+
+        let from_footage = Timecode::with_frames(footage, rates::F24)?;
+        assert_eq!(frames, from_footage.frames(), "timecode from footage");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_footage_parse_normalizes() -> Result<(), TimecodeParseError> {
+        let parsed = FeetFramesStr::new("1+20", FilmFormat::FF35mm4perf).parse()?;
+        assert_eq!(2, parsed.feet());
+        assert_eq!(4, parsed.frames());
+        assert_eq!("2+04", parsed.to_string());
+
+        Ok(())
+    }
+
+    /// tests that every perf field written by footage parses back to the same frame.
+    #[test]
+    fn test_footage_parse_perf_round_trip() -> Result<(), TimecodeParseError> {
+        for frames in -200..200 {
+            let footage = FeetFrames::with_frames(frames, FilmFormat::FF35mm3perf);
+            let input = footage.to_string();
+            let parsed = FeetFramesStr::new(&input, FilmFormat::FF35mm3perf).parse()?;
+            assert_eq!(footage, parsed, "{}", input);
+        }
+
+        Ok(())
+    }
+
+    /// tests that lenient parsing ignores a perf field which does not match the foot.
+    #[rstest]
+    #[case("12+01.1", "12+01.0")]
+    #[case("2+5.1", "2+5.2")]
+    #[case("-1+02.2", "-1+02.1")]
+    fn test_footage_lenient_perf_mismatch(
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(input, rates::F24)?;
+        let expected = Timecode::with_frames(expected, rates::F24)?;
+        assert_eq!(expected, tc);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::not_footage("01:00:00:00", FilmFormat::FF35mm4perf)]
+    #[case::perf_not_allowed("1+02.1", FilmFormat::FF35mm4perf)]
+    #[case::perf_mismatch("12+01.1", FilmFormat::FF35mm3perf)]
+    #[case::perf_mismatch_negative("-1+02.2", FilmFormat::FF35mm3perf)]
+    fn test_footage_parse_errors(#[case] input: &str, #[case] format: FilmFormat) {
+        let err = FeetFramesStr::new(input, format).parse().unwrap_err();
+        assert!(
+            matches!(err, TimecodeParseError::UnknownStrFormat(_)),
+            "{:?}",
+            err
+        );
+    }
+
+    /// tests formatting options.
+    #[rstest]
+    #[case::default(45, FilmFormat::FF35mm3perf, FeetFramesFormat::default(), "2+03.2")]
+    #[case::padded(
+        45,
+        FilmFormat::FF35mm3perf,
+        FeetFramesFormat::default().frame_digits(3),
+        "2+003.2"
+    )]
+    #[case::unpadded(
+        40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().frame_digits(0),
+        "2+8"
+    )]
+    #[case::dots(
+        45,
+        FilmFormat::FF35mm3perf,
+        FeetFramesFormat::default().perf_marker(PerfMarkerStyle::Dots),
+        "2+03••"
+    )]
+    #[case::dots_zero(
+        0,
+        FilmFormat::FF35mm3perf,
+        FeetFramesFormat::default().perf_marker(PerfMarkerStyle::Dots),
+        "0+00"
+    )]
+    #[case::dots_symbol(
+        45,
+        FilmFormat::FF35mm3perf,
+        FeetFramesFormat::default()
+            .perf_marker(PerfMarkerStyle::Dots)
+            .perf_marker_symbol('*'),
+        "2+03**"
+    )]
+    #[case::hidden(
+        45,
+        FilmFormat::FF35mm3perf,
+        FeetFramesFormat::default().perf_marker(PerfMarkerStyle::Hidden),
+        "2+03"
+    )]
+    #[case::no_marker_format(
+        40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().perf_marker(PerfMarkerStyle::Dots),
+        "2+08"
+    )]
+    #[case::sign_always(
+        40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().sign(SignStyle::Always),
+        "+2+08"
+    )]
+    #[case::sign_always_zero(
+        0,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().sign(SignStyle::Always),
+        "0+00"
+    )]
+    #[case::sign_always_negative(
+        -40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().sign(SignStyle::Always),
+        "-2+08"
+    )]
+    #[case::sign_parentheses(
+        -40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().sign(SignStyle::Parentheses),
+        "(2+08)"
+    )]
+    #[case::sign_never(
+        -40,
+        FilmFormat::FF35mm4perf,
+        FeetFramesFormat::default().sign(SignStyle::Never),
+        "2+08"
+    )]
+    fn test_footage_format(
+        #[case] frames: i64,
+        #[case] film_format: FilmFormat,
+        #[case] format: FeetFramesFormat,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let footage = Timecode::with_frames(frames, rates::F24)?.footage(film_format);
+        assert_eq!(expected, footage.format(&format));
+
+        Ok(())
+    }
+}
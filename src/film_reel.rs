@@ -0,0 +1,242 @@
+use crate::{FeetFrames, FilmFormat, Framerate, Timecode, TimecodeError, TimecodeRange};
+
+/// The length of the 35mm Academy leader from picture start to the first frame of action: 12
+/// feet of 35mm 4-perf film.
+const ACADEMY_LEADER_35MM_FRAMES: i64 = 192;
+
+/// The number of frames between the first frame of the motor cue and the end of picture: 8
+/// seconds at 24 fps.
+const MOTOR_CUE_FRAMES: i64 = 192;
+
+/// The number of frames between the first frame of the changeover cue and the end of picture:
+/// 1 second at 24 fps.
+const CHANGEOVER_CUE_FRAMES: i64 = 24;
+
+/**
+A reel of film holding one part of a continuous timeline, with a head leader before its first
+frame of action.
+
+Sound and lab departments count reel footage from the picture start frame at the head of the
+leader, so `0+00` is picture start and, with a 12-foot Academy leader, the first frame of action
+is at `12+00`. [FilmReel] converts between these reel-relative footages and the timecode of the
+continuous timeline.
+
+# Examples
+
+```rust
+use vtc::{FeetFramesStr, FilmFormat, FilmReel, Timecode, TimecodeRange, rates};
+
+let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+let end = Timecode::with_frames("01:20:00:00", rates::F24).unwrap();
+let picture = TimecodeRange::new(start, end).unwrap();
+let reel = FilmReel::new(picture, FilmFormat::FF35mm4perf);
+
+assert_eq!("00:59:52:00", reel.picture_start().timecode());
+assert_eq!("12+00", reel.footage(&start).unwrap().to_string());
+
+let footage = FeetFramesStr::new("100+00", FilmFormat::FF35mm4perf).parse().unwrap();
+let tc = reel.timecode(&footage);
+assert_eq!("01:00:58:16", tc.timecode());
+assert_eq!(footage, reel.footage(&tc).unwrap());
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FilmReel {
+    picture: TimecodeRange,
+    format: FilmFormat,
+    leader: Timecode,
+}
+
+impl FilmReel {
+    /// Creates a new [FilmReel] holding `picture` of the timeline, with the 35mm Academy
+    /// leader: 12 feet of 35mm 4-perf, which is 192 frames.
+    ///
+    /// The leader is 192 frames whatever `format` is. Reels in other formats, like 16mm, should
+    /// set the length of their own leader with [FilmReel::with_leader].
+    ///
+    /// # Arguments
+    ///
+    /// * `picture` - The timeline range from the first frame of action to the end of picture.
+    ///
+    /// * `format` - The film format footages are counted in.
+    pub fn new(picture: TimecodeRange, format: FilmFormat) -> Self {
+        FilmReel {
+            picture,
+            format,
+            leader: academy_leader_35mm(picture.rate()),
+        }
+    }
+
+    /// Returns the reel with a head leader of `leader` instead of the 35mm Academy leader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `leader` is not at the rate of the reel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{FeetFramesStr, FilmFormat, FilmReel, Timecode, TimecodeRange, rates};
+    ///
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let picture = TimecodeRange::new(start, start).unwrap();
+    ///
+    /// let leader = FeetFramesStr::new("20+00", FilmFormat::FF16mm);
+    /// let leader = Timecode::with_frames(leader, rates::F24).unwrap();
+    /// let reel = FilmReel::new(picture, FilmFormat::FF16mm)
+    ///     .with_leader(leader)
+    ///     .unwrap();
+    ///
+    /// assert_eq!("20+00", reel.footage(&start).unwrap().to_string());
+    /// ```
+    pub fn with_leader(mut self, leader: Timecode) -> Result<Self, TimecodeError> {
+        TimecodeError::check_rate(leader.rate(), self.picture.rate())?;
+        self.leader = leader;
+        Ok(self)
+    }
+
+    /// Returns the timeline range from the first frame of action to the end of picture.
+    pub fn picture(&self) -> TimecodeRange {
+        self.picture
+    }
+
+    /// Returns the film format footages are counted in.
+    pub fn format(&self) -> FilmFormat {
+        self.format
+    }
+
+    /// Returns the length of the head leader.
+    pub fn leader(&self) -> Timecode {
+        self.leader
+    }
+
+    /// Returns the timeline timecode the picture start frame at the head of the leader would
+    /// fall on.
+    pub fn picture_start(&self) -> Timecode {
+        self.picture.start() - self.leader
+    }
+
+    /// Returns the length of the reel, from picture start to the end of picture.
+    pub fn length(&self) -> Timecode {
+        self.leader + self.picture.duration()
+    }
+
+    /// Returns the reel-relative footage of the timeline timecode `tc` in the reel's format,
+    /// counted from picture start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `tc` is not at the rate of the reel.
+    pub fn footage(&self, tc: &Timecode) -> Result<FeetFrames, TimecodeError> {
+        TimecodeError::check_rate(tc.rate(), self.picture.rate())?;
+        Ok((*tc - self.picture_start()).footage(self.format))
+    }
+
+    /// Returns the timeline timecode of the reel-relative `footage`, counted from picture
+    /// start. Footage in a format other than the reel's is counted by its frames.
+    pub fn timecode(&self, footage: &FeetFrames) -> Timecode {
+        self.picture_start() + Timecode::with_i64_frames(footage.frame_count(), self.picture.rate())
+    }
+
+    /// Returns the timeline timecode of the first frame of the motor cue: 192 frames, or 8
+    /// seconds at 24 fps, before the end of picture.
+    pub fn motor_cue(&self) -> Timecode {
+        self.picture.end() - Timecode::with_i64_frames(MOTOR_CUE_FRAMES, self.picture.rate())
+    }
+
+    /// Returns the timeline timecode of the first frame of the changeover cue: 24 frames, or 1
+    /// second at 24 fps, before the end of picture.
+    pub fn changeover_cue(&self) -> Timecode {
+        self.picture.end() - Timecode::with_i64_frames(CHANGEOVER_CUE_FRAMES, self.picture.rate())
+    }
+
+    /// Splits `program` into reels no longer than `max_length`, including the 192-frame 35mm
+    /// Academy leader on each whatever `format` is.
+    ///
+    /// Each reel break is placed on the latest of `cuts` which fits on the reel, so reels
+    /// change over on a cut. If no cut fits, the reel is broken at its maximum length.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The timeline range to split into reels.
+    ///
+    /// * `format` - The film format of the reels.
+    ///
+    /// * `max_length` - The longest a reel may be, from picture start to the end of picture.
+    ///
+    /// * `cuts` - Timeline timecodes of cuts the reels may be broken on, in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::ReelTooShort] if `max_length` has no room for picture after the
+    /// leader, or [TimecodeError::RateMismatch] if `max_length` or any cut is at a different
+    /// rate than `program`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{FeetFramesStr, FilmFormat, FilmReel, Timecode, TimecodeRange, rates};
+    ///
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let end = Timecode::with_frames("01:40:00:00", rates::F24).unwrap();
+    /// let program = TimecodeRange::new(start, end).unwrap();
+    ///
+    /// let max_length = FeetFramesStr::new("2000+00", FilmFormat::FF35mm4perf);
+    /// let max_length = Timecode::with_frames(max_length, rates::F24).unwrap();
+    ///
+    /// let cuts = vec![
+    ///     Timecode::with_frames("01:20:00:00", rates::F24).unwrap(),
+    ///     Timecode::with_frames("01:21:00:00", rates::F24).unwrap(),
+    /// ];
+    ///
+    /// let reels = FilmReel::plan(program, FilmFormat::FF35mm4perf, max_length, &cuts).unwrap();
+    /// assert_eq!(2, reels.len());
+    /// assert_eq!("01:21:00:00", reels[0].picture().end().timecode());
+    /// assert_eq!("01:20:52:00", reels[0].motor_cue().timecode());
+    /// assert_eq!("01:20:59:00", reels[0].changeover_cue().timecode());
+    /// assert_eq!("01:40:00:00", reels[1].picture().end().timecode());
+    /// ```
+    pub fn plan(
+        program: TimecodeRange,
+        format: FilmFormat,
+        max_length: Timecode,
+        cuts: &[Timecode],
+    ) -> Result<Vec<FilmReel>, TimecodeError> {
+        let rate = program.rate();
+        TimecodeError::check_rate(max_length.rate(), rate)?;
+        for cut in cuts {
+            TimecodeError::check_rate(cut.rate(), rate)?;
+        }
+
+        let leader = academy_leader_35mm(rate);
+        let max_picture = max_length - leader;
+        if max_picture.frames() <= 0 {
+            return Err(TimecodeError::ReelTooShort { max_length, leader });
+        }
+
+        let mut reels = Vec::new();
+        let mut start = program.start();
+        while start < program.end() {
+            let limit = start + max_picture;
+            let end = if limit >= program.end() {
+                program.end()
+            } else {
+                cuts.iter()
+                    .copied()
+                    .filter(|cut| start < *cut && *cut <= limit)
+                    .max()
+                    .unwrap_or(limit)
+            };
+
+            reels.push(FilmReel::new(TimecodeRange::new(start, end)?, format));
+            start = end;
+        }
+
+        Ok(reels)
+    }
+}
+
+/// Returns the 35mm Academy leader at `rate`.
+fn academy_leader_35mm(rate: Framerate) -> Timecode {
+    Timecode::with_i64_frames(ACADEMY_LEADER_35MM_FRAMES, rate)
+}
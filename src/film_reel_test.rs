@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::{range_at, tc, tc_at};
+    use crate::{rates, FeetFramesStr, FilmFormat, FilmReel, Framerate, Timecode, TimecodeError};
+    use rstest::rstest;
+
+    /// tests reel-relative footage converts to and from timeline timecode.
+    #[rstest]
+    #[case::picture_start(rates::F24, FilmFormat::FF35mm4perf, "00:59:52:00", "0+00")]
+    #[case::ffoa(rates::F24, FilmFormat::FF35mm4perf, "01:00:00:00", "12+00")]
+    #[case::into_reel(rates::F24, FilmFormat::FF35mm4perf, "01:01:00:00", "102+00")]
+    #[case::in_leader(rates::F24, FilmFormat::FF35mm4perf, "00:59:55:00", "4+08")]
+    #[case::three_perf(rates::F24, FilmFormat::FF35mm3perf, "01:00:00:00", "9+00.0")]
+    #[case::ntsc(rates::F23_98, FilmFormat::FF35mm4perf, "01:00:00:00", "12+00")]
+    #[case::drop_frame(rates::F29_97_DF, FilmFormat::FF35mm4perf, "01:00:00;00", "12+00")]
+    fn test_film_reel_footage(
+        #[case] rate: Framerate,
+        #[case] format: FilmFormat,
+        #[case] timecode: &str,
+        #[case] footage: &str,
+    ) -> Result<(), TimecodeError> {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rate);
+        let reel = FilmReel::new(picture, format);
+
+        let found = reel.footage(&tc_at(timecode, rate))?;
+        assert_eq!(footage, found.to_string(), "footage");
+        assert_eq!(format, found.film_format(), "format");
+
+        let parsed = FeetFramesStr::new(footage, format).parse()?;
+        assert_eq!(timecode, reel.timecode(&parsed).timecode(), "timecode");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_film_reel_leader() -> Result<(), TimecodeError> {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rates::F24);
+        let reel = FilmReel::new(picture, FilmFormat::FF35mm4perf);
+
+        assert_eq!(192, reel.leader().frames());
+        assert_eq!("00:10:08:00", reel.length().timecode());
+
+        let leader = Timecode::with_frames(
+            FeetFramesStr::new("3+00", FilmFormat::FF35mm4perf),
+            rates::F24,
+        )?;
+        let reel = reel.with_leader(leader)?;
+
+        assert_eq!("00:59:58:00", reel.picture_start().timecode());
+        assert_eq!("3+00", reel.footage(&picture.start())?.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_film_reel_leader_16mm() -> Result<(), TimecodeError> {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rates::F24);
+
+        // The default leader is the same 192 frames in every format.
+        let reel = FilmReel::new(picture, FilmFormat::FF16mm);
+        assert_eq!(192, reel.leader().frames());
+        assert_eq!("9+12", reel.footage(&picture.start())?.to_string());
+
+        let leader =
+            Timecode::with_frames(FeetFramesStr::new("7+08", FilmFormat::FF16mm), rates::F24)?;
+        let reel = reel.with_leader(leader)?;
+        assert_eq!("7+08", reel.footage(&picture.start())?.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_film_reel_cues() {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rates::F24);
+        let reel = FilmReel::new(picture, FilmFormat::FF35mm4perf);
+
+        assert_eq!("01:09:52:00", reel.motor_cue().timecode());
+        assert_eq!("01:09:59:00", reel.changeover_cue().timecode());
+    }
+
+    #[test]
+    fn test_film_reel_other_format_footage() -> Result<(), TimecodeError> {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rates::F24);
+        let reel = FilmReel::new(picture, FilmFormat::FF35mm4perf);
+
+        // 240 frames of 16mm are 15 feet of 35mm 4-perf.
+        let footage = FeetFramesStr::new("12+00", FilmFormat::FF16mm).parse()?;
+        let tc = reel.timecode(&footage);
+        assert_eq!("01:00:02:00", tc.timecode());
+        assert_eq!("15+00", reel.footage(&tc)?.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_film_reel_rate_mismatch() {
+        let picture = range_at("01:00:00:00", "01:10:00:00", rates::F24);
+        let reel = FilmReel::new(picture, FilmFormat::FF35mm4perf);
+
+        let err = reel.footage(&tc_at("01:00:00:00", rates::F25)).unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+
+        let err = reel
+            .with_leader(tc_at("00:00:08:00", rates::F23_98))
+            .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F23_98,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+
+    /// tests reel breaks land on the latest cut which fits, or the maximum length.
+    #[rstest]
+    #[case::fits(
+        "01:20:00:00",
+        vec![],
+        vec!["01:00:00:00", "01:20:00:00"],
+    )]
+    #[case::no_cuts(
+        "01:30:00:00",
+        vec![],
+        vec!["01:00:00:00", "01:22:05:08", "01:30:00:00"],
+    )]
+    #[case::latest_cut(
+        "01:30:00:00",
+        vec!["01:21:00:00", "01:10:00:00", "01:22:05:08", "01:22:05:09"],
+        vec!["01:00:00:00", "01:22:05:08", "01:30:00:00"],
+    )]
+    #[case::cut_before_limit(
+        "01:50:00:00",
+        vec!["01:20:00:00", "01:40:00:00"],
+        vec!["01:00:00:00", "01:20:00:00", "01:40:00:00", "01:50:00:00"],
+    )]
+    #[case::cut_at_start_ignored(
+        "01:30:00:00",
+        vec!["01:00:00:00"],
+        vec!["01:00:00:00", "01:22:05:08", "01:30:00:00"],
+    )]
+    fn test_film_reel_plan(
+        #[case] end: &str,
+        #[case] cuts: Vec<&str>,
+        #[case] expected: Vec<&str>,
+    ) -> Result<(), TimecodeError> {
+        let program = range_at("01:00:00:00", end, rates::F24);
+        let max_length = Timecode::with_frames(
+            FeetFramesStr::new("2000+00", FilmFormat::FF35mm4perf),
+            rates::F24,
+        )?;
+        let cuts: Vec<Timecode> = cuts.into_iter().map(tc).collect();
+
+        let reels = FilmReel::plan(program, FilmFormat::FF35mm4perf, max_length, &cuts)?;
+
+        let breaks: Vec<String> = reels
+            .iter()
+            .map(|reel| reel.picture().start().timecode())
+            .chain(reels.last().map(|reel| reel.picture().end().timecode()))
+            .collect();
+        assert_eq!(expected, breaks, "breaks");
+
+        for reel in reels {
+            assert!(reel.length() <= max_length, "{:?} too long", reel);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_film_reel_plan_errors() {
+        let program = range_at("01:00:00:00", "01:30:00:00", rates::F24);
+
+        let err =
+            FilmReel::plan(program, FilmFormat::FF35mm4perf, tc("00:00:08:00"), &[]).unwrap_err();
+        assert_eq!(
+            TimecodeError::ReelTooShort {
+                max_length: tc("00:00:08:00"),
+                leader: tc("00:00:08:00"),
+            },
+            err
+        );
+
+        let err = FilmReel::plan(
+            program,
+            FilmFormat::FF35mm4perf,
+            tc_at("00:20:00:00", rates::F25),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+
+        let err = FilmReel::plan(
+            program,
+            FilmFormat::FF35mm4perf,
+            tc("00:20:00:00"),
+            &[tc_at("01:10:00:00", rates::F23_98)],
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F23_98,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::consts::SECONDS_PER_HOUR_I64;
+use crate::drop_frame;
 use crate::errors::FramerateParseError;
 use crate::framerate_parse::FramerateSource;
 use num::ToPrimitive;
@@ -47,6 +49,18 @@ impl fmt::Display for Ntsc {
     }
 }
 
+/// The timebase which all PAL rates are a multiple of.
+const PAL_TIMEBASE: i64 = 25;
+
+/// The timebase which all drop-frame rates are a multiple of.
+const DROP_FRAME_TIMEBASE: i64 = 30;
+
+/// The largest timebase covered by SMPTE ST 12-1 timecode.
+const ST_12_1_MAX_TIMEBASE: i64 = 30;
+
+/// The largest timebase covered by SMPTE ST 12-3 high frame rate timecode.
+const ST_12_3_MAX_TIMEBASE: i64 = 120;
+
 /// The [Result] type returned by [Framerate::with_playback] and [Framerate::with_timebase].
 pub type FramerateParseResult = Result<Framerate, FramerateParseError>;
 
@@ -119,8 +133,91 @@ impl Framerate {
             return None;
         }
 
-        let drop_frames = self.timebase().round().to_integer() as f64 * 0.066666;
-        Some(drop_frames.round() as i64)
+        Some(drop_frame::dropped_per_minute(self.timebase().to_integer()))
+    }
+
+    /// Returns the number of frames in 24 hours of timecode at this rate: the frame count of
+    /// `24:00:00:00`.
+    ///
+    /// For drop-frame rates, this accounts for the frame numbers which are skipped, so it matches
+    /// the real-world frame count of a day of footage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::rates;
+    /// assert_eq!(2073600, rates::F24.frames_per_24_hours());
+    /// assert_eq!(2073600, rates::F23_98.frames_per_24_hours());
+    /// assert_eq!(2589408, rates::F29_97_DF.frames_per_24_hours());
+    /// assert_eq!(5178816, rates::F59_94_DF.frames_per_24_hours());
+    /// ```
+    pub fn frames_per_24_hours(&self) -> i64 {
+        let timebase = self.timebase().round().to_integer();
+        let frames = timebase * SECONDS_PER_HOUR_I64 * 24;
+
+        if self.ntsc == Ntsc::DropFrame {
+            // 24:00:00;00 is the label of the first frame after a full day.
+            drop_frame::label_to_frames(frames, timebase)
+        } else {
+            frames
+        }
+    }
+
+    /// Returns descriptive metadata about this rate, for presenting it to users.
+    ///
+    /// Metadata is worked out from the rate itself, so is available for any rate, not just
+    /// those in [rates].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{BroadcastStandard, rates};
+    /// let info = rates::F29_97_NDF.info();
+    /// assert_eq!("29.97 NDF", info.name);
+    /// assert_eq!(Some(BroadcastStandard::Ntsc), info.standard);
+    /// assert!(info.drop_frame_legal);
+    /// assert!(!info.st_12_3);
+    ///
+    /// let info = rates::F50.info();
+    /// assert_eq!("50", info.name);
+    /// assert_eq!(Some(BroadcastStandard::Pal), info.standard);
+    /// assert!(!info.drop_frame_legal);
+    /// assert!(info.st_12_3);
+    /// ```
+    pub fn info(&self) -> RateInfo {
+        let timebase = self.timebase().ceil().to_integer();
+
+        let name = match self.ntsc {
+            Ntsc::None => self.playback_str(),
+            Ntsc::NonDropFrame => format!("{} NDF", self.playback_str()),
+            Ntsc::DropFrame => format!("{} DF", self.playback_str()),
+        };
+
+        let standard = if self.ntsc.is_ntsc() {
+            Some(BroadcastStandard::Ntsc)
+        } else if self.value.is_integer()
+            && timebase % PAL_TIMEBASE == 0
+            && timebase <= ST_12_3_MAX_TIMEBASE
+        {
+            Some(BroadcastStandard::Pal)
+        } else {
+            None
+        };
+
+        RateInfo {
+            rate: *self,
+            name,
+            standard,
+            drop_frame_legal: self.ntsc.is_ntsc() && timebase % DROP_FRAME_TIMEBASE == 0,
+            st_12_3: timebase > ST_12_1_MAX_TIMEBASE && timebase <= ST_12_3_MAX_TIMEBASE,
+        }
+    }
+
+    /// Returns the playback speed rounded to two decimal places, with trailing zeros removed.
+    fn playback_str(&self) -> String {
+        let value_str = format!("{:.2}", self.value.to_f64().unwrap());
+        let value_str = value_str.trim_end_matches('0');
+        value_str.trim_end_matches('.').to_string()
     }
 
     /**
@@ -182,8 +279,10 @@ impl Framerate {
 
     ```rust
     # use vtc::{Framerate, FramerateSource, Ntsc, FramerateParseError};
-    let err = Framerate::with_playback("24/1", Ntsc::NonDropFrame);
-    assert_eq!(FramerateParseError::Ntsc("ntsc framerates must be n/1001".to_string()), err.err().unwrap());
+    let err = Framerate::with_playback("24/1", Ntsc::NonDropFrame).unwrap_err();
+    assert!(matches!(err, FramerateParseError::Ntsc(_)));
+    assert_eq!("ntsc framerates must be n/1001", err.diagnostic().message());
+    assert_eq!(Some("24000/1001"), err.diagnostic().suggestion());
     ```
 
     This means that integers will always result in an error if ntsc != [Ntsc::None]:
@@ -228,8 +327,12 @@ impl Framerate {
 
     ```rust
     # use vtc::{Framerate, FramerateSource, Ntsc, FramerateParseError};
-    let err = Framerate::with_playback(23.98, Ntsc::DropFrame);
-    assert_eq!(FramerateParseError::DropFrame("dropframe must have playback divisible by 30000/1001 (multiple of 29.97)".to_string()), err.err().unwrap());
+    let err = Framerate::with_playback(23.98, Ntsc::DropFrame).unwrap_err();
+    assert!(matches!(err, FramerateParseError::DropFrame(_)));
+    assert_eq!(
+        "dropframe must have playback divisible by 30000/1001 (multiple of 29.97)",
+        err.diagnostic().message(),
+    );
     ```
 
     For more information on why drop-frame timebases must be a multiple of 30000/1001, see
@@ -318,11 +421,10 @@ impl Framerate {
 
     ```rust
     # use vtc::{Framerate, FramerateSource, Ntsc, FramerateParseError};
-    let err = Framerate::with_timebase("24000/1001", Ntsc::NonDropFrame);
-    assert_eq!(
-        FramerateParseError::Ntsc("ntsc timebases must be whole numbers".to_string()),
-        err.err().unwrap(),
-    );
+    let err = Framerate::with_timebase("24000/1001", Ntsc::NonDropFrame).unwrap_err();
+    assert!(matches!(err, FramerateParseError::Ntsc(_)));
+    assert_eq!("ntsc timebases must be whole numbers", err.diagnostic().message());
+    assert_eq!(Some("24"), err.diagnostic().suggestion());
     ```
 
     If we switch our NTSC settings, we can parse arbirary Framerate values:
@@ -340,11 +442,13 @@ impl Framerate {
 
     ```rust
     # use vtc::{Framerate, FramerateSource, Ntsc, FramerateParseError};
-    let err = Framerate::with_timebase("24", Ntsc::DropFrame);
+    let err = Framerate::with_timebase("24", Ntsc::DropFrame).unwrap_err();
+    assert!(matches!(err, FramerateParseError::DropFrame(_)));
     assert_eq!(
-        FramerateParseError::DropFrame("dropframe must have timebase divisible by 30 (multiple of 29.97)".to_string()),
-        err.err().unwrap(),
+        "dropframe must have timebase divisible by 30 (multiple of 29.97)",
+        err.diagnostic().message(),
     );
+    assert_eq!(Some("30"), err.diagnostic().suggestion());
     ```
 
     For more information on why drop-frame timebases must be a multiple of 30, see
@@ -362,11 +466,7 @@ impl Framerate {
 
 impl fmt::Display for Framerate {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let value_str = format!("{:.2}", self.value.to_f64().unwrap());
-        let mut value_str = value_str.trim_end_matches('0');
-        value_str = value_str.trim_end_matches('.');
-
-        write!(f, "[{}", value_str)?;
+        write!(f, "[{}", self.playback_str())?;
         if self.ntsc.is_ntsc() {
             write!(f, " ")?;
         }
@@ -374,6 +474,33 @@ impl fmt::Display for Framerate {
     }
 }
 
+/// The broadcast television standard a [Framerate] belongs to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BroadcastStandard {
+    /// NTSC rates, which run at 1000/1001 of their timebase: 23.98, 29.97, 59.94 and so on.
+    Ntsc,
+    /// PAL / SECAM rates, which are whole multiples of 25 up to 100: 25, 50 and 100.
+    Pal,
+}
+
+/// Descriptive metadata about a [Framerate], returned by [Framerate::info].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateInfo {
+    /// The rate this metadata describes.
+    pub rate: Framerate,
+    /// The name the rate is commonly known by: `23.98 NDF`, `29.97 DF`, `24`.
+    pub name: String,
+    /// The broadcast standard the rate belongs to, if any. Film and high-speed rates do not
+    /// belong to one.
+    pub standard: Option<BroadcastStandard>,
+    /// Whether drop-frame timecode can be used with this rate's timebase. Only NTSC rates
+    /// with a timebase that is a multiple of 30 can drop frames.
+    pub drop_frame_legal: bool,
+    /// Whether timecode at this rate is labeled using SMPTE ST 12-3 high frame rate
+    /// timecode, which covers rates above 30 fps and up to 120 fps.
+    pub st_12_3: bool,
+}
+
 /**
 A collection of common framerates seen in the wild as constants.
 
@@ -386,11 +513,51 @@ assert_eq!(rates::F24, Framerate::with_playback(24, Ntsc::None).unwrap());
 assert_eq!(rates::F29_97_NDF, Framerate::with_playback(29.97, Ntsc::NonDropFrame).unwrap());
 assert_eq!(rates::F29_97_DF, Framerate::with_playback(29.97, Ntsc::DropFrame).unwrap());
 ```
+
+[rates::ALL] can drive a rate picker:
+
+```rust
+use vtc::rates;
+let names: Vec<String> = rates::ALL.iter().map(|rate| rate.info().name).collect();
+assert_eq!("12", names[0]);
+assert_eq!("23.98 NDF", names[5]);
+assert_eq!("1000", names[names.len() - 1]);
+```
 */
 pub mod rates {
     use crate::Framerate;
     use crate::Ntsc;
 
+    /// 12 fps, for animation on twos.
+    pub const F12: Framerate = Framerate {
+        value: num::Rational64::new_raw(12, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 15 fps.
+    pub const F15: Framerate = Framerate {
+        value: num::Rational64::new_raw(15, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 16 fps, for silent film.
+    pub const F16: Framerate = Framerate {
+        value: num::Rational64::new_raw(16, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 18 fps, for silent film.
+    pub const F18: Framerate = Framerate {
+        value: num::Rational64::new_raw(18, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 20 fps, for silent film.
+    pub const F20: Framerate = Framerate {
+        value: num::Rational64::new_raw(20, 1),
+        ntsc: Ntsc::None,
+    };
+
     /// 23.98 NTSC Non-drop-frame.
     pub const F23_98: Framerate = Framerate {
         value: num::Rational64::new_raw(24000, 1001),
@@ -439,6 +606,12 @@ pub mod rates {
         ntsc: Ntsc::None,
     };
 
+    /// 50 fps.
+    pub const F50: Framerate = Framerate {
+        value: num::Rational64::new_raw(50, 1),
+        ntsc: Ntsc::None,
+    };
+
     /// 59.94 NTSC Non-drop-frame.
     pub const F59_94_NDF: Framerate = Framerate {
         value: num::Rational64::new_raw(60000, 1001),
@@ -456,4 +629,74 @@ pub mod rates {
         value: num::Rational64::new_raw(60, 1),
         ntsc: Ntsc::None,
     };
+
+    /// 95.9 NTSC Non-drop-frame.
+    pub const F95_9: Framerate = Framerate {
+        value: num::Rational64::new_raw(96000, 1001),
+        ntsc: Ntsc::NonDropFrame,
+    };
+
+    /// 96 fps.
+    pub const F96: Framerate = Framerate {
+        value: num::Rational64::new_raw(96, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 100 fps.
+    pub const F100: Framerate = Framerate {
+        value: num::Rational64::new_raw(100, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 119.88 NTSC Non-drop-frame.
+    pub const F119_88_NDF: Framerate = Framerate {
+        value: num::Rational64::new_raw(120000, 1001),
+        ntsc: Ntsc::NonDropFrame,
+    };
+
+    /// 119.88 NTSC Drop-frame.
+    pub const F119_88_DF: Framerate = Framerate {
+        value: num::Rational64::new_raw(120000, 1001),
+        ntsc: Ntsc::DropFrame,
+    };
+
+    /// 120 fps.
+    pub const F120: Framerate = Framerate {
+        value: num::Rational64::new_raw(120, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// 1000 fps, for high-speed cameras.
+    pub const F1000: Framerate = Framerate {
+        value: num::Rational64::new_raw(1000, 1),
+        ntsc: Ntsc::None,
+    };
+
+    /// Every rate in this module, ordered by playback speed.
+    pub const ALL: &[Framerate] = &[
+        F12,
+        F15,
+        F16,
+        F18,
+        F20,
+        F23_98,
+        F24,
+        F25,
+        F29_97_NDF,
+        F29_97_DF,
+        F30,
+        F47_95,
+        F48,
+        F50,
+        F59_94_NDF,
+        F59_94_DF,
+        F60,
+        F95_9,
+        F96,
+        F100,
+        F119_88_NDF,
+        F119_88_DF,
+        F120,
+        F1000,
+    ];
 }
@@ -0,0 +1,331 @@
+use num::ToPrimitive;
+
+use crate::consts::TIMECODE_REGEX;
+use crate::timecode_parse::convert_tc_int;
+use crate::{drop_frame, rates, Framerate, Ntsc, ParseDiagnostic, TimecodeParseError};
+
+/// How far, in frames-per-second, a playback hint can be from a candidate before its weight
+/// falls off.
+const PLAYBACK_TOLERANCE: f64 = 0.01;
+
+/// The weight given to drop-frame candidates when nothing says whether the source is
+/// drop-frame. Non-drop-frame is more common in post-production.
+const UNKNOWN_DROP_FRAME_WEIGHT: f64 = 0.5;
+
+/// The weight given to drop-frame candidates when timecode samples were given, but none of
+/// them used a `;` separator.
+const UNMARKED_DROP_FRAME_WEIGHT: f64 = 0.2;
+
+/// The weight given to non-drop-frame candidates when timecode samples used a `;` separator.
+const MARKED_NON_DROP_FRAME_WEIGHT: f64 = 0.05;
+
+/// The weight given to built-in rates outside the range most timecode is recorded at, like
+/// silent film and high-speed rates.
+const UNCOMMON_RATE_WEIGHT: f64 = 0.25;
+
+/// The range of timebases most timecode is recorded at.
+const COMMON_TIMEBASES: std::ops::RangeInclusive<i64> = 24..=60;
+
+/// The weight given to NTSC rates which are not in the built-in catalogue.
+const ARBITRARY_RATE_WEIGHT: f64 = 0.5;
+
+/// Candidates with a confidence below this are not returned.
+const MIN_CONFIDENCE: f64 = 1e-6;
+
+/// A [Framerate] proposed by [FramerateInference], with how confident the inference is that it
+/// is the right one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FramerateCandidate {
+    /// The proposed rate.
+    pub rate: Framerate,
+    /// How likely this rate is compared to the other candidates, between `0` and `1`. The
+    /// confidences of all candidates returned together sum to `1`.
+    pub confidence: f64,
+}
+
+/**
+Infers the likely [Framerate] of timecode which arrived without one.
+
+Each hint narrows down the candidates:
+
+- **Timecode samples** rule out any rate whose timebase is too small for the largest frame
+  number seen, and favor the smallest timebase that fits. A `;` separator favors drop-frame
+  rates, and a frame number that drop-frame skips rules them out.
+- **A maximum frame value** works like the frames of a timecode sample.
+- **A drop-frame flag** keeps only drop-frame or non-drop-frame rates.
+- **A float playback rate**, like `23.976`, favors the rates closest to it, and adds an NTSC rate
+  for its timebase if it is not a built-in rate.
+
+Unlike [Framerate::with_playback], a float without an [Ntsc] setting is not an error here: it is
+just another hint.
+
+# Examples
+
+```rust
+use vtc::{FramerateInference, rates};
+
+let candidates = FramerateInference::new()
+    .timecodes(["01:00:00:00", "01:00:12:23", "01:02:00:17"])
+    .candidates()
+    .unwrap();
+assert_eq!(rates::F23_98, candidates[0].rate);
+assert_eq!(rates::F24, candidates[1].rate);
+
+let candidates = FramerateInference::new()
+    .timecode("01:00:00;29")
+    .candidates()
+    .unwrap();
+assert_eq!(rates::F29_97_DF, candidates[0].rate);
+
+let candidates = FramerateInference::new().playback(59.94).candidates().unwrap();
+assert_eq!(rates::F59_94_NDF, candidates[0].rate);
+assert_eq!(rates::F59_94_DF, candidates[1].rate);
+```
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FramerateInference {
+    timecodes: Vec<String>,
+    max_frame: Option<i64>,
+    drop_frame: Option<bool>,
+    playback: Option<f64>,
+}
+
+impl FramerateInference {
+    /// Creates a new inference with no hints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a timecode sample.
+    pub fn timecode(mut self, timecode: &str) -> Self {
+        self.timecodes.push(timecode.to_string());
+        self
+    }
+
+    /// Adds a number of timecode samples.
+    pub fn timecodes<I, S>(mut self, timecodes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.timecodes
+            .extend(timecodes.into_iter().map(|tc| tc.as_ref().to_string()));
+        self
+    }
+
+    /// Sets the largest frame number observed in the source, such as the largest `FF` value in
+    /// a column of a spreadsheet.
+    pub fn max_frame(mut self, frame: i64) -> Self {
+        self.max_frame = Some(frame);
+        self
+    }
+
+    /// Sets whether the source is known to be drop-frame.
+    pub fn drop_frame(mut self, is_drop_frame: bool) -> Self {
+        self.drop_frame = Some(is_drop_frame);
+        self
+    }
+
+    /// Sets a playback rate with no [Ntsc] setting, like `23.976` from a file's metadata.
+    pub fn playback(mut self, fps: f64) -> Self {
+        self.playback = Some(fps);
+        self
+    }
+
+    /// Returns the candidate rates, most likely first.
+    ///
+    /// Returns an error if any timecode sample cannot be parsed.
+    pub fn candidates(&self) -> Result<Vec<FramerateCandidate>, TimecodeParseError> {
+        let samples = self
+            .timecodes
+            .iter()
+            .map(|tc| parse_sample(tc))
+            .collect::<Result<Vec<Sample>, TimecodeParseError>>()?;
+
+        let mut frame_observations = samples.len() as f64;
+        let mut max_frame = samples.iter().map(|sample| sample.frames).max();
+        if let Some(hint) = self.max_frame {
+            max_frame = Some(max_frame.map_or(hint, |observed| observed.max(hint)));
+            frame_observations += 1.0;
+        }
+
+        let has_separator = samples.iter().any(|sample| sample.has_drop_separator);
+
+        // Work in log-space so many samples do not underflow the weights.
+        let mut weighted: Vec<(Framerate, f64)> = Vec::new();
+        for (rate, prior) in self.candidate_rates() {
+            let timebase = rate.timebase().to_integer();
+            let is_drop_frame = rate.ntsc() == Ntsc::DropFrame;
+
+            if matches!(max_frame, Some(frame) if frame >= timebase) {
+                continue;
+            }
+            if matches!(self.drop_frame, Some(hint) if hint != is_drop_frame) {
+                continue;
+            }
+            if is_drop_frame && samples.iter().any(|sample| sample.is_dropped(timebase)) {
+                continue;
+            }
+
+            let mut log_weight = prior.ln();
+
+            // If frame numbers are spread evenly over the timebase, the chance of every
+            // observation fitting under it falls with each observation for larger timebases.
+            log_weight -= frame_observations * (timebase as f64).ln();
+
+            if let Some(fps) = self.playback {
+                let distance = (rate.playback().to_f64().unwrap_or(0.0) - fps) / PLAYBACK_TOLERANCE;
+                log_weight -= distance * distance;
+            }
+
+            if self.drop_frame.is_none() {
+                let weight = match (is_drop_frame, samples.is_empty(), has_separator) {
+                    (true, true, _) => UNKNOWN_DROP_FRAME_WEIGHT,
+                    (true, false, false) => UNMARKED_DROP_FRAME_WEIGHT,
+                    (false, false, true) => MARKED_NON_DROP_FRAME_WEIGHT,
+                    _ => 1.0,
+                };
+                log_weight += weight.ln();
+            }
+
+            weighted.push((rate, log_weight));
+        }
+
+        let max_log_weight = weighted
+            .iter()
+            .map(|(_, log_weight)| *log_weight)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let relative: Vec<(Framerate, f64)> = weighted
+            .into_iter()
+            .map(|(rate, log_weight)| (rate, (log_weight - max_log_weight).exp()))
+            .collect();
+        let total: f64 = relative.iter().map(|(_, weight)| weight).sum();
+
+        // Drop the candidates too unlikely to be worth returning, then normalize the rest so
+        // the confidences returned still sum to 1.
+        let kept: Vec<(Framerate, f64)> = relative
+            .into_iter()
+            .filter(|(_, weight)| weight / total >= MIN_CONFIDENCE)
+            .collect();
+        let kept_total: f64 = kept.iter().map(|(_, weight)| weight).sum();
+
+        let mut candidates: Vec<FramerateCandidate> = kept
+            .into_iter()
+            .map(|(rate, weight)| FramerateCandidate {
+                rate,
+                confidence: weight / kept_total,
+            })
+            .collect();
+
+        // Sorting is stable, so ties keep their catalogue order.
+        candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+        Ok(candidates)
+    }
+
+    /// Returns the most likely rate, if any rate fits the hints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{FramerateInference, rates};
+    /// let rate = FramerateInference::new().playback(23.976).best().unwrap();
+    /// assert_eq!(Some(rates::F23_98), rate);
+    /// ```
+    pub fn best(&self) -> Result<Option<Framerate>, TimecodeParseError> {
+        Ok(self.candidates()?.first().map(|candidate| candidate.rate))
+    }
+
+    /// Returns the rates to consider along with their prior weight: the rates in [rates::ALL],
+    /// plus the NTSC rate nearest the playback hint if it is not one of them.
+    fn candidate_rates(&self) -> Vec<(Framerate, f64)> {
+        let mut rates: Vec<(Framerate, f64)> = rates::ALL
+            .iter()
+            .map(|rate| {
+                let timebase = rate.timebase().to_integer();
+                if COMMON_TIMEBASES.contains(&timebase) {
+                    (*rate, 1.0)
+                } else {
+                    (*rate, UNCOMMON_RATE_WEIGHT)
+                }
+            })
+            .collect();
+
+        let fps = match self.playback {
+            Some(fps) if fps.is_finite() && fps > 0.0 => fps,
+            _ => return rates,
+        };
+
+        let timebase = (fps * 1.001).round() as i64;
+        if timebase < 1 {
+            return rates;
+        }
+
+        let mut ntsc_settings = vec![Ntsc::NonDropFrame];
+        if timebase % 30 == 0 {
+            ntsc_settings.push(Ntsc::DropFrame);
+        }
+
+        for ntsc in ntsc_settings {
+            let rate = match Framerate::with_timebase(timebase, ntsc) {
+                Ok(rate) => rate,
+                Err(_) => continue,
+            };
+            if !rates.iter().any(|(known, _)| *known == rate) {
+                rates.push((rate, ARBITRARY_RATE_WEIGHT));
+            }
+        }
+
+        rates
+    }
+}
+
+/// The parts of a timecode sample that hint at its rate.
+struct Sample {
+    minutes: i64,
+    seconds: i64,
+    frames: i64,
+    has_drop_separator: bool,
+}
+
+impl Sample {
+    /// Returns whether the sample has a frame number drop-frame timecode at `timebase` skips.
+    fn is_dropped(&self, timebase: i64) -> bool {
+        drop_frame::is_dropped_label(self.minutes, self.seconds, self.frames, timebase)
+    }
+}
+
+/// Parses a timecode sample into the parts which hint at its rate.
+fn parse_sample(input: &str) -> Result<Sample, TimecodeParseError> {
+    let matched = match TIMECODE_REGEX.captures(input) {
+        Some(matched) => matched,
+        None => {
+            return Err(TimecodeParseError::UnknownStrFormat(
+                ParseDiagnostic::new(input, format!("{} is not a known timecode format", input))
+                    .with_span(0..input.len()),
+            ))
+        }
+    };
+
+    // Pull our sections from the right, as partial timecode leaves off the largest places.
+    let mut sections = ["section1", "section2", "section3"]
+        .iter()
+        .filter_map(|name| matched.name(name))
+        .rev();
+
+    let mut next_section = |name: &str| match sections.next() {
+        Some(section) => convert_tc_int(section.as_str(), name),
+        None => Ok(0),
+    };
+    let seconds = next_section("seconds")?;
+    let minutes = next_section("minutes")?;
+
+    // If the regex matched, the frames group must be present.
+    let frames = convert_tc_int(matched.name("frames").unwrap().as_str(), "frames")?;
+
+    Ok(Sample {
+        minutes,
+        seconds,
+        frames,
+        has_drop_separator: input.contains(';'),
+    })
+}
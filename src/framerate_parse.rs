@@ -1,7 +1,7 @@
 use num::{FromPrimitive, Rational64, Signed};
 use std::str::FromStr;
 
-use crate::errors::FramerateParseError;
+use crate::errors::{FramerateParseError, ParseDiagnostic};
 use crate::framerate::Ntsc;
 use std::convert::TryFrom;
 use std::fmt::Debug;
@@ -25,7 +25,11 @@ fn validate_ntsc_value(
     // If this value is negative, error out. We cannot have negative playback rates.
     if value.is_negative() {
         return Err(FramerateParseError::Negative(
-            "framerates cannot be negative".to_string(),
+            ParseDiagnostic::new(
+                value.to_string(),
+                "framerates cannot be negative".to_string(),
+            )
+            .with_suggestion((-value).to_string()),
         ));
     }
 
@@ -38,13 +42,21 @@ fn validate_ntsc_value(
         // If this is a timebase, it needs to be a whole-number, or it is not NTSC compliant.
         if !value.is_integer() {
             return Err(FramerateParseError::Ntsc(
-                "ntsc timebases must be whole numbers".to_string(),
+                ParseDiagnostic::new(
+                    value.to_string(),
+                    "ntsc timebases must be whole numbers".to_string(),
+                )
+                .with_suggestion(value.round().to_string()),
             ));
         }
         // Otherwise if it is a playback speed, it must be divisible by 1001, or it is not compliant.
     } else if value.denom() != &1001 {
         return Err(FramerateParseError::Ntsc(
-            "ntsc framerates must be n/1001".to_string(),
+            ParseDiagnostic::new(
+                value.to_string(),
+                "ntsc framerates must be n/1001".to_string(),
+            )
+            .with_suggestion((value.round() * 1000 / 1001).to_string()),
         ));
     }
 
@@ -70,7 +82,12 @@ fn validate_ntsc_value(
             "dropframe must have {0} divisible by {1} (multiple of 29.97)",
             rate_type, drop_divisor,
         );
-        return Err(FramerateParseError::DropFrame(err_message));
+        // Suggest the nearest drop-frame compatible value above this one.
+        let suggestion = (value / drop_divisor).ceil() * drop_divisor;
+        return Err(FramerateParseError::DropFrame(
+            ParseDiagnostic::new(value.to_string(), err_message)
+                .with_suggestion(suggestion.to_string()),
+        ));
     }
 
     // If we get to here, everything is a-ok!
@@ -140,9 +157,9 @@ impl FramerateSource for u64 {
         let i64_val = match i64::try_from(*self) {
             Ok(converted) => converted,
             Err(err) => {
-                return Err(FramerateParseError::Conversion(format!(
-                    "error converting u64 to i64 : {}",
-                    err
+                return Err(FramerateParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
+                    format!("error converting u64 to i64 : {}", err),
                 )))
             }
         };
@@ -190,16 +207,18 @@ impl FramerateSource for u8 {
 impl FramerateSource for f64 {
     fn to_playback(&self, ntsc: Ntsc, is_timebase: bool) -> FramerateSourceResult {
         if !ntsc.is_ntsc() {
-            return Err(FramerateParseError::Imprecise(
+            return Err(FramerateParseError::Imprecise(ParseDiagnostic::new(
+                self.to_string(),
                 "float values cannot be parsed for non-NTSC Framerates due to imprecision"
                     .to_string(),
-            ));
+            )));
         }
         let mut rational = match num::Rational64::from_f64(*self) {
             None => {
-                return Err(FramerateParseError::Conversion(
+                return Err(FramerateParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
                     "could not parse rational from f64".to_string(),
-                ))
+                )))
             }
             Some(rational) => rational,
         };
@@ -232,10 +251,16 @@ impl FramerateSource for &str {
             return parsed.to_playback(ntsc, is_timebase);
         }
 
-        Err(FramerateParseError::Conversion(format!(
-            "could not parse '{0}' as rational, int, or float for framerate",
-            self
-        )))
+        Err(FramerateParseError::Conversion(
+            ParseDiagnostic::new(
+                *self,
+                format!(
+                    "could not parse '{0}' as rational, int, or float for framerate",
+                    self
+                ),
+            )
+            .with_span(0..self.len()),
+        ))
     }
 }
 
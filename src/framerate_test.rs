@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod test {
-    use crate::{rates, Framerate, FramerateParseError, FramerateSource, Ntsc};
+    use crate::{
+        rates, BroadcastStandard, Framerate, FramerateInference, FramerateParseError,
+        FramerateSource, Ntsc, ParseDiagnostic, TimecodeParseError,
+    };
+    use num::Rational64;
     use rstest::rstest;
 
     #[rstest]
@@ -24,7 +28,7 @@ mod test {
         source_type: SourceType,
         /// ntsc is whether the source should be parsed as an NTSC framerate.
         ntsc: Ntsc,
-        /// expected is the expected result. Use Err([message])
+        /// expected is the expected result. Use Err([diagnostic])
         expected: Result<Success, FramerateParseError>,
     }
 
@@ -395,49 +399,73 @@ mod test {
         source: num::Rational64::new(24, 1),
         source_type: SourceType::Playback,
         ntsc: Ntsc::NonDropFrame,
-        expected: Err(FramerateParseError::Ntsc("ntsc framerates must be n/1001".to_string())),
+        expected: Err(FramerateParseError::Ntsc(
+            ParseDiagnostic::new("24", "ntsc framerates must be n/1001".to_string())
+                .with_suggestion("24000/1001"),
+        )),
     })]
     #[case::error_ntsc_timebase_bad_denom(ParseCase{
         source: "24000/1001",
         source_type: SourceType::Timebase,
         ntsc: Ntsc::NonDropFrame,
-        expected: Err(FramerateParseError::Ntsc("ntsc timebases must be whole numbers".to_string())),
+        expected: Err(FramerateParseError::Ntsc(
+            ParseDiagnostic::new("24000/1001", "ntsc timebases must be whole numbers".to_string())
+                .with_suggestion("24"),
+        )),
     })]
     #[case::error_drop_frame_bad_value(ParseCase{
         source: "24000/1001",
         source_type: SourceType::Playback,
         ntsc: Ntsc::DropFrame,
-        expected: Err(FramerateParseError::DropFrame("dropframe must have playback divisible by 30000/1001 (multiple of 29.97)".to_string())),
+        expected: Err(FramerateParseError::DropFrame(
+            ParseDiagnostic::new("24000/1001", "dropframe must have playback divisible by 30000/1001 (multiple of 29.97)".to_string())
+                .with_suggestion("30000/1001"),
+        )),
     })]
     #[case::error_drop_frame_bad_value(ParseCase{
         source: "24/1",
         source_type: SourceType::Timebase,
         ntsc: Ntsc::DropFrame,
-        expected: Err(FramerateParseError::DropFrame("dropframe must have timebase divisible by 30 (multiple of 29.97)".to_string())),
+        expected: Err(FramerateParseError::DropFrame(
+            ParseDiagnostic::new("24", "dropframe must have timebase divisible by 30 (multiple of 29.97)".to_string())
+                .with_suggestion("30"),
+        )),
     })]
     #[case::error_negative(ParseCase{
         source: -24,
         source_type: SourceType::Playback,
         ntsc: Ntsc::NonDropFrame,
-        expected: Err(FramerateParseError::Negative("framerates cannot be negative".to_string())),
+        expected: Err(FramerateParseError::Negative(
+            ParseDiagnostic::new("-24", "framerates cannot be negative".to_string())
+                .with_suggestion("24"),
+        )),
     })]
     #[case::error_f64_nonntsc(ParseCase{
         source: 23.98f64,
         source_type: SourceType::Playback,
         ntsc: Ntsc::None,
-        expected: Err(FramerateParseError::Imprecise("float values cannot be parsed for non-NTSC Framerates due to imprecision".to_string())),
+        expected: Err(FramerateParseError::Imprecise(ParseDiagnostic::new(
+            "23.98",
+            "float values cannot be parsed for non-NTSC Framerates due to imprecision".to_string(),
+        ))),
     })]
     #[case::error_f32_nonntsc(ParseCase{
         source: 23.98f32,
         source_type: SourceType::Playback,
         ntsc: Ntsc::None,
-        expected: Err(FramerateParseError::Imprecise("float values cannot be parsed for non-NTSC Framerates due to imprecision".to_string())),
+        expected: Err(FramerateParseError::Imprecise(ParseDiagnostic::new(
+            f64::from(23.98f32).to_string(),
+            "float values cannot be parsed for non-NTSC Framerates due to imprecision".to_string(),
+        ))),
     })]
     #[case::error_u64_overlfow(ParseCase{
         source: u64::MAX,
         source_type: SourceType::Playback,
         ntsc: Ntsc::NonDropFrame,
-        expected: Err(FramerateParseError::Conversion("error converting u64 to i64 : out of range integral type conversion attempted".to_string())),
+        expected: Err(FramerateParseError::Conversion(ParseDiagnostic::new(
+            "18446744073709551615",
+            "error converting u64 to i64 : out of range integral type conversion attempted".to_string(),
+        ))),
     })]
     fn test_parse_framerate<T: FramerateSource>(#[case] case: ParseCase<T>) {
         let result = match case.source_type {
@@ -474,6 +502,11 @@ mod test {
     }
 
     #[rstest]
+    #[case::f12(Framerate::with_timebase(12, Ntsc::None), rates::F12)]
+    #[case::f15(Framerate::with_timebase(15, Ntsc::None), rates::F15)]
+    #[case::f16(Framerate::with_timebase(16, Ntsc::None), rates::F16)]
+    #[case::f18(Framerate::with_timebase(18, Ntsc::None), rates::F18)]
+    #[case::f20(Framerate::with_timebase(20, Ntsc::None), rates::F20)]
     #[case::f23_98(Framerate::with_timebase(24, Ntsc::NonDropFrame), rates::F23_98)]
     #[case::f24(Framerate::with_timebase(24, Ntsc::None), rates::F24)]
     #[case::f29_97_ndf(Framerate::with_timebase(30, Ntsc::NonDropFrame), rates::F29_97_NDF)]
@@ -484,7 +517,15 @@ mod test {
     #[case::f48(Framerate::with_timebase(48, Ntsc::None), rates::F48)]
     #[case::f59_94_ndf(Framerate::with_timebase(60, Ntsc::NonDropFrame), rates::F59_94_NDF)]
     #[case::f59_94_df(Framerate::with_timebase(60, Ntsc::DropFrame), rates::F59_94_DF)]
+    #[case::f50(Framerate::with_timebase(50, Ntsc::None), rates::F50)]
     #[case::f60(Framerate::with_timebase(60, Ntsc::None), rates::F60)]
+    #[case::f95_9(Framerate::with_timebase(96, Ntsc::NonDropFrame), rates::F95_9)]
+    #[case::f96(Framerate::with_timebase(96, Ntsc::None), rates::F96)]
+    #[case::f100(Framerate::with_timebase(100, Ntsc::None), rates::F100)]
+    #[case::f119_88_ndf(Framerate::with_timebase(120, Ntsc::NonDropFrame), rates::F119_88_NDF)]
+    #[case::f119_88_df(Framerate::with_timebase(120, Ntsc::DropFrame), rates::F119_88_DF)]
+    #[case::f120(Framerate::with_timebase(120, Ntsc::None), rates::F120)]
+    #[case::f1000(Framerate::with_timebase(1000, Ntsc::None), rates::F1000)]
     fn test_framerate_consts(
         #[case] expected: Result<Framerate, FramerateParseError>,
         #[case] const_value: Framerate,
@@ -492,4 +533,211 @@ mod test {
         assert!(expected.is_ok(), "framerate was parsed");
         assert_eq!(expected.unwrap(), const_value)
     }
+
+    #[test]
+    fn test_rates_all() {
+        assert_eq!(rates::ALL.len(), 24, "all rates are listed");
+        for pair in rates::ALL.windows(2) {
+            assert!(
+                pair[0].playback() <= pair[1].playback(),
+                "{} is listed before {}",
+                pair[0],
+                pair[1]
+            );
+        }
+        for rate in [
+            rates::F23_98,
+            rates::F29_97_DF,
+            rates::F119_88_DF,
+            rates::F1000,
+        ] {
+            assert!(rates::ALL.contains(&rate), "{} is listed", rate);
+        }
+    }
+
+    #[rstest]
+    #[case::f16(rates::F16, "16", None, false, false)]
+    #[case::f23_98(
+        rates::F23_98,
+        "23.98 NDF",
+        Some(BroadcastStandard::Ntsc),
+        false,
+        false
+    )]
+    #[case::f24(rates::F24, "24", None, false, false)]
+    #[case::f25(rates::F25, "25", Some(BroadcastStandard::Pal), false, false)]
+    #[case::f29_97_ndf(
+        rates::F29_97_NDF,
+        "29.97 NDF",
+        Some(BroadcastStandard::Ntsc),
+        true,
+        false
+    )]
+    #[case::f29_97_df(
+        rates::F29_97_DF,
+        "29.97 DF",
+        Some(BroadcastStandard::Ntsc),
+        true,
+        false
+    )]
+    #[case::f30(rates::F30, "30", None, false, false)]
+    #[case::f47_95(rates::F47_95, "47.95 NDF", Some(BroadcastStandard::Ntsc), false, true)]
+    #[case::f50(rates::F50, "50", Some(BroadcastStandard::Pal), false, true)]
+    #[case::f59_94_df(
+        rates::F59_94_DF,
+        "59.94 DF",
+        Some(BroadcastStandard::Ntsc),
+        true,
+        true
+    )]
+    #[case::f95_9(rates::F95_9, "95.9 NDF", Some(BroadcastStandard::Ntsc), false, true)]
+    #[case::f100(rates::F100, "100", Some(BroadcastStandard::Pal), false, true)]
+    #[case::f119_88_df(
+        rates::F119_88_DF,
+        "119.88 DF",
+        Some(BroadcastStandard::Ntsc),
+        true,
+        true
+    )]
+    #[case::f120(rates::F120, "120", None, false, true)]
+    #[case::f1000(rates::F1000, "1000", None, false, false)]
+    #[case::arbitrary(
+        Framerate::with_playback(Rational64::new(24000, 1000), Ntsc::None).unwrap(),
+        "24",
+        None,
+        false,
+        false
+    )]
+    fn test_framerate_info(
+        #[case] rate: Framerate,
+        #[case] name: &str,
+        #[case] standard: Option<BroadcastStandard>,
+        #[case] drop_frame_legal: bool,
+        #[case] st_12_3: bool,
+    ) {
+        let info = rate.info();
+        assert_eq!(info.rate, rate, "rate");
+        assert_eq!(info.name, name, "name");
+        assert_eq!(info.standard, standard, "standard");
+        assert_eq!(info.drop_frame_legal, drop_frame_legal, "drop-frame legal");
+        assert_eq!(info.st_12_3, st_12_3, "ST 12-3");
+    }
+
+    #[rstest]
+    #[case::fits_24(
+        FramerateInference::new().timecodes(["00:00:00:00", "00:00:01:23", "00:00:02:11"]),
+        vec![rates::F23_98, rates::F24]
+    )]
+    #[case::fits_25(
+        FramerateInference::new().timecodes(["00:00:00:24", "00:00:01:03"]),
+        vec![rates::F25]
+    )]
+    #[case::drop_frame_separator(
+        FramerateInference::new().timecodes(["01:00:00;29", "01:00:01;15"]),
+        vec![rates::F29_97_DF]
+    )]
+    #[case::drop_frame_59_94(
+        FramerateInference::new().timecodes(["01:00:00;59", "01:00:01;15"]),
+        vec![rates::F59_94_DF]
+    )]
+    #[case::max_frame(FramerateInference::new().max_frame(49), vec![rates::F50])]
+    #[case::max_frame_high_frame_rate(
+        FramerateInference::new().max_frame(99),
+        vec![rates::F100]
+    )]
+    #[case::drop_frame_119_88(
+        FramerateInference::new().timecodes(["01:00:00;119", "01:00:01;15"]),
+        vec![rates::F119_88_DF]
+    )]
+    #[case::max_frame_and_samples(
+        FramerateInference::new().timecode("00:00:00:10").max_frame(29),
+        vec![rates::F29_97_NDF, rates::F30]
+    )]
+    #[case::playback_ntsc(FramerateInference::new().playback(23.976), vec![rates::F23_98])]
+    #[case::playback_rounded(FramerateInference::new().playback(23.98), vec![rates::F23_98])]
+    #[case::playback_whole(FramerateInference::new().playback(24.0), vec![rates::F24])]
+    #[case::playback_drop_frame(
+        FramerateInference::new().playback(29.97).drop_frame(true),
+        vec![rates::F29_97_DF]
+    )]
+    #[case::playback_and_samples(
+        FramerateInference::new().playback(30.0).timecode("00:00:00:29"),
+        vec![rates::F30]
+    )]
+    #[case::playback_arbitrary_ntsc(
+        FramerateInference::new().playback(71.928),
+        vec![Framerate::with_timebase(72, Ntsc::NonDropFrame).unwrap()]
+    )]
+    fn test_framerate_inference_best(
+        #[case] inference: FramerateInference,
+        #[case] expected_best: Vec<Framerate>,
+    ) {
+        let candidates = inference.candidates().expect("candidates inferred");
+        assert!(!candidates.is_empty(), "candidates were returned");
+
+        // The best candidates may tie, in which case any of them may be first.
+        let best = candidates[0];
+        assert!(
+            expected_best.contains(&best.rate),
+            "{} is one of {:?}",
+            best.rate,
+            expected_best
+        );
+        for candidate in candidates.iter() {
+            if expected_best.contains(&candidate.rate) {
+                assert!(
+                    (candidate.confidence - best.confidence).abs() < 1e-9,
+                    "{} ties for best",
+                    candidate.rate
+                );
+            } else {
+                assert!(
+                    candidate.confidence < best.confidence,
+                    "{} is less likely",
+                    candidate.rate
+                );
+            }
+        }
+
+        let total: f64 = candidates
+            .iter()
+            .map(|candidate| candidate.confidence)
+            .sum();
+        assert!(
+            (total - 1.0).abs() < 1e-9,
+            "confidences sum to 1: {}",
+            total
+        );
+    }
+
+    #[rstest]
+    #[case::frame_too_large(FramerateInference::new().timecode("00:00:00:25"), rates::F24)]
+    #[case::dropped_frame(FramerateInference::new().timecode("00:01:00;01"), rates::F29_97_DF)]
+    #[case::not_drop_frame(FramerateInference::new().drop_frame(false), rates::F29_97_DF)]
+    #[case::is_drop_frame(FramerateInference::new().drop_frame(true), rates::F24)]
+    #[case::far_playback(FramerateInference::new().playback(25.0), rates::F24)]
+    fn test_framerate_inference_excludes(
+        #[case] inference: FramerateInference,
+        #[case] excluded: Framerate,
+    ) {
+        let candidates = inference.candidates().expect("candidates inferred");
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.rate != excluded),
+            "{} was excluded",
+            excluded
+        );
+    }
+
+    #[rstest]
+    fn test_framerate_inference_bad_sample() {
+        let result = FramerateInference::new()
+            .timecode("not a timecode")
+            .candidates();
+        assert!(matches!(
+            result,
+            Err(TimecodeParseError::UnknownStrFormat(_))
+        ));
+    }
 }
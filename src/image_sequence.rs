@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+
+use crate::consts::VFX_START_FRAME;
+use crate::{
+    Framerate, ParseDiagnostic, Timecode, TimecodeError, TimecodeParseError, TimecodeRange,
+};
+
+/**
+Maps the frame numbers of an image sequence, like `shot_v003.1001.exr`, to [Timecode].
+
+Frame `first_frame` of the sequence is at the start [Timecode], and every frame number after it
+is one frame later. Two conventions are common:
+
+- VFX plates are numbered from 1001 no matter where they were pulled from:
+  [FrameNumbering::vfx].
+- Scans and renders are often numbered with the frame count of their source timecode:
+  [FrameNumbering::source_frames].
+
+# Examples
+
+```rust
+use vtc::{FrameNumbering, Timecode, rates};
+
+let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+let numbering = FrameNumbering::vfx(start);
+
+assert_eq!("01:00:00:00", numbering.timecode(1001).unwrap().timecode());
+assert_eq!("01:00:01:00", numbering.timecode(1025).unwrap().timecode());
+
+let tc = Timecode::with_frames("01:00:00:12", rates::F24).unwrap();
+assert_eq!(Ok(1013), numbering.frame_number(&tc));
+
+let numbering = FrameNumbering::source_frames(rates::F24);
+assert_eq!(Ok(86400), numbering.frame_number(&start));
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameNumbering {
+    start: Timecode,
+    first_frame: i64,
+}
+
+impl FrameNumbering {
+    /// Creates a new [FrameNumbering] where frame number `first_frame` is at `start`.
+    pub fn new(start: Timecode, first_frame: i64) -> Self {
+        FrameNumbering { start, first_frame }
+    }
+
+    /// Creates a new [FrameNumbering] where frame number 1001 is at `start`.
+    pub fn vfx(start: Timecode) -> Self {
+        FrameNumbering::new(start, VFX_START_FRAME)
+    }
+
+    /// Creates a new [FrameNumbering] where each frame number is the frame count of its
+    /// timecode at `rate`.
+    pub fn source_frames(rate: Framerate) -> Self {
+        FrameNumbering::new(Timecode::with_i64_frames(0, rate), 0)
+    }
+
+    /// Returns the [Timecode] frame number `first_frame` is at.
+    pub fn start(&self) -> Timecode {
+        self.start
+    }
+
+    /// Returns the frame number at the start [Timecode].
+    pub fn first_frame(&self) -> i64 {
+        self.first_frame
+    }
+
+    /// Returns the [Timecode] of `frame_number`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Overflow] if `frame_number` is too far from the start to count.
+    pub fn timecode(&self, frame_number: i64) -> Result<Timecode, TimecodeError> {
+        frame_number
+            .checked_sub(self.first_frame)
+            .and_then(|offset| self.start.frames().checked_add(offset))
+            .and_then(|frames| Timecode::checked_with_i64_frames(frames, self.start.rate()))
+            .ok_or(TimecodeError::Overflow)
+    }
+
+    /// Returns the frame number of `tc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `tc` is not at the rate of the numbering, or
+    /// [TimecodeError::Overflow] if its frame number is too large to count.
+    pub fn frame_number(&self, tc: &Timecode) -> Result<i64, TimecodeError> {
+        TimecodeError::check_rate(tc.rate(), self.start.rate())?;
+        tc.frames()
+            .checked_sub(self.start.frames())
+            .and_then(|offset| offset.checked_add(self.first_frame))
+            .ok_or(TimecodeError::Overflow)
+    }
+
+    /// Returns the [TimecodeRange] covering frame numbers `first` through `last`, inclusive.
+    /// The range is empty if `last` is before `first`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Overflow] if the start of `first` or the end of `last` is too far
+    /// from the start to count. A `last` of [i64::MAX] has no frame number after it to end on.
+    pub fn range(&self, first: i64, last: i64) -> Result<TimecodeRange, TimecodeError> {
+        let start = self.timecode(first)?;
+        let end = if last < first {
+            start
+        } else {
+            self.timecode(last.checked_add(1).ok_or(TimecodeError::Overflow)?)?
+        };
+        TimecodeRange::new(start, end)
+    }
+
+    /// Checks a set of frame numbers, like those of the files on disk, for gaps and
+    /// duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Overflow] if a frame number is too far from the start to count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{FrameNumbering, Timecode, rates};
+    ///
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let numbering = FrameNumbering::vfx(start);
+    ///
+    /// let report = numbering.report(vec![1001, 1002, 1003, 1006, 1006, 1007]).unwrap();
+    /// assert_eq!(2, report.present.len());
+    /// assert_eq!("01:00:00:03 - 01:00:00:05", report.missing[0].to_string());
+    /// assert_eq!("01:00:00:05 - 01:00:00:06", report.duplicates[0].to_string());
+    /// ```
+    pub fn report<I: IntoIterator<Item = i64>>(
+        &self,
+        frame_numbers: I,
+    ) -> Result<SequenceReport, TimecodeError> {
+        let mut counts = BTreeMap::new();
+        for frame_number in frame_numbers {
+            *counts.entry(frame_number).or_insert(0) += 1;
+        }
+
+        let present = self.runs(counts.keys().copied())?;
+        let duplicates = self.runs(
+            counts
+                .iter()
+                .filter(|(_, count)| **count > 1)
+                .map(|(frame_number, _)| *frame_number),
+        )?;
+
+        let missing = present
+            .windows(2)
+            .map(|pair| TimecodeRange::new(pair[0].end(), pair[1].start()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(SequenceReport {
+            present,
+            missing,
+            duplicates,
+        })
+    }
+
+    /// Groups sorted, unique frame numbers into runs of consecutive frames.
+    fn runs<I: Iterator<Item = i64>>(
+        &self,
+        frame_numbers: I,
+    ) -> Result<Vec<TimecodeRange>, TimecodeError> {
+        let mut runs: Vec<(i64, i64)> = Vec::new();
+        for frame_number in frame_numbers {
+            match runs.last_mut() {
+                Some((_, last)) if last.checked_add(1) == Some(frame_number) => {
+                    *last = frame_number
+                }
+                _ => runs.push((frame_number, frame_number)),
+            }
+        }
+
+        runs.into_iter()
+            .map(|(first, last)| self.range(first, last))
+            .collect()
+    }
+}
+
+/// The frames found in an image sequence, returned by [FrameNumbering::report].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SequenceReport {
+    /// The runs of consecutive frames which are present, in order.
+    pub present: Vec<TimecodeRange>,
+    /// The gaps between the runs of present frames, in order.
+    pub missing: Vec<TimecodeRange>,
+    /// The runs of frames which appear more than once, in order.
+    pub duplicates: Vec<TimecodeRange>,
+}
+
+/**
+The file name pattern of an image sequence, like `shot_v003.%07d.exr` or
+`shot_v003.#######.exr`.
+
+Printf-style `%d` and `%0Nd` patterns are supported, as are runs of `#` or `@`, where each
+character stands for one padded digit.
+
+# Examples
+
+```rust
+use vtc::{FrameNumbering, SequencePattern, Timecode, rates};
+
+let pattern = SequencePattern::parse("shot_v003.%07d.exr").unwrap();
+assert_eq!("shot_v003.0001001.exr", pattern.file_name(1001));
+assert_eq!(Some(1001), pattern.frame_number("shot_v003.0001001.exr"));
+assert_eq!(None, pattern.frame_number("shot_v002.0001001.exr"));
+
+let files = vec!["shot_v003.0001001.exr", "shot_v003.0001002.exr", "shot_v003.0001004.exr"];
+let numbering = FrameNumbering::vfx(Timecode::with_frames("01:00:00:00", rates::F24).unwrap());
+let report = numbering.report(pattern.frame_numbers(files)).unwrap();
+assert_eq!("01:00:00:02 - 01:00:00:03", report.missing[0].to_string());
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SequencePattern {
+    prefix: String,
+    suffix: String,
+    padding: usize,
+}
+
+impl SequencePattern {
+    /// Parses a printf-style or hash-style sequence pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeParseError::UnknownStrFormat] if `pattern` does not have exactly one
+    /// frame number placeholder.
+    pub fn parse(pattern: &str) -> Result<Self, TimecodeParseError> {
+        let mut placeholders = Vec::new();
+
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '%' => {
+                    let digits_start = start + 1;
+                    let mut end = digits_start;
+                    while let Some((i, d)) = chars.peek().copied() {
+                        if !d.is_ascii_digit() {
+                            break;
+                        }
+                        end = i + 1;
+                        chars.next();
+                    }
+                    if let Some((i, 'd')) = chars.peek().copied() {
+                        chars.next();
+                        let digits = &pattern[digits_start..end];
+                        // A printf width without a leading zero pads with spaces, which
+                        // sequence files never use.
+                        if !digits.is_empty() && !digits.starts_with('0') {
+                            return Err(unknown_pattern(pattern, start..i + 1));
+                        }
+                        let padding = digits.parse().unwrap_or(0);
+                        placeholders.push((start..i + 1, padding));
+                    }
+                }
+                '#' | '@' => {
+                    let mut end = start + 1;
+                    while let Some((i, next)) = chars.peek().copied() {
+                        if next != c {
+                            break;
+                        }
+                        end = i + 1;
+                        chars.next();
+                    }
+                    placeholders.push((start..end, end - start));
+                }
+                _ => {}
+            }
+        }
+
+        match placeholders.as_slice() {
+            [(span, padding)] => Ok(SequencePattern {
+                prefix: pattern[..span.start].to_string(),
+                suffix: pattern[span.end..].to_string(),
+                padding: *padding,
+            }),
+            [] => Err(unknown_pattern(pattern, 0..pattern.len())),
+            [_, (span, _), ..] => Err(unknown_pattern(pattern, span.clone())),
+        }
+    }
+
+    /// Returns the number of digits frame numbers are zero-padded to. `0` means no padding.
+    pub fn padding(&self) -> usize {
+        self.padding
+    }
+
+    /// Returns the file name of `frame_number`.
+    pub fn file_name(&self, frame_number: i64) -> String {
+        let sign = if frame_number < 0 { "-" } else { "" };
+        format!(
+            "{}{}{:0width$}{}",
+            self.prefix,
+            sign,
+            frame_number.unsigned_abs(),
+            self.suffix,
+            width = self.padding
+        )
+    }
+
+    /// Returns the frame number of `file_name`, or [None] if it does not match the pattern.
+    /// A frame number may have more digits than the padding, but not fewer.
+    pub fn frame_number(&self, file_name: &str) -> Option<i64> {
+        let number = file_name
+            .strip_prefix(self.prefix.as_str())?
+            .strip_suffix(self.suffix.as_str())?;
+
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        if digits.is_empty()
+            || digits.len() < self.padding
+            || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        number.parse().ok()
+    }
+
+    /// Returns the frame numbers of every file name in `file_names` which matches the pattern,
+    /// in the order given. File names which do not match are skipped.
+    pub fn frame_numbers<I, S>(&self, file_names: I) -> Vec<i64>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        file_names
+            .into_iter()
+            .filter_map(|file_name| self.frame_number(file_name.as_ref()))
+            .collect()
+    }
+}
+
+/// Returns the error for a pattern without exactly one frame number placeholder.
+fn unknown_pattern(pattern: &str, span: std::ops::Range<usize>) -> TimecodeParseError {
+    TimecodeParseError::UnknownStrFormat(
+        ParseDiagnostic::new(
+            pattern,
+            format!(
+                "{} is not a sequence pattern with one %0Nd or # frame number",
+                pattern
+            ),
+        )
+        .with_span(span),
+    )
+}
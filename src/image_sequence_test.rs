@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, FrameNumbering, SequencePattern, Timecode, TimecodeError, TimecodeParseError,
+    };
+    use rstest::rstest;
+
+    fn start() -> Timecode {
+        Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap()
+    }
+
+    #[rstest]
+    #[case::vfx(FrameNumbering::vfx(start()), 1001, "01:00:00:00")]
+    #[case::vfx_later(FrameNumbering::vfx(start()), 1049, "01:00:02:00")]
+    #[case::vfx_pre_roll(FrameNumbering::vfx(start()), 993, "00:59:59:16")]
+    #[case::source_frames(FrameNumbering::source_frames(rates::F23_98), 86400, "01:00:00:00")]
+    #[case::custom(FrameNumbering::new(start(), 0), 24, "01:00:01:00")]
+    fn test_frame_numbering(
+        #[case] numbering: FrameNumbering,
+        #[case] frame_number: i64,
+        #[case] expected: &str,
+    ) {
+        let tc = numbering.timecode(frame_number).unwrap();
+        assert_eq!(expected, tc.timecode(), "timecode");
+        assert_eq!(rates::F23_98, tc.rate(), "rate");
+        assert_eq!(
+            Ok(frame_number),
+            numbering.frame_number(&tc),
+            "frame number"
+        );
+    }
+
+    #[test]
+    fn test_frame_numbering_range() {
+        let numbering = FrameNumbering::vfx(start());
+
+        let range = numbering.range(1001, 1024).unwrap();
+        assert_eq!("01:00:00:00 - 01:00:01:00", range.to_string());
+
+        assert!(numbering.range(1001, 1000).unwrap().is_empty());
+    }
+
+    /// tests that timecode at another rate is rejected rather than rebased.
+    #[test]
+    fn test_frame_numbering_rate_mismatch() {
+        let numbering = FrameNumbering::vfx(start());
+        let tc = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+        assert_eq!(
+            Err(TimecodeError::RateMismatch {
+                found: rates::F24,
+                expected: rates::F23_98,
+            }),
+            numbering.frame_number(&tc)
+        );
+    }
+
+    /// tests that frame numbers at the limits are counted exactly, or rejected if they cannot
+    /// be.
+    #[test]
+    fn test_frame_numbering_range_limits() {
+        let numbering = FrameNumbering::new(start(), i64::MIN);
+        let range = numbering.range(i64::MIN, i64::MIN).unwrap();
+        assert_eq!(1, range.duration().frames());
+        assert!(numbering.range(i64::MIN + 1, i64::MIN).unwrap().is_empty());
+
+        let report = numbering.report(vec![i64::MIN, i64::MIN + 1]).unwrap();
+        assert_eq!("01:00:00:00 - 01:00:00:02", report.present[0].to_string());
+
+        let numbering = FrameNumbering::new(start(), i64::MAX - 1);
+        let range = numbering.range(i64::MAX - 1, i64::MAX - 1).unwrap();
+        assert_eq!(1, range.duration().frames());
+        assert_eq!(
+            Err(TimecodeError::Overflow),
+            numbering.range(i64::MAX - 1, i64::MAX)
+        );
+        assert_eq!(
+            Err(TimecodeError::Overflow),
+            numbering.report(vec![i64::MAX - 1, i64::MAX])
+        );
+
+        let tc = numbering.timecode(i64::MAX).unwrap();
+        assert_eq!(Ok(i64::MAX), numbering.frame_number(&tc));
+        let tc = tc + Timecode::with_frames(1, rates::F23_98).unwrap();
+        assert_eq!(Err(TimecodeError::Overflow), numbering.frame_number(&tc));
+    }
+
+    /// tests that frame numbers too far from the start are rejected rather than overflowing.
+    #[rstest]
+    #[case::vfx(FrameNumbering::vfx(start()), 9000000000000000000)]
+    #[case::vfx_negative(FrameNumbering::vfx(start()), -9000000000000000000)]
+    #[case::before_first(FrameNumbering::new(start(), 0), i64::MIN)]
+    #[case::past_start(FrameNumbering::new(start(), i64::MIN), i64::MAX)]
+    fn test_frame_numbering_overflow(#[case] numbering: FrameNumbering, #[case] frame_number: i64) {
+        assert_eq!(
+            Err(TimecodeError::Overflow),
+            numbering.timecode(frame_number)
+        );
+        assert_eq!(
+            Err(TimecodeError::Overflow),
+            numbering.report(vec![1001, frame_number])
+        );
+    }
+
+    #[test]
+    fn test_sequence_report() {
+        let numbering = FrameNumbering::vfx(start());
+        let report = numbering
+            .report(vec![1010, 1001, 1002, 1002, 1003, 1003, 1004, 1008])
+            .unwrap();
+
+        let present: Vec<String> = report.present.iter().map(|r| r.to_string()).collect();
+        let missing: Vec<String> = report.missing.iter().map(|r| r.to_string()).collect();
+        let duplicates: Vec<String> = report.duplicates.iter().map(|r| r.to_string()).collect();
+
+        assert_eq!(
+            vec![
+                "01:00:00:00 - 01:00:00:04",
+                "01:00:00:07 - 01:00:00:08",
+                "01:00:00:09 - 01:00:00:10",
+            ],
+            present,
+            "present"
+        );
+        assert_eq!(
+            vec!["01:00:00:04 - 01:00:00:07", "01:00:00:08 - 01:00:00:09"],
+            missing,
+            "missing"
+        );
+        assert_eq!(vec!["01:00:00:01 - 01:00:00:03"], duplicates, "duplicates");
+    }
+
+    #[test]
+    fn test_sequence_report_empty() {
+        let report = FrameNumbering::vfx(start()).report(vec![]).unwrap();
+        assert!(report.present.is_empty());
+        assert!(report.missing.is_empty());
+        assert!(report.duplicates.is_empty());
+    }
+
+    #[rstest]
+    #[case::printf("shot_v003.%07d.exr", 7, "shot_v003.0001001.exr")]
+    #[case::printf_unpadded("shot_v003.%d.exr", 0, "shot_v003.1001.exr")]
+    #[case::hashes("shot_v003.####.exr", 4, "shot_v003.1001.exr")]
+    #[case::hashes_long("shot_v003.########.exr", 8, "shot_v003.00001001.exr")]
+    #[case::at_signs("shot_v003.@@@@@.dpx", 5, "shot_v003.01001.dpx")]
+    #[case::percent_text("50%_shot.%04d.exr", 4, "50%_shot.1001.exr")]
+    fn test_sequence_pattern(
+        #[case] pattern: &str,
+        #[case] padding: usize,
+        #[case] file_name: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let parsed = SequencePattern::parse(pattern)?;
+        assert_eq!(padding, parsed.padding(), "padding");
+        assert_eq!(file_name, parsed.file_name(1001), "file name");
+        assert_eq!(Some(1001), parsed.frame_number(file_name), "frame number");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::other_prefix("shot_v002.0001001.exr", None)]
+    #[case::other_suffix("shot_v003.0001001.dpx", None)]
+    #[case::short("shot_v003.1001.exr", None)]
+    #[case::long("shot_v003.10001001.exr", Some(10001001))]
+    #[case::negative("shot_v003.-0000010.exr", Some(-10))]
+    #[case::not_number("shot_v003.00010a1.exr", None)]
+    #[case::empty("shot_v003..exr", None)]
+    fn test_sequence_pattern_frame_number(#[case] file_name: &str, #[case] expected: Option<i64>) {
+        let pattern = SequencePattern::parse("shot_v003.%07d.exr").unwrap();
+        assert_eq!(expected, pattern.frame_number(file_name));
+    }
+
+    #[test]
+    fn test_sequence_pattern_negative_file_name() {
+        let pattern = SequencePattern::parse("shot.%04d.exr").unwrap();
+        assert_eq!("shot.-0010.exr", pattern.file_name(-10));
+
+        let file_name = pattern.file_name(i64::MIN);
+        assert_eq!("shot.-9223372036854775808.exr", file_name);
+        assert_eq!(Some(i64::MIN), pattern.frame_number(&file_name));
+    }
+
+    #[rstest]
+    #[case::no_placeholder("shot_v003.exr", 0..13)]
+    #[case::two_placeholders("shot_%02d.%04d.exr", 10..14)]
+    #[case::space_padded("shot.%4d.exr", 5..8)]
+    fn test_sequence_pattern_errors(#[case] pattern: &str, #[case] span: std::ops::Range<usize>) {
+        let err = SequencePattern::parse(pattern).unwrap_err();
+        assert!(
+            matches!(err, TimecodeParseError::UnknownStrFormat(_)),
+            "{:?}",
+            err
+        );
+        assert_eq!(Some(span), err.diagnostic().span());
+    }
+
+    #[test]
+    fn test_sequence_file_list() {
+        let pattern = SequencePattern::parse("plate.####.exr").unwrap();
+        let files = vec![
+            "plate.1001.exr",
+            "plate.1002.exr",
+            "plate.1002.exr",
+            "plate.1005.exr",
+            "notes.txt",
+        ];
+
+        let frame_numbers = pattern.frame_numbers(files);
+        assert_eq!(vec![1001, 1002, 1002, 1005], frame_numbers);
+
+        let report = FrameNumbering::vfx(start()).report(frame_numbers).unwrap();
+        assert_eq!(1, report.missing.len());
+        assert_eq!(1, report.duplicates.len());
+    }
+}
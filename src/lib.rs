@@ -160,6 +160,8 @@ assert_eq!(ntsc.timecode(), "02:00:00:00");
   - SMPTE Conventions:
     - [X] NTSC
     - [X] Drop-Frame
+    - [X] ST 12-3 high frame rate timecode (full frames or frame groups)
+    - [X] ST 12-1 frame pair labels for 50p, 59.94p and 60p
     - [ ] Interlaced timecode
   - Timecode Representations:
     - Timecode    | '01:00:00:00'
@@ -172,7 +174,20 @@ assert_eq!(ntsc.timecode(), "02:00:00:00");
       - [X] 35mm, 3-perf
       - [X] 35mm, 2-perf
       - [X] 16mm
+      - [X] 65mm 5-perf and 8-perf, 70mm 15-perf IMAX
+      - [X] 35mm 8-perf VistaVision
+      - [X] Super 8 and 9.5mm
+      - [X] User-defined gauges and pulldowns
+    - Film reels (footage from picture start, leaders, reel breaks and changeover cues)
+    - Image sequences (1001 or source frame numbering, `%07d` / `####` patterns, gaps and duplicates)
+    - VFX pull lists (handles, merged pulls per reel, plate frames from 1001, CSV export)
+    - Timelines (tracks of clips, record to source mapping, gaps, overlaps and duration summaries)
+    - Change lists (inserts, deletes, trims and moves between cut versions, with running footage)
+    - Event list rate conversion (rebase, time or label preserving, with duration change reports)
+    - Editing primitives (insert, overwrite, lift, extract, slip and slide)
     - Premiere Ticks | 15240960000000
+    - Sub-frames     | '01:00:00:00.42'
+    - Durations      | '1h 2m 3s 4f', '2:30 min', 'PT1H2M3.5S'
   - Operations:
     - Comparisons (==, <, <=, >, >=)
     - Add
@@ -183,12 +198,25 @@ assert_eq!(ntsc.timecode(), "02:00:00:00");
     - Negative
     - Absolute
     - Rebase (recalculate frame count at new framerate)
+    - Convert rate (re-quantize real-world time at new framerate)
+    - Relabel (keep the HH:MM:SS:FF label at new framerate)
+    - Pulldown (2:3, 2:3:3:2, 2:2:2:4 and 24 to 25 PAL cadences, film frames to fields and back)
+    - Speed change (PAL speed-up, NTSC pull-down and pull-up, with audio sample rates)
+    - Ranges (duration, containment, overlap and intersection)
+    - Configurable rounding (half-even, half-up, floor, ceil, truncate)
+    - Rollover (wrap at 24 hours, unbounded hours, or day-prefixed)
   - Flexible Parsing:
     - Partial timecodes      | '1:12'
     - Partial runtimes       | '1.5'
     - Negative string values | '-1:12', '-3+00'
     - Poorly formatted tc    | '1:13:4'
-  - Built-in consts for common framerates.
+  - Strict Parsing (reject partial, overflowing or mis-separated tc for ingest validation).
+  - Auto-detecting parsing of any representation, with ambiguity errors.
+  - Parse errors with input spans, expected ranges and suggested corrections.
+  - Custom formatting (separators, padding, sign style, drop-frame markers).
+  - Structured feet and frames (feet, frames, perf marker, total perfs) with custom formatting.
+  - Built-in consts for common framerates, from 12 to 1000 fps, with names and standards metadata.
+  - Framerate inference from timecode samples, frame counts and float playback rates.
 
 ## Goals
 
@@ -243,31 +271,107 @@ For a technical deep-dive into the many flavors of timecode, check out
 the subject.
 !*/
 
+mod change_list;
+#[cfg(test)]
+mod change_list_test;
 mod consts;
+mod drop_frame;
+#[cfg(test)]
+mod drop_frame_test;
 mod errors;
+mod event_conversion;
+#[cfg(test)]
+mod event_conversion_test;
+mod event_list;
+#[cfg(test)]
+mod event_list_test;
+mod feet_frames;
+#[cfg(test)]
+mod feet_frames_test;
+mod film_reel;
+#[cfg(test)]
+mod film_reel_test;
 
 mod framerate;
+mod framerate_infer;
 mod framerate_parse;
 #[cfg(test)]
 mod framerate_test;
+mod image_sequence;
+#[cfg(test)]
+mod image_sequence_test;
+mod pull_list;
+#[cfg(test)]
+mod pull_list_test;
+mod pulldown;
+#[cfg(test)]
+mod pulldown_test;
+mod rounding;
+#[cfg(test)]
+mod rounding_test;
+mod speed_change;
+#[cfg(test)]
+mod speed_change_test;
+#[cfg(test)]
+mod test_support;
 
 mod timecode;
+mod timecode_detect;
+mod timecode_format;
+mod timecode_hfr;
 mod timecode_parse;
+mod timecode_range;
+mod timecode_subframe;
+#[cfg(test)]
+mod timecode_test_format;
+#[cfg(test)]
+mod timecode_test_hfr;
 #[cfg(test)]
 mod timecode_test_ops;
 #[cfg(test)]
 mod timecode_test_parse;
 #[cfg(test)]
+mod timecode_test_range;
+#[cfg(test)]
+mod timecode_test_subframe;
+#[cfg(test)]
 mod timecode_test_table;
+mod timeline;
+#[cfg(test)]
+mod timeline_test;
 
+mod source_duration;
 mod source_frames;
 mod source_ppro_ticks;
 mod source_seconds;
 
-pub use errors::{FramerateParseError, TimecodeParseError};
-pub use framerate::{rates, Framerate, FramerateParseResult, Ntsc};
+pub use change_list::{Change, ChangeKind, ChangeList};
+pub use errors::{FramerateParseError, ParseDiagnostic, TimecodeError, TimecodeParseError};
+pub use event_conversion::{
+    ConvertedEvents, DurationChange, EventConversion, EventSide, RateConversion,
+};
+pub use event_list::{Edit, EventList, MovedEvent};
+pub use feet_frames::{FeetFrames, FeetFramesFormat, PerfMarkerStyle};
+pub use film_reel::FilmReel;
+pub use framerate::{rates, BroadcastStandard, Framerate, FramerateParseResult, Ntsc, RateInfo};
+pub use framerate_infer::{FramerateCandidate, FramerateInference};
 pub use framerate_parse::{FramerateSource, FramerateSourceResult};
+pub use image_sequence::{FrameNumbering, SequencePattern, SequenceReport};
+pub use pull_list::{Pull, PullList, PullListBuilder};
+pub use pulldown::{Cadence, Pulldown, PulldownField, PulldownFields};
+pub use rounding::RoundingMode;
+pub use source_duration::DurationStr;
 pub use source_frames::{FramesSource, FramesSourceResult};
 pub use source_ppro_ticks::{PremiereTicksSource, PremiereTicksSourceResult};
 pub use source_seconds::{SecondsSource, SecondsSourceResult};
-pub use timecode::{FeetFramesStr, FilmFormat, Timecode, TimecodeParseResult, TimecodeSections};
+pub use speed_change::SpeedChange;
+pub use timecode::{
+    FeetFramesStr, FilmFormat, FilmFormatSpec, Rollover, Timecode, TimecodeParseResult,
+    TimecodeSections,
+};
+pub use timecode_detect::Representation;
+pub use timecode_format::{DropFrameMarker, SignStyle, TimecodeFormat};
+pub use timecode_hfr::{HfrMode, HfrSections, HfrStr};
+pub use timecode_range::TimecodeRange;
+pub use timecode_subframe::{SubframeStr, SubframeTimecode, SubframeTimecodeParseResult};
+pub use timeline::{Clip, Timeline, TimelineSummary, Track};
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::consts::VFX_START_FRAME;
+use crate::{FrameNumbering, Timecode, TimecodeError, TimecodeRange};
+
+/// The header row of [PullList::to_csv].
+const CSV_HEADER: &str = "Reel,Source Span In,Source Span Out,Pull In,Pull Out,Head Handle,\
+                          Tail Handle,Plate In,Plate Out,Plate Span In,Plate Span Out,Frames";
+
+/**
+Builds a [PullList] of the source material to pull for VFX shots, adding handles to each shot
+and merging the pulls which overlap or touch on the same reel.
+
+Source ranges can come from any EDL-like input, and may be added in any order.
+
+# Examples
+
+```rust
+use vtc::{PullListBuilder, TimecodeRange, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+
+let pulls = PullListBuilder::default()
+    .handles(8)
+    .add("A001", range("01:00:10:00", "01:00:12:00"))
+    .add("A001", range("01:00:12:12", "01:00:14:00"))
+    .add("B002", range("02:00:00:00", "02:00:01:00"))
+    .build()
+    .unwrap();
+
+assert_eq!(2, pulls.pulls().len());
+
+let pull = &pulls.pulls()[0];
+assert_eq!("A001", pull.reel());
+assert_eq!(2, pull.merged());
+assert_eq!("01:00:09:16 - 01:00:14:08", pull.pull().to_string());
+assert_eq!("01:00:10:00 - 01:00:14:00", pull.source_span().to_string());
+assert_eq!(1001..=1112, pull.plate_frames());
+assert_eq!(vec![1009..=1056, 1069..=1104], pull.plate_source_frames());
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PullListBuilder {
+    head_handle: i64,
+    tail_handle: i64,
+    first_frame: i64,
+    sources: Vec<(String, TimecodeRange)>,
+}
+
+impl Default for PullListBuilder {
+    fn default() -> Self {
+        PullListBuilder {
+            head_handle: 0,
+            tail_handle: 0,
+            first_frame: VFX_START_FRAME,
+            sources: Vec::new(),
+        }
+    }
+}
+
+impl PullListBuilder {
+    /// Sets both the head and tail handles to `frames`. Negative lengths are treated as no
+    /// handle.
+    pub fn handles(self, frames: i64) -> Self {
+        self.head_handle(frames).tail_handle(frames)
+    }
+
+    /// Sets the number of frames added before each source in. Defaults to `0`.
+    pub fn head_handle(mut self, frames: i64) -> Self {
+        self.head_handle = frames.max(0);
+        self
+    }
+
+    /// Sets the number of frames added after each source out. Defaults to `0`.
+    pub fn tail_handle(mut self, frames: i64) -> Self {
+        self.tail_handle = frames.max(0);
+        self
+    }
+
+    /// Sets the plate frame number of the first frame of each pull, handles included.
+    /// Defaults to `1001`.
+    pub fn first_frame(mut self, frame_number: i64) -> Self {
+        self.first_frame = frame_number;
+        self
+    }
+
+    /// Adds the `source` range of a shot, from the source in up to, but not including, the
+    /// source out, on `reel`.
+    pub fn add(mut self, reel: &str, source: TimecodeRange) -> Self {
+        self.sources.push((reel.to_string(), source));
+        self
+    }
+
+    /// Builds the [PullList], sorted by reel and then by source timecode. Pulls on the same
+    /// reel are merged when their ranges overlap or touch once handles are added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if two overlapping pulls on the same reel are at
+    /// different rates, or [TimecodeError::Overflow] if the plate frame numbers of a pull are
+    /// too large to count.
+    pub fn build(&self) -> Result<PullList, TimecodeError> {
+        let mut reels: BTreeMap<&str, Vec<Pull>> = BTreeMap::new();
+        for (reel, source) in &self.sources {
+            let rate = source.rate();
+            let pull = TimecodeRange::new(
+                source.start() - Timecode::with_i64_frames(self.head_handle, rate),
+                source.end() + Timecode::with_i64_frames(self.tail_handle, rate),
+            )?;
+
+            reels.entry(reel.as_str()).or_default().push(Pull {
+                reel: reel.clone(),
+                sources: vec![*source],
+                source_span: *source,
+                pull,
+                first_frame: self.first_frame,
+            });
+        }
+
+        let mut pulls = Vec::new();
+        for (_, mut reel_pulls) in reels {
+            reel_pulls.sort_by(|a, b| {
+                (a.pull.start(), a.pull.end()).cmp(&(b.pull.start(), b.pull.end()))
+            });
+
+            let mut merged: Vec<Pull> = Vec::new();
+            for pull in reel_pulls {
+                match merged.last_mut() {
+                    Some(last) if pull.pull.start() <= last.pull.end() => last.merge(&pull)?,
+                    _ => merged.push(pull),
+                }
+            }
+            pulls.extend(merged);
+        }
+
+        // Check the plate frame numbers of every pull can be counted, so they do not need to
+        // be checked each time they are asked for.
+        for pull in &pulls {
+            pull.numbering().frame_number(&pull.pull.end())?;
+        }
+
+        Ok(PullList { pulls })
+    }
+}
+
+/// A single pull of source material for one or more VFX shots, returned by
+/// [PullListBuilder::build].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pull {
+    reel: String,
+    sources: Vec<TimecodeRange>,
+    source_span: TimecodeRange,
+    pull: TimecodeRange,
+    first_frame: i64,
+}
+
+impl Pull {
+    /// Returns the reel the pull comes from.
+    pub fn reel(&self) -> &str {
+        &self.reel
+    }
+
+    /// Returns the source range of each shot in the pull, without handles, sorted by source
+    /// in.
+    pub fn sources(&self) -> &[TimecodeRange] {
+        &self.sources
+    }
+
+    /// Returns the range from the first source in to the last source out of the shots, without
+    /// handles. When shots are merged, this includes any frames between them which no shot
+    /// uses.
+    pub fn source_span(&self) -> TimecodeRange {
+        self.source_span
+    }
+
+    /// Returns the source range to pull, including handles.
+    pub fn pull(&self) -> TimecodeRange {
+        self.pull
+    }
+
+    /// Returns the length of the handle before the source in.
+    pub fn head_handle(&self) -> Timecode {
+        self.source_span.start() - self.pull.start()
+    }
+
+    /// Returns the length of the handle after the source out.
+    pub fn tail_handle(&self) -> Timecode {
+        self.pull.end() - self.source_span.end()
+    }
+
+    /// Returns the number of shots merged into the pull.
+    pub fn merged(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns the plate frame numbering of the pull, where the first frame of the pull is the
+    /// builder's first frame.
+    pub fn numbering(&self) -> FrameNumbering {
+        FrameNumbering::new(self.pull.start(), self.first_frame)
+    }
+
+    /// Returns the plate frame numbers of the pull, including handles.
+    pub fn plate_frames(&self) -> RangeInclusive<i64> {
+        self.plate_range(&self.pull)
+    }
+
+    /// Returns the plate frame numbers of each shot in the pull, without handles, sorted by
+    /// source in.
+    pub fn plate_source_frames(&self) -> Vec<RangeInclusive<i64>> {
+        self.sources
+            .iter()
+            .map(|source| self.plate_range(source))
+            .collect()
+    }
+
+    /// Returns the plate frame numbers of the [source span](Pull::source_span), without
+    /// handles.
+    pub fn plate_source_span(&self) -> RangeInclusive<i64> {
+        self.plate_range(&self.source_span)
+    }
+
+    /// Returns the inclusive plate frame numbers of `range`.
+    fn plate_range(&self, range: &TimecodeRange) -> RangeInclusive<i64> {
+        // Every range is within the pull, whose frame numbers were checked when it was built.
+        let numbering = self.numbering();
+        let first = numbering.frame_number(&range.start()).unwrap();
+        let end = numbering.frame_number(&range.end()).unwrap();
+        first..=end - 1
+    }
+
+    /// Extends the pull to also cover `other`.
+    fn merge(&mut self, other: &Pull) -> Result<(), TimecodeError> {
+        TimecodeError::check_rate(other.pull.rate(), self.pull.rate())?;
+        self.source_span = TimecodeRange::new(
+            self.source_span.start().min(other.source_span.start()),
+            self.source_span.end().max(other.source_span.end()),
+        )?;
+        self.pull = TimecodeRange::new(
+            self.pull.start().min(other.pull.start()),
+            self.pull.end().max(other.pull.end()),
+        )?;
+        self.sources.extend_from_slice(&other.sources);
+        self.sources
+            .sort_by_key(|source| (source.start(), source.end()));
+        Ok(())
+    }
+}
+
+/// A list of [Pull] values, returned by [PullListBuilder::build].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PullList {
+    pulls: Vec<Pull>,
+}
+
+impl PullList {
+    /// Returns the pulls, sorted by reel and then by source timecode.
+    pub fn pulls(&self) -> &[Pull] {
+        &self.pulls
+    }
+
+    /// Returns the pull list as CSV, with a header row and one row per pull.
+    ///
+    /// Source span and pull outs are exclusive, as in an EDL, while plate outs are the last
+    /// frame number of the plate. Handles and frames are frame counts. The source span of a
+    /// merged pull runs from the first source in to the last source out of its shots.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{PullListBuilder, TimecodeRange, rates};
+    ///
+    /// let source = TimecodeRange::with_frames("01:00:10:00", "01:00:12:00", rates::F24).unwrap();
+    ///
+    /// let pulls = PullListBuilder::default()
+    ///     .handles(8)
+    ///     .add("A001", source)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let csv = pulls.to_csv();
+    /// let row = csv.lines().nth(1).unwrap();
+    /// assert_eq!(
+    ///     "A001,01:00:10:00,01:00:12:00,01:00:09:16,01:00:12:08,8,8,1001,1064,1009,1056,64",
+    ///     row,
+    /// );
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+
+        for pull in &self.pulls {
+            let plate = pull.plate_frames();
+            let plate_source = pull.plate_source_span();
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                csv_field(pull.reel()),
+                pull.source_span.start().timecode(),
+                pull.source_span.end().timecode(),
+                pull.pull.start().timecode(),
+                pull.pull.end().timecode(),
+                pull.head_handle().frames(),
+                pull.tail_handle().frames(),
+                plate.start(),
+                plate.end(),
+                plate_source.start(),
+                plate_source.end(),
+                pull.pull.duration().frames(),
+            ));
+        }
+
+        csv
+    }
+}
+
+/// Quotes `field` for CSV if it contains a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::{range, range_at};
+    use crate::{rates, PullListBuilder, TimecodeError};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::no_handles(0, 0, "01:00:10:00 - 01:00:12:00", 1001..=1048, 1001..=1048)]
+    #[case::handles(8, 8, "01:00:09:16 - 01:00:12:08", 1001..=1064, 1009..=1056)]
+    #[case::head_only(12, 0, "01:00:09:12 - 01:00:12:00", 1001..=1060, 1013..=1060)]
+    #[case::tail_only(0, 12, "01:00:10:00 - 01:00:12:12", 1001..=1060, 1001..=1048)]
+    #[case::negative(-8, -8, "01:00:10:00 - 01:00:12:00", 1001..=1048, 1001..=1048)]
+    fn test_pull_handles(
+        #[case] head: i64,
+        #[case] tail: i64,
+        #[case] expected_pull: &str,
+        #[case] expected_plate: std::ops::RangeInclusive<i64>,
+        #[case] expected_plate_source: std::ops::RangeInclusive<i64>,
+    ) -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .head_handle(head)
+            .tail_handle(tail)
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            .build()?;
+
+        let pull = &pulls.pulls()[0];
+        assert_eq!(expected_pull, pull.pull().to_string(), "pull");
+        assert_eq!(
+            "01:00:10:00 - 01:00:12:00",
+            pull.source_span().to_string(),
+            "source span"
+        );
+        assert_eq!(head.max(0), pull.head_handle().frames(), "head handle");
+        assert_eq!(tail.max(0), pull.tail_handle().frames(), "tail handle");
+        assert_eq!(expected_plate, pull.plate_frames(), "plate frames");
+        assert_eq!(
+            expected_plate_source,
+            pull.plate_source_span(),
+            "plate source span"
+        );
+        assert_eq!(
+            vec![expected_plate_source],
+            pull.plate_source_frames(),
+            "plate source frames"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_first_frame() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .first_frame(0)
+            .handles(10)
+            .add("A001", range("01:00:10:00", "01:00:11:00"))
+            .build()?;
+
+        let pull = &pulls.pulls()[0];
+        assert_eq!(0..=43, pull.plate_frames());
+        assert_eq!(vec![10..=33], pull.plate_source_frames());
+        assert_eq!(
+            "01:00:10:00",
+            pull.numbering().timecode(10).unwrap().timecode(),
+            "numbering"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_merge() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .handles(8)
+            // Overlaps the first shot only once handles are added.
+            .add("A001", range("01:00:12:12", "01:00:14:00"))
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            // Inside the first shot.
+            .add("A001", range("01:00:10:12", "01:00:11:00"))
+            // Far enough away to stay separate.
+            .add("A001", range("01:00:20:00", "01:00:21:00"))
+            // The same timecode on another reel is never merged.
+            .add("A002", range("01:00:10:00", "01:00:12:00"))
+            .build()?;
+
+        let summary: Vec<(&str, String, String, usize)> = pulls
+            .pulls()
+            .iter()
+            .map(|pull| {
+                (
+                    pull.reel(),
+                    pull.source_span().to_string(),
+                    pull.pull().to_string(),
+                    pull.merged(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                (
+                    "A001",
+                    "01:00:10:00 - 01:00:14:00".to_string(),
+                    "01:00:09:16 - 01:00:14:08".to_string(),
+                    3
+                ),
+                (
+                    "A001",
+                    "01:00:20:00 - 01:00:21:00".to_string(),
+                    "01:00:19:16 - 01:00:21:08".to_string(),
+                    1
+                ),
+                (
+                    "A002",
+                    "01:00:10:00 - 01:00:12:00".to_string(),
+                    "01:00:09:16 - 01:00:12:08".to_string(),
+                    1
+                ),
+            ],
+            summary
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_merge_keeps_sources() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .handles(8)
+            .add("A001", range("01:00:12:12", "01:00:14:00"))
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            .build()?;
+
+        let pull = &pulls.pulls()[0];
+        let sources: Vec<String> = pull.sources().iter().map(|r| r.to_string()).collect();
+        assert_eq!(
+            vec!["01:00:10:00 - 01:00:12:00", "01:00:12:12 - 01:00:14:00"],
+            sources
+        );
+        assert_eq!(vec![1009..=1056, 1069..=1104], pull.plate_source_frames());
+        assert_eq!(1009..=1104, pull.plate_source_span());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_touching_merged() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .handles(12)
+            .add("A001", range("01:00:10:00", "01:00:11:00"))
+            .add("A001", range("01:00:12:00", "01:00:13:00"))
+            .build()?;
+
+        assert_eq!(1, pulls.pulls().len());
+        assert_eq!(
+            "01:00:09:12 - 01:00:13:12",
+            pulls.pulls()[0].pull().to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_gap_not_merged() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .handles(12)
+            .add("A001", range("01:00:10:00", "01:00:11:00"))
+            .add("A001", range("01:00:12:01", "01:00:13:00"))
+            .build()?;
+
+        assert_eq!(2, pulls.pulls().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_merge_mixed_rates() {
+        let other = range_at("01:00:11:00", "01:00:12:00", rates::F25);
+
+        let err = PullListBuilder::default()
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            .add("A001", other)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_pull_first_frame_overflow() {
+        let err = PullListBuilder::default()
+            .first_frame(i64::MAX - 10)
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(TimecodeError::Overflow, err);
+    }
+
+    #[test]
+    fn test_pull_list_csv() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default()
+            .handles(8)
+            .add("A001", range("01:00:10:00", "01:00:12:00"))
+            .add("Reel \"B\", day 2", range("02:00:00:00", "02:00:01:00"))
+            .build()?;
+
+        let expected = "Reel,Source Span In,Source Span Out,Pull In,Pull Out,Head Handle,\
+                        Tail Handle,Plate In,Plate Out,Plate Span In,Plate Span Out,Frames\n\
+                        A001,01:00:10:00,01:00:12:00,01:00:09:16,01:00:12:08,8,8,1001,1064,1009,1056,64\n\
+                        \"Reel \"\"B\"\", day 2\",02:00:00:00,02:00:01:00,01:59:59:16,02:00:01:08,8,8,\
+                        1001,1040,1009,1032,40\n";
+
+        assert_eq!(expected, pulls.to_csv());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_list_empty() -> Result<(), TimecodeError> {
+        let pulls = PullListBuilder::default().handles(8).build()?;
+        assert!(pulls.pulls().is_empty());
+        assert_eq!(1, pulls.to_csv().lines().count());
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+use num::Integer;
+
+use crate::{Framerate, RoundingMode, Timecode, TimecodeError};
+
+/// The number of fields in an interlaced video frame.
+const FIELDS_PER_FRAME: i64 = 2;
+
+/// How many fields each film frame of a cadence cycle is held for when it is transferred to
+/// interlaced video.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Cadence {
+    /// Classic 2:3 pulldown. 4 film frames become 10 fields: `AA BB BC CD DD`.
+    Pulldown23,
+    /// 2:3:3:2 advanced pulldown. 4 film frames become 10 fields, and only the middle video
+    /// frame mixes two film frames: `AA BB BC CC DD`.
+    Advanced2332,
+    /// 2:2:2:4 pulldown. 4 film frames become 10 fields, with the last film frame held for two
+    /// whole video frames: `AA BB CC DD DD`.
+    Pulldown2224,
+    /// 24 to 25 PAL pulldown. 12 film frames become 25 fields, with every 12th film frame held
+    /// for an extra field.
+    Pal24To25,
+}
+
+impl Cadence {
+    /// Returns how many fields each frame of the cadence cycle is held for, starting at the
+    /// A frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::Cadence;
+    /// assert_eq!(&[2, 3, 2, 3], Cadence::Pulldown23.fields());
+    /// assert_eq!(&[2, 3, 3, 2], Cadence::Advanced2332.fields());
+    /// ```
+    pub fn fields(&self) -> &'static [i64] {
+        match self {
+            Cadence::Pulldown23 => &[2, 3, 2, 3],
+            Cadence::Advanced2332 => &[2, 3, 3, 2],
+            Cadence::Pulldown2224 => &[2, 2, 2, 4],
+            Cadence::Pal24To25 => &[2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3],
+        }
+    }
+
+    /// Returns the number of film frames in one cycle of the cadence.
+    pub fn frames_per_cycle(&self) -> i64 {
+        self.fields().len() as i64
+    }
+
+    /// Returns the number of video fields one cycle of the cadence fills.
+    pub fn fields_per_cycle(&self) -> i64 {
+        self.fields().iter().sum()
+    }
+
+    /// Returns the cadence letter of the frame at `position` in the cycle: 'A' for the first
+    /// frame, 'B' for the second, and so on.
+    fn letter(position: i64) -> char {
+        (b'A' + position as u8) as char
+    }
+
+    /// Returns the field the frame at `position` in the cycle starts on, counted from the first
+    /// field of the cycle.
+    fn field_offset(&self, position: i64) -> i64 {
+        self.fields()[..position as usize].iter().sum()
+    }
+}
+
+/**
+Where a film frame lands in interlaced video. Returned by [Pulldown::video].
+
+# Examples
+
+```rust
+use vtc::{Cadence, Pulldown, Timecode, rates};
+
+let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+let c_frame = Timecode::with_frames("01:00:00:02", rates::F23_98).unwrap();
+let fields = pulldown.video(&c_frame).unwrap();
+
+assert_eq!('C', fields.letter);
+assert_eq!(1, fields.first_field);
+assert_eq!(2, fields.field_count);
+assert_eq!("01:00:00:02", fields.video_frames[0].timecode());
+assert_eq!("01:00:00:03", fields.video_frames[1].timecode());
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PulldownFields {
+    /// The cadence letter of the film frame.
+    pub letter: char,
+    /// The video frames holding at least one field of the film frame, in order.
+    pub video_frames: Vec<Timecode>,
+    /// The field of the first video frame the film frame starts on: `0` for the first field,
+    /// `1` for the second.
+    pub first_field: i64,
+    /// The number of fields the film frame is held for.
+    pub field_count: i64,
+}
+
+/// The film frame a single video field was made from. Returned by [Pulldown::film].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PulldownField {
+    /// The film frame the field was made from.
+    pub film_frame: Timecode,
+    /// The cadence letter of the film frame.
+    pub letter: char,
+}
+
+/**
+Maps film frames to interlaced video fields, and back, for a [Cadence] anchored on an A frame.
+
+The A frame's first field lands on the first field of the video frame at the same real-world
+time, rounded down to a whole video frame. Film frames before the anchor continue the cadence
+backwards.
+
+# Examples
+
+```rust
+use vtc::{Cadence, Pulldown, Timecode, rates};
+
+let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+// Telecine: the B frame is held for 3 fields.
+let b_frame = Timecode::with_frames("01:00:00:01", rates::F23_98).unwrap();
+let fields = pulldown.video(&b_frame).unwrap();
+assert_eq!('B', fields.letter);
+assert_eq!(3, fields.field_count);
+
+// Reverse telecine: the third video frame mixes the B and C frames.
+let video = Timecode::with_frames("01:00:00:02", rates::F29_97_NDF).unwrap();
+let [first, second] = pulldown.film(&video).unwrap();
+assert_eq!('B', first.letter);
+assert_eq!("01:00:00:01", first.film_frame.timecode());
+assert_eq!('C', second.letter);
+assert_eq!("01:00:00:02", second.film_frame.timecode());
+```
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct Pulldown {
+    cadence: Cadence,
+    film_anchor: Timecode,
+    video_anchor: Timecode,
+}
+
+impl Pulldown {
+    /// Creates a new [Pulldown].
+    ///
+    /// # Arguments
+    ///
+    /// * `cadence` - The cadence film frames are held for.
+    ///
+    /// * `a_frame` - A film frame the cadence cycle starts on. Its rate is the film rate.
+    ///
+    /// * `video_rate` - The rate of the interlaced video.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::CadenceMismatch] if one cycle of the cadence does not take the
+    /// same time at the film rate and `video_rate`, like 2:3 pulldown from 24 fps to 25 fps.
+    pub fn new(
+        cadence: Cadence,
+        a_frame: Timecode,
+        video_rate: Framerate,
+    ) -> Result<Self, TimecodeError> {
+        let film_rate = a_frame.rate();
+
+        let film_cycle = film_rate.playback() * cadence.fields_per_cycle();
+        let video_cycle = video_rate.playback() * (cadence.frames_per_cycle() * FIELDS_PER_FRAME);
+        if film_cycle != video_cycle {
+            return Err(TimecodeError::CadenceMismatch {
+                cadence,
+                film: film_rate,
+                video: video_rate,
+            });
+        }
+
+        Ok(Pulldown {
+            cadence,
+            film_anchor: a_frame,
+            video_anchor: a_frame.convert_rate(video_rate, RoundingMode::Floor),
+        })
+    }
+
+    /// Returns the cadence of the pulldown.
+    pub fn cadence(&self) -> Cadence {
+        self.cadence
+    }
+
+    /// Returns the film rate of the pulldown.
+    pub fn film_rate(&self) -> Framerate {
+        self.film_anchor.rate()
+    }
+
+    /// Returns the video rate of the pulldown.
+    pub fn video_rate(&self) -> Framerate {
+        self.video_anchor.rate()
+    }
+
+    /// Returns the video frames and fields `film` is transferred to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `film` is not at the film rate.
+    pub fn video(&self, film: &Timecode) -> Result<PulldownFields, TimecodeError> {
+        TimecodeError::check_rate(film.rate(), self.film_rate())?;
+
+        let relative = film.frames() - self.film_anchor.frames();
+        let (cycle, position) = relative.div_mod_floor(&self.cadence.frames_per_cycle());
+
+        let first = self.video_anchor.frames() * FIELDS_PER_FRAME
+            + cycle * self.cadence.fields_per_cycle()
+            + self.cadence.field_offset(position);
+        let field_count = self.cadence.fields()[position as usize];
+        let last = first + field_count - 1;
+
+        let (first_frame, first_field) = first.div_mod_floor(&FIELDS_PER_FRAME);
+        let (last_frame, _) = last.div_mod_floor(&FIELDS_PER_FRAME);
+
+        let video_frames = (first_frame..=last_frame)
+            .map(|frame| Timecode::with_i64_frames(frame, self.video_rate()))
+            .collect();
+
+        Ok(PulldownFields {
+            letter: Cadence::letter(position),
+            video_frames,
+            first_field,
+            field_count,
+        })
+    }
+
+    /// Returns the film frames the first and second field of `video` were made from. Both are
+    /// the same frame unless `video` is a split-field frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `video` is not at the video rate.
+    pub fn film(&self, video: &Timecode) -> Result<[PulldownField; 2], TimecodeError> {
+        TimecodeError::check_rate(video.rate(), self.video_rate())?;
+
+        let first = (video.frames() - self.video_anchor.frames()) * FIELDS_PER_FRAME;
+        Ok([self.film_field(first), self.film_field(first + 1)])
+    }
+
+    /// Returns whether the two fields of `video` were made from different film frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `video` is not at the video rate.
+    pub fn is_split_field(&self, video: &Timecode) -> Result<bool, TimecodeError> {
+        let [first, second] = self.film(video)?;
+        Ok(first.film_frame != second.film_frame)
+    }
+
+    /// Returns the film frame of the field `field` fields after the first field of the video
+    /// anchor.
+    fn film_field(&self, field: i64) -> PulldownField {
+        let (cycle, mut remaining) = field.div_mod_floor(&self.cadence.fields_per_cycle());
+
+        let mut position = 0;
+        for count in self.cadence.fields() {
+            if remaining < *count {
+                break;
+            }
+            remaining -= count;
+            position += 1;
+        }
+
+        let frames = self.film_anchor.frames() + cycle * self.cadence.frames_per_cycle() + position;
+
+        PulldownField {
+            film_frame: Timecode::with_i64_frames(frames, self.film_rate()),
+            letter: Cadence::letter(position),
+        }
+    }
+}
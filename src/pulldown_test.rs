@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, Cadence, Framerate, Pulldown, PulldownFields, RoundingMode, Timecode, TimecodeError,
+    };
+    use rstest::rstest;
+
+    struct PulldownCase {
+        cadence: Cadence,
+        film_rate: Framerate,
+        video_rate: Framerate,
+        /// The cadence letter of each field in one cycle.
+        fields: &'static str,
+    }
+
+    /// tests every field of the first two cycles maps to the right film frame and back.
+    #[rstest]
+    #[case::pulldown_23(PulldownCase{
+        cadence: Cadence::Pulldown23,
+        film_rate: rates::F23_98,
+        video_rate: rates::F29_97_NDF,
+        fields: "AABBBCCDDD",
+    })]
+    #[case::pulldown_23_df(PulldownCase{
+        cadence: Cadence::Pulldown23,
+        film_rate: rates::F23_98,
+        video_rate: rates::F29_97_DF,
+        fields: "AABBBCCDDD",
+    })]
+    #[case::pulldown_23_f24(PulldownCase{
+        cadence: Cadence::Pulldown23,
+        film_rate: rates::F24,
+        video_rate: rates::F30,
+        fields: "AABBBCCDDD",
+    })]
+    #[case::advanced_2332(PulldownCase{
+        cadence: Cadence::Advanced2332,
+        film_rate: rates::F23_98,
+        video_rate: rates::F29_97_NDF,
+        fields: "AABBBCCCDD",
+    })]
+    #[case::pulldown_2224(PulldownCase{
+        cadence: Cadence::Pulldown2224,
+        film_rate: rates::F23_98,
+        video_rate: rates::F29_97_NDF,
+        fields: "AABBCCDDDD",
+    })]
+    #[case::pal_24_to_25(PulldownCase{
+        cadence: Cadence::Pal24To25,
+        film_rate: rates::F24,
+        video_rate: rates::F25,
+        fields: "AABBCCDDEEFFGGHHIIJJKKLLL",
+    })]
+    fn test_pulldown_cycle(#[case] case: PulldownCase) {
+        let a_frame = Timecode::with_frames("01:00:00:00", case.film_rate).unwrap();
+        let pulldown = Pulldown::new(case.cadence, a_frame, case.video_rate).unwrap();
+        let video_start = a_frame
+            .convert_rate(case.video_rate, RoundingMode::Floor)
+            .frames();
+
+        let letters: Vec<char> = case.fields.chars().collect();
+        let frames_per_cycle = case.cadence.frames_per_cycle();
+        assert_eq!(
+            case.cadence.fields_per_cycle(),
+            letters.len() as i64,
+            "fields per cycle"
+        );
+
+        for field in 0..letters.len() as i64 * 2 {
+            let cycle = field / letters.len() as i64;
+            let letter = letters[field as usize % letters.len()];
+            let film_frame =
+                a_frame.frames() + cycle * frames_per_cycle + (letter as u8 - b'A') as i64;
+
+            let video = Timecode::with_frames(video_start + field / 2, case.video_rate).unwrap();
+            let film = &pulldown.film(&video).unwrap()[(field % 2) as usize];
+
+            assert_eq!(letter, film.letter, "field {} letter", field);
+            assert_eq!(film_frame, film.film_frame.frames(), "field {} film", field);
+            assert_eq!(
+                case.film_rate,
+                film.film_frame.rate(),
+                "field {} rate",
+                field
+            );
+
+            let fields = pulldown.video(&film.film_frame).unwrap();
+            assert_eq!(letter, fields.letter, "field {} video letter", field);
+            assert!(
+                fields.video_frames.contains(&video),
+                "field {} video frames {:?}",
+                field,
+                fields.video_frames
+            );
+        }
+    }
+
+    #[test]
+    fn test_pulldown_video() {
+        let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+        let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+        let film = Timecode::with_frames("01:00:00:03", rates::F23_98).unwrap();
+        let fields = pulldown.video(&film).unwrap();
+
+        assert_eq!(
+            PulldownFields {
+                letter: 'D',
+                video_frames: vec![
+                    Timecode::with_frames("01:00:00:03", rates::F29_97_NDF).unwrap(),
+                    Timecode::with_frames("01:00:00:04", rates::F29_97_NDF).unwrap(),
+                ],
+                first_field: 1,
+                field_count: 3,
+            },
+            fields
+        );
+    }
+
+    #[rstest]
+    #[case::before_anchor("00:59:59:23", 'D', "00:59:59:28")]
+    #[case::next_cycle("01:00:00:05", 'B', "01:00:00:06")]
+    #[case::off_anchor_cycle("01:00:00:10", 'C', "01:00:00:12")]
+    fn test_pulldown_cadence_continues(
+        #[case] film: &str,
+        #[case] letter: char,
+        #[case] first_video: &str,
+    ) {
+        let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+        let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+        let film = Timecode::with_frames(film, rates::F23_98).unwrap();
+        let fields = pulldown.video(&film).unwrap();
+
+        assert_eq!(letter, fields.letter, "letter");
+        assert_eq!(
+            first_video,
+            fields.video_frames[0].timecode(),
+            "first video"
+        );
+    }
+
+    #[rstest]
+    #[case::aa("01:00:00:00", false)]
+    #[case::bb("01:00:00:01", false)]
+    #[case::bc("01:00:00:02", true)]
+    #[case::cd("01:00:00:03", true)]
+    #[case::dd("01:00:00:04", false)]
+    fn test_pulldown_is_split_field(#[case] video: &str, #[case] expected: bool) {
+        let a_frame = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+        let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+        let video = Timecode::with_frames(video, rates::F29_97_NDF).unwrap();
+        assert_eq!(expected, pulldown.is_split_field(&video).unwrap());
+    }
+
+    #[rstest]
+    #[case::pulldown_23_to_pal(Cadence::Pulldown23, rates::F24, rates::F25)]
+    #[case::pal_from_ntsc(Cadence::Pal24To25, rates::F23_98, rates::F25)]
+    #[case::ntsc_mismatch(Cadence::Pulldown23, rates::F23_98, rates::F30)]
+    fn test_pulldown_bad_rates(
+        #[case] cadence: Cadence,
+        #[case] film_rate: Framerate,
+        #[case] video_rate: Framerate,
+    ) {
+        let a_frame = Timecode::with_frames(0, film_rate).unwrap();
+        let err = Pulldown::new(cadence, a_frame, video_rate).unwrap_err();
+        assert_eq!(
+            TimecodeError::CadenceMismatch {
+                cadence,
+                film: film_rate,
+                video: video_rate,
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_pulldown_wrong_input_rate() {
+        let a_frame = Timecode::with_frames(0, rates::F23_98).unwrap();
+        let pulldown = Pulldown::new(Cadence::Pulldown23, a_frame, rates::F29_97_NDF).unwrap();
+
+        let video = Timecode::with_frames(0, rates::F29_97_NDF).unwrap();
+        let err = pulldown.video(&video).unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F29_97_NDF,
+                expected: rates::F23_98,
+            },
+            err
+        );
+
+        let film = Timecode::with_frames(0, rates::F23_98).unwrap();
+        let err = pulldown.film(&film).unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F23_98,
+                expected: rates::F29_97_NDF,
+            },
+            err
+        );
+    }
+}
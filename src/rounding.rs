@@ -0,0 +1,98 @@
+use num::{Integer, Rational64, Signed, Zero};
+
+/**
+The rule used to snap a value which falls between two frames onto a whole frame.
+
+Different workflows need different snapping rules. An EDL conform wants the frame a cut
+*starts* on, subtitle timing usually wants the nearest frame, and audio alignment often
+needs to avoid cumulative bias when many values land exactly between two frames.
+
+# Examples
+
+```rust
+use vtc::{Timecode, RoundingMode, rates};
+use num::Rational64;
+
+// 1/48th of a second is exactly half a frame at 24 fps.
+let half_frame = Rational64::new(1, 48);
+
+let tc = Timecode::with_seconds_rounded(half_frame, rates::F24, RoundingMode::HalfUp).unwrap();
+assert_eq!(1, tc.frames());
+
+let tc = Timecode::with_seconds_rounded(half_frame, rates::F24, RoundingMode::HalfEven).unwrap();
+assert_eq!(0, tc.frames());
+
+let tc = Timecode::with_seconds_rounded(half_frame, rates::F24, RoundingMode::Floor).unwrap();
+assert_eq!(0, tc.frames());
+
+let tc = Timecode::with_seconds_rounded(half_frame, rates::F24, RoundingMode::Ceil).unwrap();
+assert_eq!(1, tc.frames());
+```
+*/
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest frame. Values exactly halfway between two frames are rounded to
+    /// the even frame (banker's rounding), so repeated conversions carry no bias.
+    HalfEven,
+    /// Round to the nearest frame. Values exactly halfway between two frames are rounded
+    /// away from zero. This is the rounding [Timecode](crate::Timecode) has always used, and is
+    /// the default.
+    #[default]
+    HalfUp,
+    /// Round towards negative infinity: always the frame which contains the value.
+    Floor,
+    /// Round towards positive infinity: always the next frame boundary at or after the value.
+    Ceil,
+    /// Round towards zero, discarding any partial frame.
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Rounds `value` to a whole number using this rounding rule.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::RoundingMode;
+    /// use num::Rational64;
+    ///
+    /// let value = Rational64::new(-5, 2);
+    /// assert_eq!(Rational64::from_integer(-2), RoundingMode::HalfEven.round(value));
+    /// assert_eq!(Rational64::from_integer(-3), RoundingMode::HalfUp.round(value));
+    /// assert_eq!(Rational64::from_integer(-3), RoundingMode::Floor.round(value));
+    /// assert_eq!(Rational64::from_integer(-2), RoundingMode::Ceil.round(value));
+    /// assert_eq!(Rational64::from_integer(-2), RoundingMode::Truncate.round(value));
+    /// ```
+    pub fn round(self, value: Rational64) -> Rational64 {
+        match self {
+            RoundingMode::HalfEven => round_half_even(value),
+            RoundingMode::HalfUp => value.round(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Truncate => value.trunc(),
+        }
+    }
+}
+
+/// Rounds to the nearest integer, breaking ties towards the even neighbor.
+fn round_half_even(value: Rational64) -> Rational64 {
+    let half = Rational64::new_raw(1, 2);
+    if value.fract().abs() != half {
+        return value.round();
+    }
+
+    let floor = value.floor();
+    if floor.to_integer().is_even() {
+        floor
+    } else {
+        floor + Rational64::from_integer(1)
+    }
+}
+
+/// Rounds `value` using `mode`, returning the result as an i64.
+pub(crate) fn round_to_integer(value: Rational64, mode: RoundingMode) -> i64 {
+    if value.denom() == &1 || value.is_zero() {
+        return value.to_integer();
+    }
+    mode.round(value).to_integer()
+}
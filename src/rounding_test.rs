@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod test {
+    use crate::{rates, RoundingMode, Timecode, TimecodeParseError};
+    use num::Rational64;
+    use rstest::rstest;
+
+    /// tests rounding whole and fractional values with each rounding mode.
+    #[rstest]
+    #[case(Rational64::new(5, 2), RoundingMode::HalfEven, 2)]
+    #[case(Rational64::new(7, 2), RoundingMode::HalfEven, 4)]
+    #[case(Rational64::new(-5, 2), RoundingMode::HalfEven, -2)]
+    #[case(Rational64::new(-7, 2), RoundingMode::HalfEven, -4)]
+    #[case(Rational64::new(8, 3), RoundingMode::HalfEven, 3)]
+    #[case(Rational64::new(5, 2), RoundingMode::HalfUp, 3)]
+    #[case(Rational64::new(-5, 2), RoundingMode::HalfUp, -3)]
+    #[case(Rational64::new(7, 3), RoundingMode::HalfUp, 2)]
+    #[case(Rational64::new(8, 3), RoundingMode::Floor, 2)]
+    #[case(Rational64::new(-8, 3), RoundingMode::Floor, -3)]
+    #[case(Rational64::new(7, 3), RoundingMode::Ceil, 3)]
+    #[case(Rational64::new(-7, 3), RoundingMode::Ceil, -2)]
+    #[case(Rational64::new(8, 3), RoundingMode::Truncate, 2)]
+    #[case(Rational64::new(-8, 3), RoundingMode::Truncate, -2)]
+    #[case(Rational64::new(4, 1), RoundingMode::Ceil, 4)]
+    fn test_round(#[case] value: Rational64, #[case] mode: RoundingMode, #[case] expected: i64) {
+        assert_eq!(
+            Rational64::from_integer(expected),
+            mode.round(value),
+            "{} rounded with {:?}",
+            value,
+            mode,
+        );
+    }
+
+    /// tests that seconds between frames are snapped according to the rounding mode.
+    #[rstest]
+    #[case(Rational64::new(1, 48), RoundingMode::HalfEven, 0)]
+    #[case(Rational64::new(3, 48), RoundingMode::HalfEven, 2)]
+    #[case(Rational64::new(1, 48), RoundingMode::HalfUp, 1)]
+    #[case(Rational64::new(-1, 48), RoundingMode::HalfUp, -1)]
+    #[case(Rational64::new(1, 30), RoundingMode::Floor, 0)]
+    #[case(Rational64::new(-1, 30), RoundingMode::Floor, -1)]
+    #[case(Rational64::new(1, 30), RoundingMode::Ceil, 1)]
+    #[case(Rational64::new(-1, 30), RoundingMode::Truncate, 0)]
+    #[case(Rational64::new(1, 24), RoundingMode::Ceil, 1)]
+    fn test_with_seconds_rounded(
+        #[case] seconds: Rational64,
+        #[case] mode: RoundingMode,
+        #[case] frames: i64,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_seconds_rounded(seconds, rates::F24, mode)?;
+        assert_eq!(frames, tc.frames(), "{} seconds with {:?}", seconds, mode);
+        Ok(())
+    }
+
+    /// tests that the default rounding mode matches the existing constructor behavior.
+    #[test]
+    fn test_default_matches_with_seconds() -> Result<(), TimecodeParseError> {
+        assert_eq!(RoundingMode::HalfUp, RoundingMode::default());
+
+        for numer in -100..100 {
+            let seconds = Rational64::new(numer, 97);
+            let expected = Timecode::with_seconds(seconds, rates::F23_98)?;
+            let rounded =
+                Timecode::with_seconds_rounded(seconds, rates::F23_98, RoundingMode::default())?;
+            assert_eq!(expected, rounded, "{} seconds", seconds);
+        }
+
+        Ok(())
+    }
+
+    /// tests that converting between rates snaps to the expected frame.
+    #[rstest]
+    #[case(
+        "00:00:00:01",
+        rates::F24,
+        rates::F30,
+        RoundingMode::Floor,
+        "00:00:00:01"
+    )]
+    #[case(
+        "00:00:00:01",
+        rates::F24,
+        rates::F30,
+        RoundingMode::Ceil,
+        "00:00:00:02"
+    )]
+    #[case(
+        "00:00:00:01",
+        rates::F24,
+        rates::F30,
+        RoundingMode::HalfUp,
+        "00:00:00:01"
+    )]
+    #[case(
+        "00:00:00:01",
+        rates::F24,
+        rates::F60,
+        RoundingMode::HalfEven,
+        "00:00:00:02"
+    )]
+    #[case(
+        "00:00:00:01",
+        rates::F24,
+        rates::F60,
+        RoundingMode::HalfUp,
+        "00:00:00:03"
+    )]
+    #[case(
+        "01:00:00:00",
+        rates::F24,
+        rates::F23_98,
+        RoundingMode::Floor,
+        "00:59:56:09"
+    )]
+    #[case(
+        "01:00:00:00",
+        rates::F24,
+        rates::F23_98,
+        RoundingMode::Ceil,
+        "00:59:56:10"
+    )]
+    fn test_convert_rate(
+        #[case] tc_str: &str,
+        #[case] rate: crate::Framerate,
+        #[case] new_rate: crate::Framerate,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc_str, rate)?;
+        let converted = tc.convert_rate(new_rate, mode);
+        assert_eq!(new_rate, converted.rate());
+        assert_eq!(expected, converted.timecode());
+        Ok(())
+    }
+
+    /// tests that division and remainders are complementary for every rounding mode.
+    #[rstest]
+    fn test_div_rem_complementary(
+        #[values(
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::Truncate
+        )]
+        mode: RoundingMode,
+        #[values(-49, -7, 0, 5, 13, 86400)] frames: i64,
+        #[values(2, 3, 7)] divisor: i64,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(frames, rates::F24)?;
+        let rhs = Rational64::from_integer(divisor);
+
+        let quotient = tc.div_rounded(rhs, mode);
+        let remainder = tc.rem_rounded(rhs, mode);
+
+        assert_eq!(
+            frames,
+            quotient.frames() * divisor + remainder.frames(),
+            "{} frames divided by {} with {:?}",
+            frames,
+            divisor,
+            mode,
+        );
+        Ok(())
+    }
+
+    /// tests that the scaling operators match their rounded method counterparts.
+    #[test]
+    fn test_operators_match_rounded_methods() -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames("01:00:00:01", rates::F23_98)?;
+        let rhs = Rational64::new(2, 3);
+
+        assert_eq!(tc * rhs, tc.mul_rounded(rhs, RoundingMode::HalfUp));
+        assert_eq!(tc / rhs, tc.div_rounded(rhs, RoundingMode::Floor));
+        Ok(())
+    }
+}
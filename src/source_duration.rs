@@ -0,0 +1,343 @@
+use num::{CheckedAdd, CheckedDiv, CheckedMul, One, Rational64, Zero};
+
+use crate::consts::{
+    DURATION_CLOCK_REGEX, DURATION_UNITS_REGEX, ISO_8601_DURATION_REGEX, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE,
+};
+use crate::rounding::round_to_integer;
+use crate::timecode_parse::convert_tc_int;
+use crate::{
+    drop_frame, Framerate, FramesSource, FramesSourceResult, Ntsc, ParseDiagnostic, RoundingMode,
+    SecondsSource, SecondsSourceResult, TimecodeParseError,
+};
+
+/// The number of seconds in a day, for ISO 8601 day components.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The most fractional digits a duration value can have, as `10^18` is the largest power of
+/// ten an i64 can hold. Values with fewer digits may still be too large to count once their
+/// whole part is scaled, which is checked as they are parsed.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/**
+A struct that wraps a human-written duration string, to parse it as a [FramesSource] or
+[SecondsSource].
+
+The following forms are supported:
+
+| Form          | Example        |
+|---------------|----------------|
+| Unit suffixes | `1h 2m 3s 4f`  |
+| Single unit   | `90s`, `1500f` |
+| Clock + unit  | `2:30 min`     |
+| ISO 8601      | `PT1H2M3.5S`   |
+
+Unit suffixes may be abbreviated or spelled out (`h`, `hr`, `hrs`, `hour`, `hours` and so on),
+and are not case-sensitive. Hours, minutes and seconds may carry a decimal fraction.
+
+# Timecode time vs real time
+
+Producers who write `30s` for a spot mean thirty seconds of *timecode*: `00:00:30:00`. Every
+form other than ISO 8601 is read this way, as the sections of a timecode label. This means
+`90s` at 23.98 NTSC is `00:01:30:00`, not 90 real-world seconds, and drop-frame durations are
+counted on the drop-frame clock.
+
+ISO 8601 durations are defined in real-world time, so are read as [Timecode::runtime] would
+be, and rounded to the nearest frame.
+
+# Examples
+
+```rust
+use vtc::{DurationStr, Timecode, rates};
+
+let tc = Timecode::with_frames(DurationStr::new("1h 2m 3s 4f"), rates::F24).unwrap();
+assert_eq!("01:02:03:04", tc.timecode());
+
+let tc = Timecode::with_frames(DurationStr::new("2:30 min"), rates::F23_98).unwrap();
+assert_eq!("00:02:30:00", tc.timecode());
+
+let tc = Timecode::with_frames(DurationStr::new("1500f"), rates::F24).unwrap();
+assert_eq!("00:01:02:12", tc.timecode());
+
+// ISO 8601 durations are real-world time.
+let tc = Timecode::with_seconds(DurationStr::new("PT1H"), rates::F23_98).unwrap();
+assert_eq!("00:59:56:10", tc.timecode());
+
+// The nearest frame lands 13 milliseconds past the hour.
+assert_eq!("PT1H0.013S", tc.iso8601_duration(3));
+```
+
+[Timecode::runtime]: crate::Timecode::runtime
+*/
+#[derive(Debug)]
+pub struct DurationStr<'a> {
+    pub(crate) input: &'a str,
+}
+
+impl<'a> DurationStr<'a> {
+    /// Create a [DurationStr] object from a string.
+    pub fn new(input: &'a str) -> Self {
+        DurationStr { input }
+    }
+}
+
+impl<'a> FramesSource for DurationStr<'a> {
+    fn to_frames(&self, rate: Framerate) -> FramesSourceResult {
+        match parse_duration_str(self.input, rate)? {
+            ParsedDuration::Frames(frames) => Ok(frames),
+            ParsedDuration::Seconds(seconds) => match seconds.checked_mul(&rate.playback()) {
+                Some(frames) => Ok(round_to_integer(frames, RoundingMode::HalfUp)),
+                None => Err(duration_too_large_error(self.input)),
+            },
+        }
+    }
+}
+
+impl<'a> SecondsSource for DurationStr<'a> {
+    fn to_seconds(&self, rate: Framerate) -> SecondsSourceResult {
+        match parse_duration_str(self.input, rate)? {
+            ParsedDuration::Frames(frames) => Rational64::from_integer(frames)
+                .checked_div(&rate.playback())
+                .ok_or_else(|| duration_too_large_error(self.input)),
+            ParsedDuration::Seconds(seconds) => Ok(seconds),
+        }
+    }
+}
+
+/// A duration parsed from a [DurationStr]. Which variant is returned depends on whether the
+/// form is counted in timecode time or real-world time.
+enum ParsedDuration {
+    Frames(i64),
+    Seconds(Rational64),
+}
+
+/// parse_duration_str parses any of the duration forms supported by [DurationStr].
+fn parse_duration_str(input: &str, rate: Framerate) -> Result<ParsedDuration, TimecodeParseError> {
+    let trimmed = input.trim();
+
+    if let Some(matched) = ISO_8601_DURATION_REGEX.captures(trimmed) {
+        return parse_iso_8601_duration(input, matched).map(ParsedDuration::Seconds);
+    }
+
+    if let Some(matched) = DURATION_CLOCK_REGEX.captures(trimmed) {
+        return parse_clock_duration(input, matched, rate).map(ParsedDuration::Frames);
+    }
+
+    if let Some(matched) = DURATION_UNITS_REGEX.captures(trimmed) {
+        return parse_units_duration(input, matched, rate).map(ParsedDuration::Frames);
+    }
+
+    Err(unknown_duration_error(input))
+}
+
+/// Parses a duration like '1h 2m 3s 4f' into a frame count.
+fn parse_units_duration(
+    input: &str,
+    matched: regex::Captures,
+    rate: Framerate,
+) -> FramesSourceResult {
+    let hours = matched.name("hours");
+    let minutes = matched.name("minutes");
+    let seconds = matched.name("seconds");
+    let frames = matched.name("frames");
+
+    // Every section of the regex is optional, so make sure we actually got something.
+    if hours.is_none() && minutes.is_none() && seconds.is_none() && frames.is_none() {
+        return Err(unknown_duration_error(input));
+    }
+
+    let mut total_seconds = Rational64::zero();
+    if let Some(hours) = hours {
+        total_seconds = add_section(input, total_seconds, hours.as_str(), SECONDS_PER_HOUR)?;
+    }
+    if let Some(minutes) = minutes {
+        total_seconds = add_section(input, total_seconds, minutes.as_str(), SECONDS_PER_MINUTE)?;
+    }
+    if let Some(seconds) = seconds {
+        total_seconds = add_section(input, total_seconds, seconds.as_str(), Rational64::one())?;
+    }
+
+    let frames = match frames {
+        Some(frames) => convert_tc_int(frames.as_str(), "frames")?,
+        None => 0,
+    };
+
+    let is_negative = matched.name("negative").is_some();
+    label_duration_to_frames(input, total_seconds, frames, is_negative, rate)
+}
+
+/// Parses a duration like '2:30 min' into a frame count.
+fn parse_clock_duration(
+    input: &str,
+    matched: regex::Captures,
+    rate: Framerate,
+) -> FramesSourceResult {
+    // If the regex matched, the clock group must be present.
+    let clock = matched.name("clock").unwrap().as_str();
+    let sections: Vec<&str> = clock.split(':').collect();
+
+    // The unit names the first section of the clock, and each section after it is the next
+    // smallest unit.
+    let first_unit = if matched.name("hours").is_some() {
+        SECONDS_PER_HOUR
+    } else {
+        SECONDS_PER_MINUTE
+    };
+
+    if first_unit == SECONDS_PER_MINUTE && sections.len() > 2 {
+        return Err(TimecodeParseError::UnknownStrFormat(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: minute durations can only have minutes and seconds sections",
+                    input
+                ),
+            )
+            .with_span(0..input.len()),
+        ));
+    }
+
+    let mut unit = first_unit;
+    let mut total_seconds = Rational64::zero();
+    for section in sections {
+        total_seconds = add_section(input, total_seconds, section, unit)?;
+        unit /= 60;
+    }
+
+    let is_negative = matched.name("negative").is_some();
+    label_duration_to_frames(input, total_seconds, 0, is_negative, rate)
+}
+
+/// Parses an ISO 8601 duration like 'PT1H2M3.5S' into real-world seconds.
+fn parse_iso_8601_duration(input: &str, matched: regex::Captures) -> SecondsSourceResult {
+    let days = matched.name("days");
+    let time = matched.name("time");
+    let hours = matched.name("hours");
+    let minutes = matched.name("minutes");
+    let seconds = matched.name("seconds");
+
+    let has_time = hours.is_some() || minutes.is_some() || seconds.is_some();
+
+    // 'P' and 'PT' alone are not valid durations.
+    if !has_time && (days.is_none() || time.is_some()) {
+        return Err(unknown_duration_error(input));
+    }
+
+    let mut total_seconds = Rational64::zero();
+    if let Some(days) = days {
+        let days = convert_tc_int(days.as_str(), "days")?;
+        total_seconds = days
+            .checked_mul(SECONDS_PER_DAY)
+            .map(Rational64::from_integer)
+            .ok_or_else(|| duration_too_large_error(input))?;
+    }
+    if let Some(hours) = hours {
+        total_seconds = add_section(input, total_seconds, hours.as_str(), SECONDS_PER_HOUR)?;
+    }
+    if let Some(minutes) = minutes {
+        total_seconds = add_section(input, total_seconds, minutes.as_str(), SECONDS_PER_MINUTE)?;
+    }
+    if let Some(seconds) = seconds {
+        total_seconds = add_section(input, total_seconds, seconds.as_str(), Rational64::one())?;
+    }
+
+    if matched.name("negative").is_some() {
+        total_seconds = -total_seconds;
+    }
+
+    Ok(total_seconds)
+}
+
+/// Converts a duration counted in timecode time into a frame count. `seconds` are timecode
+/// seconds, which are rounded to the nearest frame of the timebase before `frames` are added.
+///
+/// For drop-frame rates the total is treated as a drop-frame label, so the result matches the
+/// real frame count which would display the same `HH:MM:SS;FF`.
+fn label_duration_to_frames(
+    input: &str,
+    seconds: Rational64,
+    frames: i64,
+    is_negative: bool,
+    rate: Framerate,
+) -> FramesSourceResult {
+    let mut label = seconds
+        .checked_mul(&rate.timebase())
+        .map(|label| round_to_integer(label, RoundingMode::HalfUp))
+        .and_then(|label| label.checked_add(frames))
+        .ok_or_else(|| duration_too_large_error(input))?;
+
+    if rate.ntsc() == Ntsc::DropFrame {
+        label = drop_frame::label_to_frames(label, rate.timebase().to_integer());
+    }
+
+    if is_negative {
+        Ok(-label)
+    } else {
+        Ok(label)
+    }
+}
+
+/// Parses one section of a duration and adds it to `total_seconds`, scaled by the seconds in
+/// its unit.
+fn add_section(
+    input: &str,
+    total_seconds: Rational64,
+    section: &str,
+    unit: Rational64,
+) -> Result<Rational64, TimecodeParseError> {
+    parse_decimal(section)?
+        .checked_mul(&unit)
+        .and_then(|seconds| total_seconds.checked_add(&seconds))
+        .ok_or_else(|| duration_too_large_error(input))
+}
+
+/// Parses a string like '3.5' or '3,5' into an exact rational value.
+fn parse_decimal(value: &str) -> Result<Rational64, TimecodeParseError> {
+    let (whole, fraction) = match value.split_once(['.', ',']) {
+        Some(split) => split,
+        None => (value, ""),
+    };
+
+    let whole = convert_tc_int(whole, "duration")?;
+    if fraction.is_empty() {
+        return Ok(Rational64::from_integer(whole));
+    }
+
+    if fraction.len() > MAX_FRACTION_DIGITS {
+        return Err(TimecodeParseError::Conversion(
+            ParseDiagnostic::new(
+                value,
+                format!(
+                    "duration value '{}' has more than {} decimal places",
+                    value, MAX_FRACTION_DIGITS
+                ),
+            )
+            .with_span(value.len() - fraction.len()..value.len()),
+        ));
+    }
+
+    let fraction_numerator = convert_tc_int(fraction, "duration fraction")?;
+    let denominator = 10i64.pow(fraction.len() as u32);
+    let numerator = whole
+        .checked_mul(denominator)
+        .and_then(|whole| whole.checked_add(fraction_numerator))
+        .ok_or_else(|| duration_too_large_error(value))?;
+
+    Ok(Rational64::new(numerator, denominator))
+}
+
+/// Returns the error for a duration which is too large to count.
+fn duration_too_large_error(input: &str) -> TimecodeParseError {
+    TimecodeParseError::Conversion(
+        ParseDiagnostic::new(input, format!("duration '{}' is too large to count", input))
+            .with_span(0..input.len()),
+    )
+}
+
+/// Returns the error for a string which is not any known duration format.
+fn unknown_duration_error(input: &str) -> TimecodeParseError {
+    TimecodeParseError::UnknownStrFormat(
+        ParseDiagnostic::new(input, format!("{} is not a known duration format", input))
+            .with_span(0..input.len()),
+    )
+}
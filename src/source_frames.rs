@@ -8,8 +8,8 @@ use crate::consts::{
     FEET_AND_FRAMES_REGEX, SECONDS_PER_HOUR_I64, SECONDS_PER_MINUTE_I64, TIMECODE_REGEX,
 };
 use crate::{
-    timecode_parse, FeetFramesStr, FilmFormat, Framerate, Ntsc, TimecodeParseError,
-    TimecodeSections,
+    drop_frame, timecode_parse, FeetFrames, FeetFramesStr, FilmFormat, Framerate, Ntsc,
+    ParseDiagnostic, TimecodeParseError, TimecodeSections,
 };
 
 /// The result type of [FramesSource::to_frames].
@@ -48,9 +48,9 @@ impl FramesSource for isize {
         let i64_val = match i64::try_from(*self) {
             Ok(converted) => converted,
             Err(err) => {
-                return Err(TimecodeParseError::Conversion(format!(
-                    "error converting isize to i64 : {}",
-                    err
+                return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
+                    format!("error converting isize to i64 : {}", err),
                 )))
             }
         };
@@ -64,9 +64,9 @@ impl FramesSource for usize {
         let i64_val = match i64::try_from(*self) {
             Ok(converted) => converted,
             Err(err) => {
-                return Err(TimecodeParseError::Conversion(format!(
-                    "error converting usize to i64 : {}",
-                    err
+                return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
+                    format!("error converting usize to i64 : {}", err),
                 )))
             }
         };
@@ -80,9 +80,9 @@ impl FramesSource for u64 {
         let i64_val = match i64::try_from(*self) {
             Ok(converted) => converted,
             Err(err) => {
-                return Err(TimecodeParseError::Conversion(format!(
-                    "error converting u64 to i64 : {}",
-                    err
+                return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
+                    format!("error converting u64 to i64 : {}", err),
                 )))
             }
         };
@@ -134,26 +134,22 @@ impl FramesSource for &str {
         }
 
         if let Some(matched) = FEET_AND_FRAMES_REGEX.captures(self) {
-            return parse_feet_and_frames_str(matched, None);
+            return Ok(parse_feet_and_frames_str(matched, None, false)?.frame_count());
         }
 
-        Err(TimecodeParseError::UnknownStrFormat(format!(
-            "{} is not a known frame-count timecode format",
-            self
-        )))
+        Err(TimecodeParseError::UnknownStrFormat(
+            ParseDiagnostic::new(
+                *self,
+                format!("{} is not a known frame-count timecode format", self),
+            )
+            .with_span(0..self.len()),
+        ))
     }
 }
 
 impl<'a> FramesSource for FeetFramesStr<'a> {
     fn to_frames(&self, _rate: Framerate) -> FramesSourceResult {
-        if let Some(matched) = FEET_AND_FRAMES_REGEX.captures(self.input) {
-            parse_feet_and_frames_str(matched, Some(self.format))
-        } else {
-            Err(TimecodeParseError::UnknownStrFormat(format!(
-                "{} is not a known frame-count timecode format",
-                self.input
-            )))
-        }
+        Ok(self.parse()?.frame_count())
     }
 }
 
@@ -163,8 +159,29 @@ impl FramesSource for String {
     }
 }
 
+/// Parses a feet and frames string into its pieces, inferring the format if `given_format` is
+/// [None]. The perf field must match the foot it is on.
+pub(crate) fn parse_feet_and_frames(
+    input: &str,
+    given_format: Option<FilmFormat>,
+) -> Result<FeetFrames, TimecodeParseError> {
+    match FEET_AND_FRAMES_REGEX.captures(input) {
+        Some(matched) => parse_feet_and_frames_str(matched, given_format, true),
+        None => Err(TimecodeParseError::UnknownStrFormat(
+            ParseDiagnostic::new(
+                input,
+                format!("{} is not a known frame-count timecode format", input),
+            )
+            .with_span(0..input.len()),
+        )),
+    }
+}
+
 /// parse_timecode_string parses a tc string matched by TIMECODE_REGEX into a frame count.
-fn parse_timecode_string(matched: regex::Captures, rate: Framerate) -> FramesSourceResult {
+pub(crate) fn parse_timecode_string(
+    matched: regex::Captures,
+    rate: Framerate,
+) -> FramesSourceResult {
     // We can unwrap the frames here because we know that if the regex matched, the frames value
     // must be there.
     let frames =
@@ -213,6 +230,7 @@ fn parse_timecode_string(matched: regex::Captures, rate: Framerate) -> FramesSou
                 frames,
             },
             rate,
+            &matched,
         )?
     } else {
         0
@@ -240,37 +258,47 @@ fn parse_timecode_string(matched: regex::Captures, rate: Framerate) -> FramesSou
 /// https://www.davidheidelberger.com/2010/06/10/drop-frame-timecode/
 ///
 /// **WARNING** this method will panic if a non-drop-frame Framerate is passed to it.
-fn drop_frame_tc_adjustment(sections: TimecodeSections, rate: Framerate) -> FramesSourceResult {
-    // Get the number of frames we need to drop each time we drop frames (ex: 2 for 29.97)
-    let drop_frames = rate.drop_frames_per_minute().unwrap();
-
-    // We have a bad frame value if our 'frames' place is less than the drop_frames we
-    // skip on minutes not divisible by 10.
-    let has_bad_frames = sections.frames < drop_frames;
-    let is_tenth_minute = sections.minutes % 10 == 0;
-    let is_minute_boundary = sections.seconds == 0;
-
-    if has_bad_frames && is_minute_boundary && !is_tenth_minute {
-        return Err(TimecodeParseError::DropFrameValue(format!(
-            "drop-frame tc cannot have a frames value of less than {} on minutes not divisible by 10, found '{}'",
-            drop_frames,
+fn drop_frame_tc_adjustment(
+    sections: TimecodeSections,
+    rate: Framerate,
+    matched: &regex::Captures,
+) -> FramesSourceResult {
+    let timebase = rate.timebase().to_integer();
+
+    // We have a bad frame value if our 'frames' place is one of the frame numbers we skip on
+    // minutes not divisible by 10.
+    if drop_frame::is_dropped_label(
+        sections.minutes,
+        sections.seconds,
+        sections.frames,
+        timebase,
+    ) {
+        // If the regex matched, the frames group must be present.
+        let frames_match = matched.name("frames").unwrap();
+        return Err(timecode_parse::dropped_label_error(
+            matched.get(0).unwrap().as_str(),
+            frames_match.range(),
             sections.frames,
-        )));
+            rate,
+        ));
     };
 
+    // calculate the adjustment, we need to remove the dropped frames for each minute except for
+    // every 10th minute.
     let total_minutes = 60 * sections.hours + sections.minutes;
-    // calculate the adjustment, we need to remove two frames for each minute except for every
-    // 10th minute.
-    let adjustment = drop_frames * (total_minutes - total_minutes / 10);
+    let adjustment = drop_frame::dropped_minutes_adjustment(total_minutes, timebase);
 
     // We need the adjustment to remove frames, so return a negative.
     Ok(-adjustment)
 }
 
+/// Parses a feet and frames string matched by FEET_AND_FRAMES_REGEX into its pieces. If
+/// `check_perf` is false, a perf field which does not match the foot is ignored.
 fn parse_feet_and_frames_str(
     matched: regex::Captures,
     given_format: Option<FilmFormat>,
-) -> FramesSourceResult {
+    check_perf: bool,
+) -> Result<FeetFrames, TimecodeParseError> {
     // If we got a match, these groups had to be present, so we can unwrap them.
 
     let feet = timecode_parse::convert_tc_int(matched.name("feet").unwrap().as_str(), "feet")?;
@@ -279,9 +307,10 @@ fn parse_feet_and_frames_str(
         timecode_parse::convert_tc_int(matched.name("frames").unwrap().as_str(), "frames")?;
 
     // Parse perfs field if it was present otherwise pull a Option::None.
-    let perfs_n = matched
-        .name("perf")
-        .and_then(|perfs_n| perfs_n.as_str().parse::<i64>().ok());
+    let perfs_n = match matched.name("perf") {
+        Some(perfs_n) => Some(timecode_parse::convert_tc_int(perfs_n.as_str(), "perf")?),
+        None => None,
+    };
 
     // Get whether this value was a negative timecode value.
     let is_negative = matched.name("negative").is_some();
@@ -289,8 +318,12 @@ fn parse_feet_and_frames_str(
     // Infer the format of the footage if it hasn't been provided.
     let final_format : Result<FilmFormat, TimecodeParseError> = match (given_format, perfs_n) {
         (Some(film_format) , Some(_)) if !film_format.allows_perf_field()  => Err(TimecodeParseError::UnknownStrFormat(
-             format!("Perf field was present in string \"{}\", which is not allowed for given film format {:?}.", 
-                 matched.get(0).unwrap().as_str(), film_format)
+             ParseDiagnostic::new(
+                 matched.get(0).unwrap().as_str(),
+                 format!("Perf field was present in string \"{}\", which is not allowed for given film format {:?}.",
+                     matched.get(0).unwrap().as_str(), film_format),
+             )
+             .with_span(matched.name("perf").unwrap().range())
              )
             ),
         (Some(film_format), _) => Ok(film_format),
@@ -298,42 +331,89 @@ fn parse_feet_and_frames_str(
         (_, _) => Ok(FilmFormat::FF35mm4perf),
     };
 
-    if let Ok(final_format) = final_format {
-        // If the number of perfs in a foot is evenly divisible in perfs in a frame,
-        // this will be the same as the final footage count. If not (as in 35mm 3 perf),
-        // there will be a couple feet left over.
-
-        // We set up `rem_frames` with `frames` from the string. This will accumulate
-        // our final result.
-        let mut rem_frames = frames;
-
-        // We obtain the count of integral footage moduli in the `feet` count with floor
-        // division.
-        let footage_moduli = div_floor(feet, final_format.footage_modulus_footage_count());
-
-        // There may be feet left over, because we took the floor value.
-        let mut rem_feet = feet - (footage_moduli * final_format.footage_modulus_footage_count());
+    let final_format = final_format?;
+
+    // A foot can only start part way through a frame, so the perf field is less than the perfs
+    // in a frame.
+    if let Some(perfs_n) = perfs_n {
+        let perfs_per_frame = final_format.perfs_per_frame();
+        if perfs_n >= perfs_per_frame {
+            let input = matched.get(0).unwrap().as_str();
+            return Err(TimecodeParseError::SectionOutOfRange(
+                ParseDiagnostic::new(
+                    input,
+                    format!(
+                        "perf field of {} must be less than {} perfs per frame in '{}'",
+                        perfs_n, perfs_per_frame, input,
+                    ),
+                )
+                .with_span(matched.name("perf").unwrap().range())
+                .with_expected(0..=perfs_per_frame - 1),
+            ));
+        }
 
-        // Add all the frames in the footage_moduli.
-        rem_frames += footage_moduli * final_format.footage_modulus_frame_count();
+        // The perf field is set by the foot, so a different one means a mistyped footage.
+        let expected = feet % final_format.footage_modulus_footage_count();
+        if check_perf && perfs_n != expected {
+            let input = matched.get(0).unwrap().as_str();
+            return Err(TimecodeParseError::UnknownStrFormat(
+                ParseDiagnostic::new(
+                    input,
+                    format!(
+                        "perf field of {} does not match foot {} in '{}', expected {}",
+                        perfs_n, feet, input, expected,
+                    ),
+                )
+                .with_span(matched.name("perf").unwrap().range())
+                .with_suggestion(format!(
+                    "{}{}+{}.{}",
+                    if is_negative { "-" } else { "" },
+                    matched.name("feet").unwrap().as_str(),
+                    matched.name("frames").unwrap().as_str(),
+                    expected,
+                )),
+            ));
+        }
+    }
 
-        // If there WEREN'T any feet left over, we can just continue, but if there were,
-        // we have to step through each remaining foot in the modulus and add the
-        // leftover frames in those feet to rem_frames.
-        while rem_feet > 0 {
-            rem_frames += final_format.footage_modulus_frame_count()
-                / final_format.footage_modulus_footage_count();
-            rem_feet -= 1;
+    // A frame is counted in the foot it ends in, so the frames before this foot are the
+    // whole frames which fit in the perfs before it. If the number of perfs in a foot is
+    // evenly divisible by perfs in a frame, this is simply feet * frames per foot.
+    let frame_count = feet
+        .checked_mul(final_format.perfs_per_foot())
+        .and_then(|feet_perfs| {
+            div_floor(feet_perfs, final_format.perfs_per_frame()).checked_add(frames)
+        })
+        // The pieces are rebuilt from the perfs up to the end of the last frame, so those need
+        // to fit as well.
+        .filter(|frame_count| {
+            frame_count
+                .checked_add(1)
+                .and_then(|count| count.checked_mul(final_format.perfs_per_frame()))
+                .is_some()
+        });
+    let mut frame_count = match frame_count {
+        Some(frame_count) => frame_count,
+        None => {
+            let input = matched.get(0).unwrap().as_str();
+            return Err(TimecodeParseError::Conversion(
+                ParseDiagnostic::new(
+                    input,
+                    format!("'{}' is too large to count in frames", input),
+                )
+                .with_span(
+                    matched.name("feet").unwrap().start()..matched.name("frames").unwrap().end(),
+                ),
+            ));
         }
+    };
 
-        // Negate if indicated.
-        if is_negative {
-            rem_frames = -rem_frames;
-        };
+    // Negate if indicated.
+    if is_negative {
+        frame_count = -frame_count;
+    };
 
-        // We divide by perfs per frame to obtain the final frame count value
-        Ok(dbg!(rem_frames))
-    } else {
-        Err(final_format.err().unwrap())
-    }
+    // Rebuild the pieces from the frame count, so values which overflow a foot come back in
+    // their usual form.
+    Ok(FeetFrames::with_frames(frame_count, final_format))
 }
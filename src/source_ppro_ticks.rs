@@ -1,4 +1,4 @@
-use crate::{Framerate, TimecodeParseError};
+use crate::{Framerate, ParseDiagnostic, TimecodeParseError};
 use std::convert::TryFrom;
 use std::fmt::Debug;
 
@@ -38,9 +38,9 @@ impl PremiereTicksSource for u64 {
         let i64_val = match i64::try_from(*self) {
             Ok(converted) => converted,
             Err(err) => {
-                return Err(TimecodeParseError::Conversion(format!(
-                    "error converting u64 to i64 : {}",
-                    err
+                return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
+                    format!("error converting u64 to i64 : {}", err),
                 )))
             }
         };
@@ -1,4 +1,7 @@
-use crate::{timecode_parse::round_seconds_to_frame, Framerate, TimecodeParseError};
+use crate::{
+    timecode_parse::round_seconds_to_frame, Framerate, ParseDiagnostic, RoundingMode,
+    TimecodeParseError,
+};
 use core::result::Result;
 use core::result::Result::Ok;
 use num::Rational32;
@@ -54,9 +57,10 @@ impl SecondsSource for f64 {
         // overflow.
         let rat32 = match Rational32::from_f64(*self) {
             None => {
-                return Err(TimecodeParseError::Conversion(
+                return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                    self.to_string(),
                     "could not convert f64 to Rational64".to_string(),
-                ))
+                )))
             }
             Some(parsed) => parsed,
         };
@@ -81,10 +85,13 @@ impl SecondsSource for &str {
             return parse_runtime_str(matched, rate);
         }
 
-        Err(TimecodeParseError::UnknownStrFormat(format!(
-            "{} is not a known seconds timecode format",
-            self
-        )))
+        Err(TimecodeParseError::UnknownStrFormat(
+            ParseDiagnostic::new(
+                *self,
+                format!("{} is not a known seconds timecode format", self),
+            )
+            .with_span(0..self.len()),
+        ))
     }
 }
 
@@ -145,9 +152,9 @@ fn parse_runtime_str(matched: regex::Captures, rate: Framerate) -> SecondsSource
     let seconds_fractal = match seconds_fractal_str.parse::<f64>() {
         Ok(parsed) => parsed,
         Err(err) => {
-            return Err(TimecodeParseError::Conversion(format!(
-                "error conversion seconds of runtime to f64: {}",
-                err
+            return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                seconds_str,
+                format!("error conversion seconds of runtime to f64: {}", err),
             )))
         }
     };
@@ -158,9 +165,10 @@ fn parse_runtime_str(matched: regex::Captures, rate: Framerate) -> SecondsSource
     // don't cause an overfow when we add it to the seconds value.
     let seconds_fractal_rat32 = match Rational32::from_f64(seconds_fractal) {
         None => {
-            return Err(TimecodeParseError::Conversion(
+            return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                seconds_str,
                 "error conversion fractal seconds of runtime to rational".to_string(),
-            ))
+            )))
         }
         Some(parsed) => parsed,
     };
@@ -173,7 +181,8 @@ fn parse_runtime_str(matched: regex::Captures, rate: Framerate) -> SecondsSource
     // We're still in danger of getting an overflow here with large numbers that could have complex
     // time bases, so before we add the fractal seconds to our whole seconds, we're going to bring
     // the fractal value into the corrct base, THEN add it.
-    seconds_fractal_rat64 = round_seconds_to_frame(seconds_fractal_rat64, rate);
+    seconds_fractal_rat64 =
+        round_seconds_to_frame(seconds_fractal_rat64, rate, RoundingMode::HalfUp);
 
     // Which we can combine with the integer-calculated seconds to get a full rational
     // value of our seconds.
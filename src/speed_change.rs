@@ -0,0 +1,124 @@
+use num::Rational64;
+
+use crate::rounding::round_to_integer;
+use crate::{Framerate, RoundingMode};
+
+#[allow(unused)]
+// we need this here for the doc links, but clippy doesnt like that it isn't being used in code.
+use crate::{Timecode, TimecodeRange};
+
+/**
+A change in playback speed, from footage made at one [Framerate] being played back at another.
+
+Moving a 24 fps feature to 25 fps PAL plays every frame 4.1% faster, and pulling 24 fps film
+down to 23.98 for NTSC plays it 0.1% slower. The frames do not change, but every duration does,
+and any audio has to be sped up or slowed down to stay in sync.
+
+Use [Timecode::rebase] to keep the frame count of footage at the new rate. Use
+[Timecode::change_speed] and [TimecodeRange::change_speed] to find how long something lasts
+after the change, and [SpeedChange::sample_rate] to keep audio in sync.
+
+# Examples
+
+```rust
+use vtc::{SpeedChange, RoundingMode, Timecode, rates};
+use num::Rational64;
+
+let pal = SpeedChange::new(rates::F24, rates::F25);
+assert_eq!(Rational64::new(25, 24), pal.factor());
+
+// A 1-hour feature runs 57 minutes and 36 seconds after the PAL speed-up.
+let feature = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+let sped_up = feature.change_speed(pal, RoundingMode::HalfUp);
+assert_eq!("00:57:36:00", sped_up.timecode());
+assert_eq!("00:57:36.0", sped_up.runtime(1));
+
+// Audio recorded at 48048 Hz on a 24 fps set plays back at 48000 Hz once the picture is
+// pulled down to 23.98.
+let pull_down = SpeedChange::new(rates::F24, rates::F23_98);
+assert_eq!(Rational64::from_integer(48000), pull_down.sample_rate(48048));
+assert_eq!(Rational64::from_integer(48048), pull_down.inverse().sample_rate(48000));
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpeedChange {
+    from: Framerate,
+    to: Framerate,
+}
+
+impl SpeedChange {
+    /// Creates a new [SpeedChange] for footage made at `from` being played back at `to`.
+    pub fn new(from: Framerate, to: Framerate) -> Self {
+        SpeedChange { from, to }
+    }
+
+    /// Returns the rate the footage was made at.
+    pub fn from(&self) -> Framerate {
+        self.from
+    }
+
+    /// Returns the rate the footage is played back at.
+    pub fn to(&self) -> Framerate {
+        self.to
+    }
+
+    /// Returns how many times faster the footage plays, like `25/24` for a PAL speed-up or
+    /// `1000/1001` for an NTSC pull-down.
+    pub fn factor(&self) -> Rational64 {
+        self.to.playback() / self.from.playback()
+    }
+
+    /// Returns the [SpeedChange] which undoes this one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{SpeedChange, rates};
+    /// use num::Rational64;
+    /// let pull_up = SpeedChange::new(rates::F23_98, rates::F24).inverse();
+    /// assert_eq!(Rational64::new(1000, 1001), pull_up.factor());
+    /// ```
+    pub fn inverse(&self) -> Self {
+        SpeedChange {
+            from: self.to,
+            to: self.from,
+        }
+    }
+
+    /// Returns the duration in seconds that `seconds` of footage lasts after the change.
+    pub fn seconds(&self, seconds: Rational64) -> Rational64 {
+        seconds / self.factor()
+    }
+
+    /// Returns the sample rate audio recorded at `rate` plays back at to stay in sync with the
+    /// picture, like 48048 Hz becoming 48000 Hz for an NTSC pull-down.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{SpeedChange, rates};
+    /// use num::Rational64;
+    /// let pal = SpeedChange::new(rates::F24, rates::F25);
+    /// assert_eq!(Rational64::from_integer(50000), pal.sample_rate(48000));
+    /// ```
+    pub fn sample_rate(&self, rate: i64) -> Rational64 {
+        Rational64::from_integer(rate) * self.factor()
+    }
+
+    /// Returns how many samples audio with `count` samples must be resampled to, to stay in
+    /// sync with the picture at its original sample rate. Partial samples are snapped using
+    /// `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{SpeedChange, RoundingMode, rates};
+    /// let pal = SpeedChange::new(rates::F24, rates::F25);
+    ///
+    /// // 1 second of 48kHz audio lasts 0.96 seconds after a PAL speed-up.
+    /// assert_eq!(46080, pal.samples(48000, RoundingMode::HalfUp));
+    /// ```
+    pub fn samples(&self, count: i64, mode: RoundingMode) -> i64 {
+        round_to_integer(Rational64::from_integer(count) / self.factor(), mode)
+    }
+}
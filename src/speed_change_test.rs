@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use crate::{rates, Framerate, RoundingMode, SpeedChange, Timecode};
+    use num::Rational64;
+    use rstest::rstest;
+
+    struct SpeedChangeCase {
+        from: Framerate,
+        to: Framerate,
+        factor: Rational64,
+        duration: &'static str,
+        changed: &'static str,
+        sample_rate: i64,
+        changed_sample_rate: Rational64,
+    }
+
+    /// tests speed changes between common picture rates, and the audio changes which go with
+    /// them.
+    #[rstest]
+    #[case::pal_speed_up(SpeedChangeCase{
+        from: rates::F24,
+        to: rates::F25,
+        factor: Rational64::new(25, 24),
+        duration: "01:00:00:00",
+        changed: "00:57:36:00",
+        sample_rate: 48000,
+        changed_sample_rate: Rational64::from_integer(50000),
+    })]
+    #[case::pal_slow_down(SpeedChangeCase{
+        from: rates::F25,
+        to: rates::F24,
+        factor: Rational64::new(24, 25),
+        duration: "00:57:36:00",
+        changed: "01:00:00:00",
+        sample_rate: 48000,
+        changed_sample_rate: Rational64::from_integer(46080),
+    })]
+    #[case::ntsc_pull_down(SpeedChangeCase{
+        from: rates::F24,
+        to: rates::F23_98,
+        factor: Rational64::new(1000, 1001),
+        duration: "01:00:00:00",
+        changed: "01:00:03:14",
+        sample_rate: 48048,
+        changed_sample_rate: Rational64::from_integer(48000),
+    })]
+    #[case::ntsc_pull_up(SpeedChangeCase{
+        from: rates::F29_97_NDF,
+        to: rates::F30,
+        factor: Rational64::new(1001, 1000),
+        duration: "01:00:03:18",
+        changed: "01:00:00:00",
+        sample_rate: 48000,
+        changed_sample_rate: Rational64::from_integer(48048),
+    })]
+    #[case::none(SpeedChangeCase{
+        from: rates::F23_98,
+        to: rates::F23_98,
+        factor: Rational64::from_integer(1),
+        duration: "01:00:00:00",
+        changed: "01:00:00:00",
+        sample_rate: 48000,
+        changed_sample_rate: Rational64::from_integer(48000),
+    })]
+    fn test_speed_change(#[case] case: SpeedChangeCase) {
+        let speed = SpeedChange::new(case.from, case.to);
+        assert_eq!(case.factor, speed.factor(), "factor");
+        assert_eq!(
+            Rational64::from_integer(1) / case.factor,
+            speed.inverse().factor(),
+            "inverse factor"
+        );
+
+        let tc = Timecode::with_frames(case.duration, case.from).unwrap();
+        let changed = tc.change_speed(speed, RoundingMode::HalfUp);
+        assert_eq!(case.changed, changed.timecode(), "changed");
+        assert_eq!(case.from, changed.rate(), "changed rate");
+
+        assert_eq!(
+            case.changed_sample_rate,
+            speed.sample_rate(case.sample_rate),
+            "sample rate"
+        );
+    }
+
+    #[rstest]
+    #[case::half_up(RoundingMode::HalfUp, 501)]
+    #[case::half_even(RoundingMode::HalfEven, 500)]
+    #[case::floor(RoundingMode::Floor, 500)]
+    #[case::ceil(RoundingMode::Ceil, 501)]
+    fn test_speed_change_samples(#[case] mode: RoundingMode, #[case] expected: i64) {
+        // 500 samples slowed down by 0.1% last as long as 500.5 samples did.
+        let pull_down = SpeedChange::new(rates::F24, rates::F23_98);
+        assert_eq!(expected, pull_down.samples(500, mode));
+        assert_eq!(48048, pull_down.samples(48000, mode));
+    }
+
+    #[test]
+    fn test_speed_change_rounding() {
+        let tc = Timecode::with_frames(1, rates::F24).unwrap();
+        let pal = SpeedChange::new(rates::F24, rates::F25);
+
+        assert_eq!(1, tc.change_speed(pal, RoundingMode::HalfUp).frames());
+        assert_eq!(0, tc.change_speed(pal, RoundingMode::Floor).frames());
+    }
+}
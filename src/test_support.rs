@@ -0,0 +1,21 @@
+use crate::{rates, Framerate, FramesSource, Timecode, TimecodeRange};
+
+/// Returns the 24 fps timecode of `value`.
+pub(crate) fn tc<T: FramesSource>(value: T) -> Timecode {
+    tc_at(value, rates::F24)
+}
+
+/// Returns the timecode of `value` at `rate`.
+pub(crate) fn tc_at<T: FramesSource>(value: T, rate: Framerate) -> Timecode {
+    Timecode::with_frames(value, rate).unwrap()
+}
+
+/// Returns the 24 fps range from `start` up to, but not including, `end`.
+pub(crate) fn range<T: FramesSource>(start: T, end: T) -> TimecodeRange {
+    range_at(start, end, rates::F24)
+}
+
+/// Returns the range from `start` up to, but not including, `end` at `rate`.
+pub(crate) fn range_at<T: FramesSource>(start: T, end: T, rate: Framerate) -> TimecodeRange {
+    TimecodeRange::with_frames(start, end, rate).unwrap()
+}
@@ -1,15 +1,21 @@
-use num::integer::{div_rem, lcm};
+use num::integer::lcm;
 use num::rational::Ratio;
-use num::{abs, FromPrimitive, Rational64, Signed, ToPrimitive, Zero};
+use num::{abs, CheckedDiv, FromPrimitive, Rational64, Signed, ToPrimitive, Zero};
 
 use crate::{
     consts::{
-        PERFS_PER_6INCHES_16, PERFS_PER_FOOT_35, PREMIERE_TICKS_PER_SECOND, SECONDS_PER_HOUR,
-        SECONDS_PER_MINUTE,
+        ISO8601_MAX_PRECISION, PERFS_PER_6INCHES_16, PERFS_PER_FOOT_35, PERFS_PER_FOOT_65,
+        PERFS_PER_FOOT_9_5, PERFS_PER_FOOT_SUPER8, PREMIERE_TICKS_PER_SECOND, SECONDS_PER_HOUR,
+        SECONDS_PER_HOUR_I64, SECONDS_PER_MINUTE, SECONDS_PER_MINUTE_I64,
     },
+    drop_frame,
+    rounding::round_to_integer,
+    source_frames,
     source_ppro_ticks::PremiereTicksSource,
-    timecode_parse::round_seconds_to_frame,
-    Framerate, FramesSource, Ntsc, SecondsSource, TimecodeParseError,
+    timecode_detect, timecode_hfr,
+    timecode_parse::{parse_strict_timecode, round_seconds_to_frame},
+    FeetFrames, Framerate, FramesSource, HfrMode, HfrSections, Ntsc, ParseDiagnostic,
+    Representation, RoundingMode, SecondsSource, SpeedChange, TimecodeFormat, TimecodeParseError,
 };
 use std::ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub};
 use std::{cmp::Ordering, ops::AddAssign};
@@ -37,7 +43,7 @@ pub struct TimecodeSections {
 
 /** Feet and Frames Representations
 
-[[FF35mm4perf]]
+[FilmFormat::FF35mm4perf]
 
 35mm 4-perf film (16 frames per foot). ex: '5400+13'.
 
@@ -58,9 +64,9 @@ reference picture for them.
 - Sound turnover reference picture.
 - Sound turnover change lists.
 
-[[FF35mm3perf]]
+[FilmFormat::FF35mm3perf]
 
-35mm film with 3-perf per-frame pulldown (64 perforations per foot). ex: '12+01.1'.
+35mm film with 3-perf per-frame pulldown (64 perforations per foot). ex: '13+01.1'.
 
 # What it is
 
@@ -72,7 +78,7 @@ a period, indicating how many perfs were cut off the first frame of this foot.
 
 Avid cutlists and pull lists on Avid 3 perf projects.
 
-[[FF35mm2perf]]
+[FilmFormat::FF35mm2perf]
 
 # What it is
 
@@ -90,7 +96,7 @@ usually marketed as "Techniscope", the original trademark for Technicolor Italia
 to digital filmmaking, and is used on some contemporary films to obtain a film look
 while keeping stock and processing costs down.
 
-[[FF16mm]]
+[FilmFormat::FF16mm]
 
 # What it is
 
@@ -102,6 +108,52 @@ frames per code, so the footage "1+19" is succeeded by "2+0".
 
 16mm telecines, 16mm edge codes.
 
+[FilmFormat::FF65mm5perf], [FilmFormat::FF65mm8perf] and [FilmFormat::FF70mmImax15perf]
+
+# What it is
+
+Large format negative runs horizontally or vertically through the camera at 64 perforations per
+foot, like 35mm, but with larger frames: 12.8 frames per foot for 5-perf, 8 for 8-perf and
+4.27 for 15-perf IMAX. As with 3-perf, feet and frames do not line up, so footages carry a perf
+marker.
+
+# Where you see it
+
+65mm and IMAX camera reports, lab reports and pull lists.
+
+[FilmFormat::FF35mm8perf]
+
+# What it is
+
+VistaVision runs 35mm film horizontally through the camera, 8 perforations to a frame, giving 8
+frames per foot.
+
+# Where you see it
+
+VFX plate photography and VistaVision camera reports.
+
+[FilmFormat::FFSuper8] and [FilmFormat::FF9_5mm]
+
+# What it is
+
+Small gauge film with one perforation per frame: 72 frames per foot for Super 8 and 40 for
+9.5mm.
+
+# Where you see it
+
+Archive and home movie transfers.
+
+[FilmFormat::Custom]
+
+Any other gauge or pulldown can be described with a [FilmFormatSpec].
+
+```rust
+use vtc::{FilmFormat, FilmFormatSpec, Timecode, rates};
+
+let spec = FilmFormatSpec::new(6, 64).unwrap().with_perf_field(false);
+let tc = Timecode::with_frames(24, rates::F24).unwrap();
+assert_eq!("2+03", tc.feet_and_frames(FilmFormat::Custom(spec)));
+```
 */
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum FilmFormat {
@@ -113,18 +165,50 @@ pub enum FilmFormat {
     FF35mm2perf,
     /// 16mm footage
     FF16mm,
+    /// 65mm, 5-perf footage
+    FF65mm5perf,
+    /// 65mm, 8-perf footage
+    FF65mm8perf,
+    /// 70mm, 15-perf IMAX footage
+    FF70mmImax15perf,
+    /// 35mm, 8-perf VistaVision footage
+    FF35mm8perf,
+    /// Super 8 footage
+    FFSuper8,
+    /// 9.5mm footage
+    FF9_5mm,
+    /// User-defined footage
+    Custom(FilmFormatSpec),
 }
 
 impl FilmFormat {
+    /// Returns the [FilmFormatSpec] describing the format.
+    pub fn spec(&self) -> FilmFormatSpec {
+        let (perfs_per_frame, perfs_per_foot) = match self {
+            FilmFormat::FF35mm4perf => (4, PERFS_PER_FOOT_35),
+            FilmFormat::FF35mm3perf => (3, PERFS_PER_FOOT_35),
+            FilmFormat::FF35mm2perf => (2, PERFS_PER_FOOT_35),
+            FilmFormat::FF16mm => (1, PERFS_PER_6INCHES_16),
+            FilmFormat::FF65mm5perf => (5, PERFS_PER_FOOT_65),
+            FilmFormat::FF65mm8perf => (8, PERFS_PER_FOOT_65),
+            FilmFormat::FF70mmImax15perf => (15, PERFS_PER_FOOT_65),
+            FilmFormat::FF35mm8perf => (8, PERFS_PER_FOOT_35),
+            FilmFormat::FFSuper8 => (1, PERFS_PER_FOOT_SUPER8),
+            FilmFormat::FF9_5mm => (1, PERFS_PER_FOOT_9_5),
+            FilmFormat::Custom(spec) => return *spec,
+        };
+
+        FilmFormatSpec {
+            perfs_per_frame,
+            perfs_per_foot,
+            perf_field: perfs_per_foot % perfs_per_frame != 0,
+        }
+    }
+
     /// Utility function mapping self to number
     /// of perfs per (logical) foot in this case.
     pub fn perfs_per_foot(&self) -> i64 {
-        match self {
-            FilmFormat::FF16mm => PERFS_PER_6INCHES_16,
-            FilmFormat::FF35mm4perf | FilmFormat::FF35mm3perf | FilmFormat::FF35mm2perf => {
-                PERFS_PER_FOOT_35
-            }
-        }
+        self.spec().perfs_per_foot
     }
 
     /// Fewest number of perfs required to complete an integral
@@ -148,24 +232,85 @@ impl FilmFormat {
     /// Utility function mapping self to number of
     /// perfs per frame in this case.
     pub fn perfs_per_frame(&self) -> i64 {
-        match self {
-            FilmFormat::FF16mm => 1,
-            FilmFormat::FF35mm4perf => 4,
-            FilmFormat::FF35mm3perf => 3,
-            FilmFormat::FF35mm2perf => 2,
-        }
+        self.spec().perfs_per_frame
     }
 
     /// Utility function indicating if the format requires
     /// a perf field in footage string representations.
     pub fn allows_perf_field(&self) -> bool {
-        self.perfs_per_foot() % self.perfs_per_frame() != 0
+        self.spec().perf_field
+    }
+}
+
+/**
+The perforation counts which describe a [FilmFormat], used to define gauges and pulldowns which
+do not ship with this crate through [FilmFormat::Custom].
+
+# Examples
+
+```rust
+use vtc::{FeetFramesStr, FilmFormat, FilmFormatSpec, Timecode, rates};
+
+// 35mm, 6-perf.
+let spec = FilmFormatSpec::new(6, 64).unwrap();
+let format = FilmFormat::Custom(spec);
+
+assert!(format.allows_perf_field());
+
+let tc = Timecode::with_frames(FeetFramesStr::new("3+02.0", format), rates::F24).unwrap();
+assert_eq!(34, tc.frames());
+assert_eq!("3+02.0", tc.feet_and_frames(format));
+```
+*/
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FilmFormatSpec {
+    perfs_per_frame: i64,
+    perfs_per_foot: i64,
+    perf_field: bool,
+}
+
+impl FilmFormatSpec {
+    /// Creates a new [FilmFormatSpec] which shows a perf field if frames do not fit evenly into
+    /// a foot.
+    ///
+    /// Returns [None] if either count is not positive.
+    pub fn new(perfs_per_frame: i64, perfs_per_foot: i64) -> Option<Self> {
+        if perfs_per_frame <= 0 || perfs_per_foot <= 0 {
+            return None;
+        }
+
+        Some(FilmFormatSpec {
+            perfs_per_frame,
+            perfs_per_foot,
+            perf_field: perfs_per_foot % perfs_per_frame != 0,
+        })
+    }
+
+    /// Sets whether footage strings show a perf field.
+    pub fn with_perf_field(mut self, perf_field: bool) -> Self {
+        self.perf_field = perf_field;
+        self
+    }
+
+    /// Returns the number of perfs in a frame.
+    pub fn perfs_per_frame(&self) -> i64 {
+        self.perfs_per_frame
+    }
+
+    /// Returns the number of perfs in a (logical) foot.
+    pub fn perfs_per_foot(&self) -> i64 {
+        self.perfs_per_foot
+    }
+
+    /// Returns whether footage strings show a perf field.
+    pub fn perf_field(&self) -> bool {
+        self.perf_field
     }
 }
 
 /// A struct that bundles a str together with a format, to hint parsing.
 ///
-/// This struct, which implelements [FrameSource], allows you to include
+/// This struct, which implelements [FramesSource], allows you to include
 /// a format hint to the footage parser, which will override its default
 /// inference.
 #[derive(Debug)]
@@ -179,6 +324,65 @@ impl<'a> FeetFramesStr<'a> {
     pub fn new(input: &'a str, format: FilmFormat) -> Self {
         FeetFramesStr { input, format }
     }
+
+    /// Parses the string into its individual pieces, without needing a [Framerate].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a feet and frames footage in the given format, or
+    /// its perf field does not match its feet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{FeetFramesStr, FilmFormat, TimecodeParseError};
+    ///
+    /// let footage = FeetFramesStr::new("13+01.1", FilmFormat::FF35mm3perf).parse().unwrap();
+    /// assert_eq!(13, footage.feet());
+    /// assert_eq!(1, footage.frames());
+    /// assert_eq!(Some(1), footage.perf_marker());
+    /// assert_eq!(278, footage.frame_count());
+    ///
+    /// let err = FeetFramesStr::new("12+01.1", FilmFormat::FF35mm3perf).parse().unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::UnknownStrFormat(_)));
+    /// assert_eq!(Some("12+01.0"), err.diagnostic().suggestion());
+    /// ```
+    pub fn parse(&self) -> Result<FeetFrames, TimecodeParseError> {
+        source_frames::parse_feet_and_frames(self.input, Some(self.format))
+    }
+}
+
+/**
+How a [Timecode] is treated when it runs past 24 hours, or before 00:00:00:00.
+
+Timecode was designed to label frames on a clock which rolls over at midnight, but long
+recordings, multi-day events and arithmetic on timecode values can all run past 24 hours.
+[Rollover] selects how such values are represented, and is applied the same way to drop-frame
+and non-drop-frame rates.
+
+# Examples
+
+```rust
+use vtc::{Timecode, Rollover, rates};
+let tc = Timecode::with_frames("34:46:26:04", rates::F23_98).unwrap();
+
+assert_eq!("34:46:26:04", tc.timecode_rollover(Rollover::Unbounded));
+assert_eq!("10:46:26:04", tc.timecode_rollover(Rollover::Wrap24Hours));
+assert_eq!("1:10:46:26:04", tc.timecode_rollover(Rollover::Days));
+```
+*/
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Rollover {
+    /// Hours count up without bound, and negative values keep a leading `-`: `34:46:26:04`.
+    /// This is how [Timecode::timecode] has always behaved.
+    #[default]
+    Unbounded,
+    /// Wrap at 24 hours like a wall clock: `34:46:26:04` becomes `10:46:26:04`, and
+    /// `-00:00:00:01` becomes `23:59:59:23`.
+    Wrap24Hours,
+    /// Whole days are split off and prefixed to the timecode: `34:46:26:04` becomes
+    /// `1:10:46:26:04`. Values under 24 hours are formatted without a day section.
+    Days,
 }
 
 /// The [Result] type returned by [Timecode::with_seconds], [Timecode::with_frames], and
@@ -316,6 +520,37 @@ tc %= 1.65;
 assert_eq!("00:00:00:01", tc.timecode())
 ```
 
+## Change Framerates
+
+There are three ways to move a [Timecode] to a new [Framerate], each keeping a different
+value the same:
+
+| Method                  | Keeps                      | Changes                    |
+|-------------------------|----------------------------|----------------------------|
+| [Timecode::rebase]      | Frame count                | Real-world time and label  |
+| [Timecode::convert_rate]| Real-world time, to a frame| Frame count and label      |
+| [Timecode::relabel]     | `HH:MM:SS:FF` label        | Frame count and real time  |
+
+Rebasing is for reinterpreting footage, like a 24 fps file played back at 25. Converting is for
+rendering footage to a new rate, like a standards conversion. Relabeling is for fixing metadata,
+like a 29.97 DF tape logged as 29.97 NDF.
+
+```rust
+use vtc::{Timecode, RoundingMode, rates};
+let tc = Timecode::with_frames("01:00:00;00", rates::F29_97_DF).unwrap();
+
+let rebased = tc.rebase(rates::F25);
+assert_eq!("01:11:55:17", rebased.timecode());
+assert_eq!(tc.frames(), rebased.frames());
+
+let converted = tc.convert_rate(rates::F25, RoundingMode::HalfUp);
+assert_eq!("01:00:00:00", converted.timecode());
+
+let relabeled = tc.relabel(rates::F29_97_NDF).unwrap();
+assert_eq!("01:00:00:00", relabeled.timecode());
+assert_eq!(108000, relabeled.frames());
+```
+
 */
 #[derive(Clone, Copy, Debug)]
 pub struct Timecode {
@@ -389,7 +624,7 @@ impl Timecode {
 
         if self.rate.ntsc() == Ntsc::DropFrame {
             // Convert the frame number to an adjusted one for drop-frame display values.
-            frames_int += drop_frame_adjustment(frames_int, self.rate);
+            frames_int = drop_frame::frames_to_label(frames_int, timebase.to_integer());
         }
 
         let mut frames = Rational64::from_integer(frames_int);
@@ -458,6 +693,188 @@ impl Timecode {
         )
     }
 
+    /// Returns the formatted SMPTE timecode, with values past 24 hours or below zero represented
+    /// according to `rollover`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, Rollover, rates};
+    /// let tc = Timecode::with_frames("-00:00:00:01", rates::F24).unwrap();
+    /// assert_eq!("-00:00:00:01", tc.timecode_rollover(Rollover::Unbounded));
+    /// assert_eq!("23:59:59:23", tc.timecode_rollover(Rollover::Wrap24Hours));
+    /// assert_eq!("-00:00:00:01", tc.timecode_rollover(Rollover::Days));
+    ///
+    /// let tc = Timecode::with_frames("48:00:00;00", rates::F29_97_DF).unwrap();
+    /// assert_eq!("48:00:00;00", tc.timecode_rollover(Rollover::Unbounded));
+    /// assert_eq!("00:00:00;00", tc.timecode_rollover(Rollover::Wrap24Hours));
+    /// assert_eq!("2:00:00:00;00", tc.timecode_rollover(Rollover::Days));
+    /// ```
+    pub fn timecode_rollover(&self, rollover: Rollover) -> String {
+        match rollover {
+            Rollover::Unbounded => self.timecode(),
+            Rollover::Wrap24Hours => self.rollover(Rollover::Wrap24Hours).timecode(),
+            Rollover::Days => {
+                let days = self.days();
+                if days == 0 {
+                    return self.timecode();
+                }
+
+                let frames_per_day = self.rate.frames_per_24_hours();
+                let remainder =
+                    Timecode::with_i64_frames(abs(self.frames()) % frames_per_day, self.rate);
+                let sign = if self.seconds.is_negative() { "-" } else { "" };
+
+                format!("{}{}:{}", sign, days, remainder.timecode())
+            }
+        }
+    }
+
+    /// Returns the timecode formatted according to a custom [TimecodeFormat].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Framerate, Ntsc, Timecode, TimecodeFormat};
+    /// let rate = Framerate::with_playback(120, Ntsc::None).unwrap();
+    ///
+    /// let tc = Timecode::with_frames("01:00:00:119", rate).unwrap();
+    /// assert_eq!("01:00:00:119", tc.format(&TimecodeFormat::default()));
+    ///
+    /// let tc = Timecode::with_frames("00:00:00:05", rate).unwrap();
+    /// assert_eq!("00:00:00:005", tc.format(&TimecodeFormat::default()));
+    ///
+    /// let avid = TimecodeFormat::default().hour_digits(1).frame_separator('.');
+    /// assert_eq!("0:00:00.005", tc.format(&avid));
+    /// ```
+    pub fn format(&self, format: &TimecodeFormat) -> String {
+        format.apply(self)
+    }
+
+    /// The sections of a high frame rate timecode in its SMPTE ST 12-3 frame group form. See
+    /// [HfrSections].
+    ///
+    /// Returns [None] if the rate is not a high frame rate with an ST 12-1 base rate: above 30
+    /// fps, up to 120 fps, and a whole multiple of 24, 25 or 30 fps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, rates};
+    /// let tc = Timecode::with_frames("01:00:00;59", rates::F59_94_DF).unwrap();
+    /// let sections = tc.hfr_sections().unwrap();
+    /// assert_eq!(2, sections.group_size);
+    /// assert_eq!(29, sections.base.frames);
+    /// assert_eq!(1, sections.index);
+    ///
+    /// let tc = Timecode::with_frames("01:00:00:23", rates::F24).unwrap();
+    /// assert_eq!(None, tc.hfr_sections());
+    /// ```
+    pub fn hfr_sections(&self) -> Option<HfrSections> {
+        timecode_hfr::hfr_sections(self)
+    }
+
+    /// Returns the formatted SMPTE timecode of a high frame rate value in the given
+    /// [HfrMode].
+    ///
+    /// [HfrMode::FullFrames] counts every frame in the frames field, while
+    /// [HfrMode::FrameGroup] keeps the frames field under the ST 12-1 base rate and adds the
+    /// index of the frame in its group, for tools which only understand ST 12-1 timecode.
+    ///
+    /// Returns [None] under the same conditions as [Timecode::hfr_sections].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{HfrMode, Timecode, rates};
+    /// let tc = Timecode::with_frames("01:00:00:119", rates::F120).unwrap();
+    /// assert_eq!(Some("01:00:00:119".to_string()), tc.timecode_hfr(HfrMode::FullFrames));
+    /// assert_eq!(Some("01:00:00:29.3".to_string()), tc.timecode_hfr(HfrMode::FrameGroup));
+    ///
+    /// let tc = Timecode::with_frames("01:00:00:49", rates::F50).unwrap();
+    /// assert_eq!(Some("01:00:00:24.1".to_string()), tc.timecode_hfr(HfrMode::FrameGroup));
+    /// ```
+    pub fn timecode_hfr(&self, mode: HfrMode) -> Option<String> {
+        timecode_hfr::timecode_hfr(self, mode)
+    }
+
+    /// Returns this timecode as an SMPTE ST 12-1 label at half the rate, and the index of the
+    /// frame within the pair of frames sharing that label.
+    ///
+    /// Devices which only carry 30 fps style timecode, like LTC and VITC, label 50p, 59.94p and
+    /// 60p video this way, using the frame pair (field) flag to mark the second frame of each
+    /// pair. Index `0` is the first frame of the pair, and `1` the second.
+    ///
+    /// Returns [None] if the rate does not have exactly two frames for every frame of its ST
+    /// 12-1 base rate. See [Timecode::with_frame_pair] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, TimecodeSections, rates};
+    /// let tc = Timecode::with_frames("00:10:00;03", rates::F59_94_DF).unwrap();
+    /// let (sections, pair_index) = tc.frame_pair().unwrap();
+    /// assert_eq!(
+    ///     TimecodeSections{negative: false, hours: 0, minutes: 10, seconds: 0, frames: 1},
+    ///     sections,
+    /// );
+    /// assert_eq!(1, pair_index);
+    ///
+    /// let tc = Timecode::with_frames("01:00:00:119", rates::F120).unwrap();
+    /// assert_eq!(None, tc.frame_pair());
+    /// ```
+    pub fn frame_pair(&self) -> Option<(TimecodeSections, i64)> {
+        timecode_hfr::frame_pair(self)
+    }
+
+    /// Returns the number of whole 24-hour days this timecode spans, counted away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, rates};
+    /// let tc = Timecode::with_frames("49:00:00:00", rates::F24).unwrap();
+    /// assert_eq!(2, tc.days());
+    ///
+    /// let tc = Timecode::with_frames("-23:59:59:23", rates::F24).unwrap();
+    /// assert_eq!(0, tc.days());
+    /// ```
+    pub fn days(&self) -> i64 {
+        abs(self.frames()) / self.rate.frames_per_24_hours()
+    }
+
+    /// Returns a [Timecode] with `rollover` applied to its value.
+    ///
+    /// [Rollover::Wrap24Hours] wraps the value into the range of `00:00:00:00` to one frame before
+    /// `24:00:00:00`. [Rollover::Unbounded] and [Rollover::Days] only change how a [Timecode] is
+    /// formatted, so the value is returned unchanged.
+    ///
+    /// Arithmetic on [Timecode] is always unbounded. To get wall-clock arithmetic, apply a rollover
+    /// to the result:
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, Rollover, rates};
+    /// let tc = Timecode::with_frames("23:00:00;00", rates::F29_97_DF).unwrap();
+    /// let offset = Timecode::with_frames("02:00:00;00", rates::F29_97_DF).unwrap();
+    ///
+    /// let result = (tc + offset).rollover(Rollover::Wrap24Hours);
+    /// assert_eq!("01:00:00;00", result.timecode());
+    ///
+    /// let result = (offset - tc).rollover(Rollover::Wrap24Hours);
+    /// assert_eq!("03:00:00;00", result.timecode());
+    /// ```
+    pub fn rollover(&self, rollover: Rollover) -> Self {
+        match rollover {
+            Rollover::Wrap24Hours => {
+                let frames = self.frames().rem_euclid(self.rate.frames_per_24_hours());
+                Timecode::with_i64_frames(frames, self.rate)
+            }
+            Rollover::Unbounded | Rollover::Days => *self,
+        }
+    }
+
     /**
     Returns the number of frames that would have elapsed between 00:00:00:00 and this timecode.
 
@@ -568,6 +985,134 @@ impl Timecode {
         )
     }
 
+    /**
+    Returns the real-world duration of the timecode as an ISO 8601 duration string, with the
+    seconds rounded to `precision` decimal places.
+
+    # What it is
+
+    A duration in the `PT{HOURS}H{MINUTES}M{SECONDS}S` form defined by ISO 8601, with empty
+    places left off. Like [Timecode::runtime], it reports true real-world time.
+
+    # Where you see it
+
+    - Broadcast scheduling and playout systems.
+    - Web APIs and metadata formats such as XML Schema and schema.org `duration` fields.
+
+    # Examples
+
+    ```rust
+    # use vtc::{Timecode, rates};
+    let tc = Timecode::with_frames("01:02:03:12", rates::F24).unwrap();
+    assert_eq!("PT1H2M3.5S", tc.iso8601_duration(3));
+
+    let tc = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+    assert_eq!("PT1H3.6S", tc.iso8601_duration(3));
+
+    let tc = Timecode::with_frames(0, rates::F24).unwrap();
+    assert_eq!("PT0S", tc.iso8601_duration(3));
+    ```
+
+    ## note:
+
+    Precisions above 18 decimal places are written with 18 places.
+
+    ISO 8601 does not define negative durations. Negative values are prefixed with `-`, which
+    is a common extension.
+    */
+    pub fn iso8601_duration(&self, precision: usize) -> String {
+        // Round to our precision before splitting into places, so rounding up carries over
+        // into the larger places.
+        // The math is done in i128 so that large values and precisions cannot overflow.
+        let precision = precision.min(ISO8601_MAX_PRECISION);
+        let scale = 10i128.pow(precision as u32);
+        let seconds =
+            Ratio::<i128>::new(*self.seconds.numer() as i128, *self.seconds.denom() as i128);
+        let mut remaining = (seconds.abs() * scale).round().to_integer();
+
+        let scaled_hour = SECONDS_PER_HOUR_I64 as i128 * scale;
+        let scaled_minute = SECONDS_PER_MINUTE_I64 as i128 * scale;
+        let hours = remaining / scaled_hour;
+        remaining %= scaled_hour;
+        let minutes = remaining / scaled_minute;
+        remaining %= scaled_minute;
+        let seconds = remaining / scale;
+        let fract = remaining % scale;
+
+        let mut formatted = String::new();
+        if self.seconds.is_negative() {
+            formatted.push('-');
+        }
+        formatted.push_str("PT");
+        if hours != 0 {
+            formatted.push_str(&format!("{}H", hours));
+        }
+        if minutes != 0 {
+            formatted.push_str(&format!("{}M", minutes));
+        }
+        if seconds != 0 || fract != 0 || (hours == 0 && minutes == 0) {
+            formatted.push_str(&seconds.to_string());
+            if fract != 0 {
+                let fract_str = format!("{:0width$}", fract, width = precision);
+                formatted.push('.');
+                formatted.push_str(fract_str.trim_end_matches('0'));
+            }
+            formatted.push('S');
+        }
+
+        formatted
+    }
+
+    /**
+    Returns the timecode as a human-readable duration, like `1h 2m 3s 4f`.
+
+    # What it is
+
+    The sections of [Timecode::timecode], labeled with units, and with empty places left off.
+    Like [Timecode::timecode], this is counted in timecode time rather than real-world time.
+
+    # Where you see it
+
+    - Production schedules, call sheets and delivery specs.
+
+    # Examples
+
+    ```rust
+    # use vtc::{DurationStr, Timecode, rates};
+    let tc = Timecode::with_frames("01:02:03:04", rates::F23_98).unwrap();
+    assert_eq!("1h 2m 3s 4f", tc.human_duration());
+
+    let tc = Timecode::with_frames("-00:01:30:00", rates::F24).unwrap();
+    assert_eq!("-1m 30s", tc.human_duration());
+
+    // The output can be parsed back with DurationStr.
+    let parsed = Timecode::with_frames(DurationStr::new("-1m 30s"), rates::F24).unwrap();
+    assert_eq!(tc, parsed);
+    ```
+    */
+    pub fn human_duration(&self) -> String {
+        let sections = self.sections();
+
+        let places = [
+            (sections.hours, "h"),
+            (sections.minutes, "m"),
+            (sections.seconds, "s"),
+            (sections.frames, "f"),
+        ];
+        let parts: Vec<String> = places
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, unit)| format!("{}{}", value, unit))
+            .collect();
+
+        if parts.is_empty() {
+            return "0f".to_string();
+        }
+
+        let sign = if sections.negative { "-" } else { "" };
+        format!("{}{}", sign, parts.join(" "))
+    }
+
     /**
     Returns the number of elapsed ticks this timecode represents in Adobe Premiere Pro.
 
@@ -630,7 +1175,7 @@ impl Timecode {
 
     The `rep` parameter selects the film format. 35mm, 4-perf vertical
     pulldown is by far the most common, but other film gauges and pulldowns
-    are supported. See the [FeetFramesRep] enum for all available formats.
+    are supported. See the [FilmFormat] enum for all available formats.
 
     # Where you see it
 
@@ -640,42 +1185,26 @@ impl Timecode {
 
     */
     pub fn feet_and_frames(&self, rep: FilmFormat) -> String {
-        // get the frame count and sign
-        let total_frames = abs(self.frames());
-        let negative = self.seconds.is_negative();
-
-        let total_perfs = total_frames * rep.perfs_per_frame();
-
-        // The foot a frame lies in, by convention, is where the frame _ends_,
-        // not where it begins. If we add rep.perfs_per_frame() -1 to the perf
-        // count, we get the last perf of the frame we are on.
-        //
-        // Once we have that, the foot we are on can be obtained with integer
-        // division.
-        let last_perf = total_perfs + rep.perfs_per_frame() - 1;
-        let feet = last_perf / rep.perfs_per_foot();
-        let frames = (last_perf % rep.perfs_per_foot()) / rep.perfs_per_frame();
-
-        let sign = if negative { "-" } else { "" };
-
-        if rep.allows_perf_field() {
-            // The perf marker is simply the modulo of the footage count and the
-            // number of feet in a footage modulus.
-            //
-            // For almost all formats this is always zero, but not in the case of
-            // 3-perf.
-            //
-            // The meaning of the perf marker is obscure but it indicates the
-            // position of the Kodak KeyKode "black dot," a dot that appears
-            // every 32 perfs (6 inches) and witnesses an associated KeyKode latent
-            // edge number. On an Avid the perf marker is represented as a number,
-            // However on window dubs from Evertz TCGs this would appear as a visual
-            // symbol,
-            let perf_marker = feet % rep.footage_modulus_footage_count();
-            format!("{}{}+{:02}.{}", sign, feet, frames, perf_marker)
-        } else {
-            format!("{}{}+{:02}", sign, feet, frames)
-        }
+        self.footage(rep).to_string()
+    }
+
+    /// Returns the feet and frames footage of `self` as its individual pieces. See
+    /// [Timecode::feet_and_frames] for the customary text representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{FilmFormat, Timecode, rates};
+    /// let tc = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let footage = tc.footage(FilmFormat::FF35mm3perf);
+    ///
+    /// assert_eq!(4050, footage.feet());
+    /// assert_eq!(0, footage.frames());
+    /// assert_eq!(Some(0), footage.perf_marker());
+    /// assert_eq!(259200, footage.total_perfs());
+    /// ```
+    pub fn footage(&self, rep: FilmFormat) -> FeetFrames {
+        FeetFrames::with_frames(self.frames(), rep)
     }
 
     /// Returns a [Timecode] with the same number of frames running at a different
@@ -685,6 +1214,11 @@ impl Timecode {
     ///
     /// * `rate` - The new framerate to apply to the frrame count..
     ///
+    /// See [Changing Framerates](Timecode#change-framerates) for how this compares to
+    /// [Timecode::convert_rate] and [Timecode::relabel].
+    ///
+    /// # Examples
+    ///
     /// ```rust
     /// # use vtc::{Timecode, rates};
     /// let tc = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
@@ -695,6 +1229,197 @@ impl Timecode {
         Timecode::with_i64_frames(self.frames(), rate)
     }
 
+    /// Returns a [Timecode] at a different [Framerate] which represents the same real-world
+    /// time, snapped to a frame of the new rate using `mode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The new framerate to quantize the seconds value to.
+    ///
+    /// * `mode` - How to snap seconds which fall between two frames of the new rate.
+    ///
+    /// See [Changing Framerates](Timecode#change-framerates) for how this compares to
+    /// [Timecode::rebase] and [Timecode::relabel].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// let tc = Timecode::with_frames("00:00:00:01", rates::F24).unwrap();
+    ///
+    /// let converted = tc.convert_rate(rates::F25, RoundingMode::Floor);
+    /// assert_eq!("00:00:00:01", converted.timecode());
+    ///
+    /// let converted = tc.convert_rate(rates::F60, RoundingMode::Floor);
+    /// assert_eq!("00:00:00:02", converted.timecode());
+    ///
+    /// let converted = tc.convert_rate(rates::F60, RoundingMode::Ceil);
+    /// assert_eq!("00:00:00:03", converted.timecode());
+    /// ```
+    pub fn convert_rate(&self, rate: Framerate, mode: RoundingMode) -> Self {
+        Timecode::with_rational_seconds_rounded(self.seconds, rate, mode)
+    }
+
+    /// Returns a [Timecode] at a different [Framerate] with the same `HH:MM:SS:FF` label, such
+    /// as a 29.97 DF label read as 29.97 NDF.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The new framerate to read the label at.
+    ///
+    /// See [Changing Framerates](Timecode#change-framerates) for how this compares to
+    /// [Timecode::rebase] and [Timecode::convert_rate].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the label does not exist at the new rate: if its frames are too
+    /// large for the new timebase, or it is a frame number a drop-frame rate skips.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, TimecodeParseError, rates};
+    /// let tc = Timecode::with_frames("00:10:00:00", rates::F29_97_NDF).unwrap();
+    /// let relabeled = tc.relabel(rates::F29_97_DF).unwrap();
+    /// assert_eq!("00:10:00;00", relabeled.timecode());
+    /// assert_eq!(17982, relabeled.frames());
+    ///
+    /// let tc = Timecode::with_frames("00:01:00:00", rates::F29_97_NDF).unwrap();
+    /// let err = tc.relabel(rates::F29_97_DF).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::DropFrameValue(_)));
+    ///
+    /// let tc = Timecode::with_frames("00:00:00:29", rates::F30).unwrap();
+    /// let err = tc.relabel(rates::F24).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::SectionOutOfRange(_)));
+    /// ```
+    pub fn relabel(&self, rate: Framerate) -> TimecodeParseResult {
+        let sections = self.sections();
+
+        let sign = if sections.negative { "-" } else { "" };
+        let frame_sep = if rate.ntsc() == Ntsc::DropFrame {
+            ";"
+        } else {
+            ":"
+        };
+        let label = format!(
+            "{}{:02}:{:02}:{:02}{}{:02}",
+            sign, sections.hours, sections.minutes, sections.seconds, frame_sep, sections.frames,
+        );
+
+        // Parsing would roll frames past the timebase into the next second, giving a different
+        // label, so they need to be caught first.
+        let timebase = rate.timebase().ceil().to_integer();
+        if sections.frames >= timebase {
+            let frames_start = label.len() - format!("{:02}", sections.frames).len();
+            return Err(TimecodeParseError::SectionOutOfRange(
+                ParseDiagnostic::new(
+                    label.as_str(),
+                    format!("{} at {}: frames must be < {}", label, rate, timebase),
+                )
+                .with_span(frames_start..label.len())
+                .with_expected(0..=timebase - 1),
+            ));
+        }
+
+        Timecode::with_frames(label.as_str(), rate)
+    }
+
+    /// Returns how long this [Timecode] lasts after a [SpeedChange], at the same [Framerate].
+    /// Partial frames are snapped using `mode`.
+    ///
+    /// Footage keeps its frame count through a speed change, which [Timecode::rebase] gives.
+    /// This method gives the new real-world length instead, like the runtime of a feature after
+    /// a PAL speed-up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{RoundingMode, SpeedChange, Timecode, rates};
+    /// let tc = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    ///
+    /// let pal = SpeedChange::new(rates::F24, rates::F25);
+    /// let sped_up = tc.change_speed(pal, RoundingMode::HalfUp);
+    /// assert_eq!("00:57:36:00", sped_up.timecode());
+    /// assert_eq!(82944, sped_up.frames());
+    ///
+    /// let pull_down = SpeedChange::new(rates::F24, rates::F23_98);
+    /// let slowed = tc.change_speed(pull_down, RoundingMode::HalfUp);
+    /// assert_eq!("01:00:03:14", slowed.timecode());
+    /// ```
+    pub fn change_speed(&self, speed: SpeedChange, mode: RoundingMode) -> Self {
+        Timecode::with_rational_seconds_rounded(speed.seconds(self.seconds), self.rate, mode)
+    }
+
+    /// Returns this [Timecode] multiplied by `rhs`, snapping the result to a frame using `mode`.
+    ///
+    /// The `*` operator is equivalent to calling this method with [RoundingMode::HalfUp].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// use num::Rational64;
+    /// let tc = Timecode::with_frames(3, rates::F24).unwrap();
+    ///
+    /// let scaled = tc.mul_rounded(Rational64::new(1, 2), RoundingMode::HalfUp);
+    /// assert_eq!(2, scaled.frames());
+    ///
+    /// let scaled = tc.mul_rounded(Rational64::new(1, 2), RoundingMode::Floor);
+    /// assert_eq!(1, scaled.frames());
+    /// ```
+    pub fn mul_rounded(&self, rhs: Rational64, mode: RoundingMode) -> Self {
+        Timecode::with_rational_seconds_rounded(self.seconds * rhs, self.rate, mode)
+    }
+
+    /// Returns the frame count of this [Timecode] divided by `rhs`, snapping the result to a
+    /// frame using `mode`.
+    ///
+    /// The `/` operator is equivalent to calling this method with [RoundingMode::Floor].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// use num::Rational64;
+    /// let tc = Timecode::with_frames(5, rates::F24).unwrap();
+    ///
+    /// let divided = tc.div_rounded(Rational64::from_integer(2), RoundingMode::Floor);
+    /// assert_eq!(2, divided.frames());
+    ///
+    /// let divided = tc.div_rounded(Rational64::from_integer(2), RoundingMode::Ceil);
+    /// assert_eq!(3, divided.frames());
+    /// ```
+    pub fn div_rounded(&self, rhs: Rational64, mode: RoundingMode) -> Self {
+        let frames_rat = Rational64::from_integer(self.frames()) / rhs;
+        Timecode::with_i64_frames(round_to_integer(frames_rat, mode), self.rate)
+    }
+
+    /// Returns the frames left over after [Timecode::div_rounded] is called with the same
+    /// arguments, snapping any partial frame using `mode`.
+    ///
+    /// `self == self.div_rounded(rhs, mode) * rhs + self.rem_rounded(rhs, mode)` whenever `rhs`
+    /// is a whole number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// use num::Rational64;
+    /// let tc = Timecode::with_frames(5, rates::F24).unwrap();
+    ///
+    /// let remainder = tc.rem_rounded(Rational64::from_integer(2), RoundingMode::Floor);
+    /// assert_eq!(1, remainder.frames());
+    ///
+    /// let remainder = tc.rem_rounded(Rational64::from_integer(2), RoundingMode::Ceil);
+    /// assert_eq!(-1, remainder.frames());
+    /// ```
+    pub fn rem_rounded(&self, rhs: Rational64, mode: RoundingMode) -> Self {
+        let frames_rat = Rational64::from_integer(self.frames());
+        let quotient = mode.round(frames_rat / rhs);
+        let remainder = frames_rat - quotient * rhs;
+        Timecode::with_i64_frames(round_to_integer(remainder, mode), self.rate)
+    }
+
     /// Returns the absolute value of the [Timecode] value.
     ///
     /// # Examples
@@ -757,6 +1482,107 @@ impl Timecode {
         Ok(Self::with_i64_frames(frame_count, rate))
     }
 
+    /// Returns a new [Timecode] parsed from a full SMPTE timecode string, rejecting anything
+    /// [Timecode::with_frames] would have to guess at.
+    ///
+    /// [Timecode::with_frames] is forgiving: it accepts partial timecode like `1:12`, sections
+    /// which overflow into the next like `00:00:62:04`, and either separator for any rate. That
+    /// is helpful for user input, but ingest validation needs to know a value is exactly what
+    /// it claims to be. [Timecode::parse_strict] requires:
+    ///
+    /// - No whitespace anywhere in the string.
+    /// - All four sections, each zero-padded to two digits. At rates of 100 fps and above,
+    ///   frames may also be padded to three digits.
+    /// - Hours under 24, minutes and seconds under 60, and frames under the timebase.
+    /// - `:` separators for non-drop-frame rates. Drop-frame rates must have a `;` before the
+    ///   frames, and may use `;` for the other separators.
+    /// - No dropped frame numbers for drop-frame rates.
+    ///
+    /// An optional leading `-` is allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, TimecodeParseError, rates};
+    /// let tc = Timecode::parse_strict("01:00:00:00", rates::F23_98).unwrap();
+    /// assert_eq!(86400, tc.frames());
+    ///
+    /// let tc = Timecode::parse_strict("00:01:00;02", rates::F29_97_DF).unwrap();
+    /// assert_eq!(1800, tc.frames());
+    ///
+    /// let err = Timecode::parse_strict("1:13:4", rates::F23_98).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::MissingSection(_)));
+    ///
+    /// let err = Timecode::parse_strict("00:00:00:24", rates::F24).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::SectionOutOfRange(_)));
+    ///
+    /// let err = Timecode::parse_strict("00:00:00;12", rates::F24).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::SeparatorMismatch(_)));
+    ///
+    /// let err = Timecode::parse_strict("01:00:00:00 ", rates::F24).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::Whitespace(_)));
+    /// ```
+    pub fn parse_strict(input: &str, rate: Framerate) -> TimecodeParseResult {
+        let frame_count = parse_strict_timecode(input, rate)?;
+        Ok(Self::with_i64_frames(frame_count, rate))
+    }
+
+    /// Returns a new [Timecode] parsed from a string of any representation, along with the
+    /// [Representation] it was detected as.
+    ///
+    /// The `&str` [FramesSource] and [SecondsSource] implementations each assume the kind of
+    /// value they are given. [Timecode::parse_any] is for input where that isn't known up front,
+    /// like cells of an imported spreadsheet. It detects:
+    ///
+    /// - SMPTE timecode (drop-frame when `rate` is drop-frame): `01:00:00:00`.
+    /// - Runtime, when there is a decimal place: `01:00:03.6`, `1.5`.
+    /// - Feet and frames, inferring the [FilmFormat] from the perf and frames places: `5400+00`.
+    /// - Frame counts, for whole numbers: `86400`.
+    /// - Premiere ticks, for whole numbers which land exactly on a frame and are too large to
+    ///   be a frame count.
+    /// - Rational seconds: `18018/5`.
+    ///
+    /// Two or three sections, like `1:30`, could be partial timecode or a runtime. If the last
+    /// section is too large to be a frame, it is a runtime. If it is too large to be seconds, it
+    /// is timecode. Otherwise [TimecodeParseError::Ambiguous] is returned. The same error is
+    /// returned when a feet+frames value could be 35mm 2-perf or 16mm, or a whole number could
+    /// be a frame count or Premiere ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{FilmFormat, Representation, Timecode, TimecodeParseError, rates};
+    /// let (tc, representation) = Timecode::parse_any("01:00:00:00", rates::F23_98).unwrap();
+    /// assert_eq!("01:00:00:00", tc.timecode());
+    /// assert_eq!(Representation::Timecode, representation);
+    ///
+    /// let (tc, representation) = Timecode::parse_any("01:00:03.6", rates::F23_98).unwrap();
+    /// assert_eq!("01:00:00:00", tc.timecode());
+    /// assert_eq!(Representation::Runtime, representation);
+    ///
+    /// let (tc, representation) = Timecode::parse_any("5400+00", rates::F23_98).unwrap();
+    /// assert_eq!("01:00:00:00", tc.timecode());
+    /// assert_eq!(Representation::FeetAndFrames(FilmFormat::FF35mm4perf), representation);
+    ///
+    /// // '1:45' cannot be timecode at 24 fps, as there is no frame 45.
+    /// let (tc, representation) = Timecode::parse_any("1:45", rates::F24).unwrap();
+    /// assert_eq!("00:01:45:00", tc.timecode());
+    /// assert_eq!(Representation::Runtime, representation);
+    ///
+    /// let err = Timecode::parse_any("1:12", rates::F24).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::Ambiguous(_)));
+    ///
+    /// // One hour of Premiere ticks at 23.98, or a frame count running for centuries.
+    /// let err = Timecode::parse_any("915372057600000", rates::F23_98).unwrap_err();
+    /// assert!(matches!(err, TimecodeParseError::Ambiguous(_)));
+    /// ```
+    pub fn parse_any(
+        input: &str,
+        rate: Framerate,
+    ) -> Result<(Timecode, Representation), TimecodeParseError> {
+        timecode_detect::parse_any(input, rate)
+    }
+
     /// Returns a new [Timecode] with a [Timecode::seconds] return value equal to the seconds arg
     /// (rounded to the nearest frame).
     ///
@@ -808,8 +1634,37 @@ impl Timecode {
     /// assert_eq!(Rational64::new(43200157, 12000), tc.seconds())
     /// ```
     pub fn with_seconds<T: SecondsSource>(seconds: T, rate: Framerate) -> TimecodeParseResult {
+        Self::with_seconds_rounded(seconds, rate, RoundingMode::HalfUp)
+    }
+
+    /// Returns a new [Timecode] with a [Timecode::seconds] return value equal to the seconds arg,
+    /// snapped to a whole frame using `mode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - A value which can be represented as a number of seconds.
+    ///
+    /// * `rate` - The Framerate which seconds will be snapped to a frame of.
+    ///
+    /// * `mode` - How to snap seconds which fall between two frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// let tc = Timecode::with_seconds_rounded(1.99, rates::F24, RoundingMode::Floor).unwrap();
+    /// assert_eq!("00:00:01:23", tc.timecode());
+    ///
+    /// let tc = Timecode::with_seconds_rounded(1.99, rates::F24, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!("00:00:02:00", tc.timecode());
+    /// ```
+    pub fn with_seconds_rounded<T: SecondsSource>(
+        seconds: T,
+        rate: Framerate,
+        mode: RoundingMode,
+    ) -> TimecodeParseResult {
         let seconds_rat = seconds.to_seconds(rate)?;
-        Ok(Self::with_rational_seconds(seconds_rat, rate))
+        Ok(Self::with_rational_seconds_rounded(seconds_rat, rate, mode))
     }
 
     /// Returns a new [Timecode] with a [Timecode::premiere_ticks] return value equal to the ticks
@@ -832,6 +1687,38 @@ impl Timecode {
     pub fn with_premiere_ticks<T: PremiereTicksSource>(
         ticks: T,
         rate: Framerate,
+    ) -> TimecodeParseResult {
+        Self::with_premiere_ticks_rounded(ticks, rate, RoundingMode::HalfUp)
+    }
+
+    /// Returns a new [Timecode] with a [Timecode::premiere_ticks] return value equal to the ticks
+    /// arg, snapped to a whole frame using `mode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticks` - A value which can be represented as a number Adobe Premiere Pro ticks.
+    ///
+    /// * `rate` - The Framerate which seconds will be snapped to a frame of.
+    ///
+    /// * `mode` - How to snap ticks which fall between two frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, RoundingMode, rates};
+    /// // Three quarters of a frame at 24 fps.
+    /// let ticks = 7938000000i64;
+    ///
+    /// let tc = Timecode::with_premiere_ticks_rounded(ticks, rates::F24, RoundingMode::Floor).unwrap();
+    /// assert_eq!(0, tc.frames());
+    ///
+    /// let tc = Timecode::with_premiere_ticks_rounded(ticks, rates::F24, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(1, tc.frames());
+    /// ```
+    pub fn with_premiere_ticks_rounded<T: PremiereTicksSource>(
+        ticks: T,
+        rate: Framerate,
+        mode: RoundingMode,
     ) -> TimecodeParseResult {
         let tick_count = ticks.to_ticks(rate)?;
         // We need to do this calculation in a 128-bit Ratio because otherwise
@@ -840,20 +1727,68 @@ impl Timecode {
         let seconds128 =
             Ratio::<i128>::from_integer(tick_count as i128) / PREMIERE_TICKS_PER_SECOND;
         let seconds = Rational64::new(*seconds128.numer() as i64, *seconds128.denom() as i64);
-        Self::with_seconds(seconds, rate)
+        Self::with_seconds_rounded(seconds, rate, mode)
+    }
+
+    /// Returns a new [Timecode] from an SMPTE ST 12-1 label at half of `rate`, and the index of
+    /// the frame within the pair of frames sharing that label. This is the reverse of
+    /// [Timecode::frame_pair].
+    ///
+    /// `sections` must be a valid label at the base rate, and `pair_index` must be `0` or `1`.
+    /// An error is returned if they are not, or if `rate` does not label frames in pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{Timecode, TimecodeSections, rates};
+    /// let sections = TimecodeSections{
+    ///     negative: false,
+    ///     hours: 1,
+    ///     minutes: 0,
+    ///     seconds: 0,
+    ///     frames: 24,
+    /// };
+    /// let tc = Timecode::with_frame_pair(sections, 1, rates::F50).unwrap();
+    /// assert_eq!("01:00:00:49", tc.timecode());
+    /// ```
+    pub fn with_frame_pair(
+        sections: TimecodeSections,
+        pair_index: i64,
+        rate: Framerate,
+    ) -> TimecodeParseResult {
+        let frame_count = timecode_hfr::frame_pair_to_frames(&sections, pair_index, rate)?;
+        Ok(Self::with_i64_frames(frame_count, rate))
     }
 
     /// Used internally for creating new timecodes from i64 frame count values without
     /// an error return.
-    fn with_i64_frames(frame_count: i64, rate: Framerate) -> Timecode {
+    pub(crate) fn with_i64_frames(frame_count: i64, rate: Framerate) -> Timecode {
         let seconds = Rational64::from_integer(frame_count) / rate.playback();
         Self::with_rational_seconds(seconds, rate)
     }
 
+    /// Like [Timecode::with_i64_frames], but returns [None] if `frame_count` is too large to
+    /// count in seconds at `rate`.
+    pub(crate) fn checked_with_i64_frames(frame_count: i64, rate: Framerate) -> Option<Timecode> {
+        // A whole number of frames is already on a frame, so does not need to be rounded.
+        let seconds = Rational64::from_integer(frame_count).checked_div(&rate.playback())?;
+        Some(Timecode { seconds, rate })
+    }
+
     /// Used internally for creating new timecodes from Rational64 seconds values
     /// without an error return.
     fn with_rational_seconds(seconds: Rational64, rate: Framerate) -> Timecode {
-        let seconds = round_seconds_to_frame(seconds, rate);
+        Self::with_rational_seconds_rounded(seconds, rate, RoundingMode::HalfUp)
+    }
+
+    /// Used internally for creating new timecodes from Rational64 seconds values
+    /// without an error return, snapping to a frame with the given [RoundingMode].
+    pub(crate) fn with_rational_seconds_rounded(
+        seconds: Rational64,
+        rate: Framerate,
+        mode: RoundingMode,
+    ) -> Timecode {
+        let seconds = round_seconds_to_frame(seconds, rate, mode);
         Timecode { seconds, rate }
     }
 }
@@ -1058,39 +1993,3 @@ impl Neg for Timecode {
         Timecode::with_rational_seconds(-self.seconds, self.rate)
     }
 }
-
-/// Converts a frame-number to an adjusted frame number for creating drop-frame tc.
-///
-/// Algorithm adapted from: https://www.davidheidelberger.com/2010/06/10/drop-frame-timecode/
-///
-/// * `frame_number` - The frame number to convert to a drop-frame number.
-///
-/// * `rate` - the framerate of the timecode.
-///
-/// returns The frame number adjusted to produce the correct drop-frame timecode when
-/// used in the normal timecode calculation.
-///
-/// ***WARNING:*** This method will panic if passed a non-drop-frame framerate.
-fn drop_frame_adjustment(mut frame_number: i64, rate: Framerate) -> i64 {
-    let framerate = rate.playback().to_f64().unwrap();
-
-    let dropped_per_min = rate.drop_frames_per_minute().unwrap();
-    let frames_per_hour = (framerate * 60.0 * 60.0).round().to_i64().unwrap();
-    let frames_per_24_hours = frames_per_hour * 24;
-    let frames_per_10_min = (framerate * 60.0 * 10.0).round().to_i64().unwrap();
-    let frames_per_min = (framerate * 60.0).round().to_i64().unwrap();
-
-    frame_number %= frames_per_24_hours;
-
-    let (tens_of_mins, remaining_mins) = div_rem(frame_number, frames_per_10_min);
-    let tens_of_mins_adjustment = dropped_per_min * 9 * tens_of_mins;
-
-    if remaining_mins > dropped_per_min {
-        let remaining_minutes_adjustment =
-            dropped_per_min * (remaining_mins - dropped_per_min) / frames_per_min;
-
-        tens_of_mins_adjustment + remaining_minutes_adjustment
-    } else {
-        tens_of_mins_adjustment
-    }
-}
@@ -0,0 +1,235 @@
+use num::rational::Ratio;
+use num::Rational64;
+use std::str::FromStr;
+
+use crate::consts::{
+    FEET_AND_FRAMES_REGEX, PREMIERE_TICKS_PER_SECOND, RUNTIME_REGEX, TIMECODE_REGEX,
+};
+use crate::timecode_parse::convert_tc_int;
+use crate::{
+    FeetFramesStr, FilmFormat, Framerate, Ntsc, ParseDiagnostic, Timecode, TimecodeParseError,
+};
+
+/// The number of seconds in a minute, and minutes in an hour, which every section of a runtime
+/// after the first must be under.
+const RUNTIME_SECTION_LIMIT: i64 = 60;
+
+/// The representation of a [Timecode] that [Timecode::parse_any] detected its input as.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Representation {
+    /// Non-drop-frame SMPTE timecode, including partial timecode: `01:00:00:00`, `3:12`.
+    Timecode,
+    /// Drop-frame SMPTE timecode: `01:00:00;00`.
+    DropFrameTimecode,
+    /// Real-world runtime: `01:00:03.6`, `1.5`.
+    Runtime,
+    /// Feet and frames of the given film format: `5400+00`, `1+20.1`.
+    FeetAndFrames(FilmFormat),
+    /// A frame count: `86400`.
+    Frames,
+    /// Adobe Premiere Pro ticks, for whole numbers too large to be a frame count.
+    PremiereTicks,
+    /// Rational seconds: `18018/5`.
+    RationalSeconds,
+}
+
+/// Does the work of [Timecode::parse_any].
+pub(crate) fn parse_any(
+    input: &str,
+    rate: Framerate,
+) -> Result<(Timecode, Representation), TimecodeParseError> {
+    if input.contains('/') {
+        return parse_rational_seconds(input, rate);
+    }
+
+    if let Some(matched) = FEET_AND_FRAMES_REGEX.captures(input) {
+        let format = detect_film_format(input, matched)?;
+        let tc = Timecode::with_frames(FeetFramesStr::new(input, format), rate)?;
+        return Ok((tc, Representation::FeetAndFrames(format)));
+    }
+
+    // Timecode never has a decimal place, so anything with one must be a runtime.
+    if input.contains('.') {
+        if RUNTIME_REGEX.is_match(input) {
+            let tc = Timecode::with_seconds(input, rate)?;
+            return Ok((tc, Representation::Runtime));
+        }
+        return Err(unknown_format_error(input));
+    }
+
+    let matched = match TIMECODE_REGEX.captures(input) {
+        Some(matched) => matched,
+        None => return Err(unknown_format_error(input)),
+    };
+
+    let sections: Vec<&str> = ["section1", "section2", "section3", "frames"]
+        .iter()
+        .filter_map(|name| matched.name(name))
+        .map(|section| section.as_str())
+        .collect();
+
+    if sections.len() == 1 {
+        return parse_frames_or_ticks(input, rate);
+    }
+
+    let timecode_representation = if rate.ntsc() == Ntsc::DropFrame {
+        Representation::DropFrameTimecode
+    } else {
+        Representation::Timecode
+    };
+
+    // Four sections, or a drop-frame separator, can only be timecode.
+    if sections.len() == 4 || input.contains(';') {
+        let tc = Timecode::with_frames(input, rate)?;
+        return Ok((tc, timecode_representation));
+    }
+
+    // Two or three sections could be partial timecode like '3:12', or a runtime like '1:30'. We
+    // can only decide if the last section rules one of them out.
+    let mut values = Vec::with_capacity(sections.len());
+    for section in sections.iter() {
+        values.push(convert_tc_int(section, "section")?);
+    }
+
+    let last = *values.last().unwrap();
+    let could_be_timecode = last < rate.timebase().ceil().to_integer();
+    let could_be_runtime = values[1..]
+        .iter()
+        .all(|value| *value < RUNTIME_SECTION_LIMIT);
+
+    match (could_be_timecode, could_be_runtime) {
+        (true, false) => Ok((Timecode::with_frames(input, rate)?, timecode_representation)),
+        (false, true) => Ok((Timecode::with_seconds(input, rate)?, Representation::Runtime)),
+        (true, true) => Err(TimecodeParseError::Ambiguous(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{} at {}: could be partial timecode or a runtime; use Timecode::with_frames or Timecode::with_seconds",
+                    input, rate
+                ),
+            )
+            .with_span(0..input.len()),
+        )),
+        (false, false) => Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{} at {}: last section is too large for frames or runtime seconds",
+                    input, rate
+                ),
+            )
+            .with_span(matched.name("frames").unwrap().range()),
+        )),
+    }
+}
+
+/// Parses a value like '18018/5' as rational seconds.
+fn parse_rational_seconds(
+    input: &str,
+    rate: Framerate,
+) -> Result<(Timecode, Representation), TimecodeParseError> {
+    let seconds = match Rational64::from_str(input) {
+        Ok(seconds) => seconds,
+        Err(_) => return Err(unknown_format_error(input)),
+    };
+
+    let tc = Timecode::with_seconds(seconds, rate)?;
+    Ok((tc, Representation::RationalSeconds))
+}
+
+/// Decides whether a whole number is a frame count or Premiere ticks.
+///
+/// Ticks are very large numbers which land exactly on a frame boundary, but a frame count can
+/// be any whole number. A non-zero value which lands on a frame as ticks is ambiguous unless it
+/// is too large to be read as frames.
+fn parse_frames_or_ticks(
+    input: &str,
+    rate: Framerate,
+) -> Result<(Timecode, Representation), TimecodeParseError> {
+    let value = convert_tc_int(input, "frames")?;
+
+    let playback = Ratio::<i128>::new(
+        *rate.playback().numer() as i128,
+        *rate.playback().denom() as i128,
+    );
+    let frames = Ratio::<i128>::from_integer(value as i128) * playback / PREMIERE_TICKS_PER_SECOND;
+
+    let could_be_ticks = value != 0 && frames.is_integer();
+    let could_be_frames = value.checked_mul(*rate.playback().denom()).is_some();
+
+    match (could_be_frames, could_be_ticks) {
+        (_, false) => Ok((Timecode::with_frames(value, rate)?, Representation::Frames)),
+        (false, true) => Ok((
+            Timecode::with_premiere_ticks(value, rate)?,
+            Representation::PremiereTicks,
+        )),
+        (true, true) => Err(TimecodeParseError::Ambiguous(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{} at {}: could be a frame count or Premiere ticks; use Timecode::with_frames or Timecode::with_premiere_ticks",
+                    input, rate
+                ),
+            )
+            .with_span(0..input.len()),
+        )),
+    }
+}
+
+/// Infers the film format of a feet+frames string from its frames and perf fields.
+///
+/// A perf field only appears on 3-perf footage. Otherwise the frames place tells us the
+/// smallest foot the value fits in. 4-perf is assumed when the frames place fits a 4-perf foot,
+/// as that is by far the most common format.
+fn detect_film_format(
+    input: &str,
+    matched: regex::Captures,
+) -> Result<FilmFormat, TimecodeParseError> {
+    if matched.name("perf").is_some() {
+        return Ok(FilmFormat::FF35mm3perf);
+    }
+
+    // If the regex matched, the frames group must be present.
+    let frames_match = matched.name("frames").unwrap();
+    let frames = convert_tc_int(frames_match.as_str(), "frames")?;
+
+    let fits = |format: FilmFormat| frames * format.perfs_per_frame() < format.perfs_per_foot();
+
+    if fits(FilmFormat::FF35mm4perf) {
+        return Ok(FilmFormat::FF35mm4perf);
+    }
+
+    match (fits(FilmFormat::FF35mm2perf), fits(FilmFormat::FF16mm)) {
+        (true, false) => Ok(FilmFormat::FF35mm2perf),
+        (false, true) => Ok(FilmFormat::FF16mm),
+        (true, true) => Err(TimecodeParseError::Ambiguous(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: frames place of {} could be 35mm 2-perf or 16mm footage; use FeetFramesStr",
+                    input, frames
+                ),
+            )
+            .with_span(frames_match.range()),
+        )),
+        (false, false) => Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                input,
+                format!("{}: frames place of {} is too large for any film format", input, frames),
+            )
+            .with_span(frames_match.range())
+            .with_expected(0..=FilmFormat::FF35mm2perf.perfs_per_foot() / FilmFormat::FF35mm2perf.perfs_per_frame() - 1),
+        )),
+    }
+}
+
+/// Returns the error for a string which is not any known representation.
+fn unknown_format_error(input: &str) -> TimecodeParseError {
+    TimecodeParseError::UnknownStrFormat(
+        ParseDiagnostic::new(
+            input,
+            format!("{} is not a known timecode representation", input),
+        )
+        .with_span(0..input.len()),
+    )
+}
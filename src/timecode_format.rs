@@ -0,0 +1,292 @@
+use num::Signed;
+
+use crate::{Framerate, Ntsc, Rollover, Timecode};
+
+/// How the sign of a [Timecode] is shown by a [TimecodeFormat].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SignStyle {
+    /// Negative values are prefixed with `-`, positive values have no sign: `-01:00:00:00`.
+    #[default]
+    NegativeOnly,
+    /// All non-zero values carry a sign: `+01:00:00:00`, `-01:00:00:00`.
+    Always,
+    /// Negative values are wrapped in parentheses, as in accounting: `(01:00:00:00)`.
+    Parentheses,
+    /// The sign is never shown, and negative values are formatted as their absolute value.
+    Never,
+}
+
+/// Where a [TimecodeFormat] marks that a [Timecode] is drop-frame.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DropFrameMarker {
+    /// The separator before the frames is replaced with the drop-frame separator:
+    /// `01:00:00;00`.
+    #[default]
+    FrameSeparator,
+    /// Every separator is replaced with the drop-frame separator: `01;00;00;00`.
+    AllSeparators,
+    /// The drop-frame suffix is appended to the timecode: `01:00:00:00 DF`.
+    Suffix,
+    /// Drop-frame timecode is not marked.
+    None,
+}
+
+/**
+Builds a custom timecode format, for use with [Timecode::format].
+
+[Timecode::timecode] always returns SMPTE-style `HH:MM:SS:FF` timecode. Burn-ins, bins and
+logs from different tools each have their own house style, which can be described once with a
+[TimecodeFormat] then applied to any [Timecode].
+
+The default [TimecodeFormat] matches [Timecode::timecode], except that rates of 100 fps or above
+get three frame digits.
+
+# Examples
+
+```rust
+use vtc::{DropFrameMarker, SignStyle, Timecode, TimecodeFormat, rates};
+
+let tc = Timecode::with_frames("-00:00:03:12", rates::F23_98).unwrap();
+
+let default = TimecodeFormat::default();
+assert_eq!("-00:00:03:12", tc.format(&default));
+
+let burn_in = TimecodeFormat::default()
+    .frame_separator('.')
+    .trim_leading_zeros(true)
+    .sign(SignStyle::Parentheses);
+assert_eq!("(03.12)", tc.format(&burn_in));
+
+let tc = Timecode::with_frames("01:00:00;00", rates::F29_97_DF).unwrap();
+
+let log = TimecodeFormat::default()
+    .drop_frame_marker(DropFrameMarker::Suffix)
+    .drop_frame_suffix(" DF");
+assert_eq!("01:00:00:00 DF", tc.format(&log));
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimecodeFormat {
+    hours_separator: char,
+    minutes_separator: char,
+    frame_separator: char,
+    drop_frame_separator: char,
+    drop_frame_marker: DropFrameMarker,
+    drop_frame_suffix: String,
+    hour_digits: usize,
+    frame_digits: Option<usize>,
+    trim_leading_zeros: bool,
+    sign: SignStyle,
+    rollover: Rollover,
+}
+
+impl Default for TimecodeFormat {
+    fn default() -> Self {
+        TimecodeFormat {
+            hours_separator: ':',
+            minutes_separator: ':',
+            frame_separator: ':',
+            drop_frame_separator: ';',
+            drop_frame_marker: DropFrameMarker::FrameSeparator,
+            drop_frame_suffix: " DF".to_string(),
+            hour_digits: 2,
+            frame_digits: None,
+            trim_leading_zeros: false,
+            sign: SignStyle::NegativeOnly,
+            rollover: Rollover::Unbounded,
+        }
+    }
+}
+
+impl TimecodeFormat {
+    /// Sets the separator placed after the hours and after the minutes. Defaults to `:`.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.hours_separator = separator;
+        self.minutes_separator = separator;
+        self
+    }
+
+    /// Sets the separator placed after the hours. Defaults to `:`.
+    pub fn hours_separator(mut self, separator: char) -> Self {
+        self.hours_separator = separator;
+        self
+    }
+
+    /// Sets the separator placed after the minutes. Defaults to `:`.
+    pub fn minutes_separator(mut self, separator: char) -> Self {
+        self.minutes_separator = separator;
+        self
+    }
+
+    /// Sets the separator placed between the seconds and the frames. Defaults to `:`.
+    ///
+    /// Drop-frame timecode uses the [drop frame separator](TimecodeFormat::drop_frame_separator)
+    /// here instead, unless the [DropFrameMarker] is [DropFrameMarker::Suffix] or
+    /// [DropFrameMarker::None].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{DropFrameMarker, Timecode, TimecodeFormat, rates};
+    ///
+    /// let tc = Timecode::with_frames("01:00:00;00", rates::F29_97_DF).unwrap();
+    ///
+    /// let format = TimecodeFormat::default().frame_separator('.');
+    /// assert_eq!("01:00:00;00", tc.format(&format));
+    ///
+    /// let format = format.drop_frame_separator(',');
+    /// assert_eq!("01:00:00,00", tc.format(&format));
+    ///
+    /// let format = format.drop_frame_marker(DropFrameMarker::None);
+    /// assert_eq!("01:00:00.00", tc.format(&format));
+    /// ```
+    pub fn frame_separator(mut self, separator: char) -> Self {
+        self.frame_separator = separator;
+        self
+    }
+
+    /// Sets the separator used to mark drop-frame timecode. Defaults to `;`.
+    ///
+    /// With [DropFrameMarker::FrameSeparator] it replaces the
+    /// [frame separator](TimecodeFormat::frame_separator) of drop-frame timecode, and with
+    /// [DropFrameMarker::AllSeparators] it replaces every separator.
+    pub fn drop_frame_separator(mut self, separator: char) -> Self {
+        self.drop_frame_separator = separator;
+        self
+    }
+
+    /// Sets where drop-frame timecode is marked. Defaults to
+    /// [DropFrameMarker::FrameSeparator].
+    pub fn drop_frame_marker(mut self, marker: DropFrameMarker) -> Self {
+        self.drop_frame_marker = marker;
+        self
+    }
+
+    /// Sets the suffix appended to drop-frame timecode when using [DropFrameMarker::Suffix].
+    /// Defaults to `" DF"`.
+    pub fn drop_frame_suffix(mut self, suffix: &str) -> Self {
+        self.drop_frame_suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets the minimum number of digits the hours are zero-padded to. Defaults to `2`.
+    pub fn hour_digits(mut self, digits: usize) -> Self {
+        self.hour_digits = digits;
+        self
+    }
+
+    /// Sets the number of digits the frames are zero-padded to. By default the frames are padded
+    /// to fit the largest frame number of the timebase, with a minimum of `2`.
+    pub fn frame_digits(mut self, digits: usize) -> Self {
+        self.frame_digits = Some(digits);
+        self
+    }
+
+    /// Sets whether leading sections which are zero are left off: `00:00:03:12` becomes
+    /// `03:12`. The frames are always shown. Defaults to `false`.
+    pub fn trim_leading_zeros(mut self, trim: bool) -> Self {
+        self.trim_leading_zeros = trim;
+        self
+    }
+
+    /// Sets how the sign of the timecode is shown. Defaults to [SignStyle::NegativeOnly].
+    pub fn sign(mut self, sign: SignStyle) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Sets how timecode past 24 hours or below zero is shown. Defaults to
+    /// [Rollover::Unbounded].
+    pub fn rollover(mut self, rollover: Rollover) -> Self {
+        self.rollover = rollover;
+        self
+    }
+
+    /// Formats `tc` in this format. See [Timecode::format].
+    pub(crate) fn apply(&self, tc: &Timecode) -> String {
+        let rolled = tc.rollover(self.rollover);
+        let days = match self.rollover {
+            Rollover::Days => rolled.days(),
+            _ => 0,
+        };
+
+        // Split whole days off of the value so the hours fall under 24.
+        let mut sections = if days > 0 {
+            let frames_per_day = rolled.rate().frames_per_24_hours();
+            let frames = rolled.frames().abs() % frames_per_day;
+            Timecode::with_i64_frames(frames, rolled.rate()).sections()
+        } else {
+            rolled.sections()
+        };
+        sections.negative = rolled.seconds().is_negative();
+
+        let is_drop_frame = rolled.rate().ntsc() == Ntsc::DropFrame;
+        let (hours_sep, minutes_sep, frame_sep) = match (is_drop_frame, self.drop_frame_marker) {
+            (true, DropFrameMarker::FrameSeparator) => (
+                self.hours_separator,
+                self.minutes_separator,
+                self.drop_frame_separator,
+            ),
+            (true, DropFrameMarker::AllSeparators) => (
+                self.drop_frame_separator,
+                self.drop_frame_separator,
+                self.drop_frame_separator,
+            ),
+            _ => (
+                self.hours_separator,
+                self.minutes_separator,
+                self.frame_separator,
+            ),
+        };
+
+        let frame_digits = self
+            .frame_digits
+            .unwrap_or_else(|| frame_digits(rolled.rate()));
+
+        let mut leading = vec![
+            (days, 1, hours_sep),
+            (sections.hours, self.hour_digits, hours_sep),
+            (sections.minutes, 2, minutes_sep),
+            (sections.seconds, 2, frame_sep),
+        ];
+        // The day section is only ever shown when there are days to show.
+        if days == 0 {
+            leading.remove(0);
+        }
+        if self.trim_leading_zeros {
+            let first_non_zero = leading.iter().position(|section| section.0 != 0);
+            leading.drain(..first_non_zero.unwrap_or(leading.len()));
+        }
+
+        let mut formatted = String::new();
+        for (value, digits, separator) in leading {
+            formatted.push_str(&format!("{:0width$}", value, width = digits));
+            formatted.push(separator);
+        }
+        formatted.push_str(&format!(
+            "{:0width$}",
+            sections.frames,
+            width = frame_digits
+        ));
+
+        if is_drop_frame && self.drop_frame_marker == DropFrameMarker::Suffix {
+            formatted.push_str(&self.drop_frame_suffix);
+        }
+
+        let is_zero = rolled.frames() == 0;
+        match (self.sign, sections.negative) {
+            (SignStyle::NegativeOnly, true) | (SignStyle::Always, true) => {
+                format!("-{}", formatted)
+            }
+            (SignStyle::Always, false) if !is_zero => format!("+{}", formatted),
+            (SignStyle::Parentheses, true) => format!("({})", formatted),
+            _ => formatted,
+        }
+    }
+}
+
+/// Returns the number of digits the frames section of a timecode at `rate` is written with.
+pub(crate) fn frame_digits(rate: Framerate) -> usize {
+    let max_frame = rate.timebase().ceil().to_integer() - 1;
+    max_frame.to_string().len().max(2)
+}
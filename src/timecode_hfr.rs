@@ -0,0 +1,349 @@
+use crate::consts::TIMECODE_REGEX;
+use crate::source_frames::parse_timecode_string;
+use crate::timecode_format::frame_digits;
+use crate::timecode_parse::convert_tc_int;
+use crate::{
+    Framerate, FramesSource, FramesSourceResult, Ntsc, ParseDiagnostic, Timecode,
+    TimecodeParseError, TimecodeSections,
+};
+
+/// The ST 12-1 timebases a high frame rate can be grouped into, in order of preference.
+const BASE_TIMEBASES: [i64; 3] = [30, 25, 24];
+
+/// The number of frames sharing a label in a frame pair.
+const FRAME_PAIR_SIZE: i64 = 2;
+
+/// The number of minutes in an hour and seconds in a minute.
+const SEXAGESIMAL_LIMIT: i64 = 60;
+
+/// How a high frame rate [Timecode] labels frames past the 30 an ST 12-1 frames field can hold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HfrMode {
+    /// The frames field counts every frame of the timebase: `01:00:00:119`.
+    FullFrames,
+    /// The frames field counts frames of the ST 12-1 base rate, and an index after a `.` says
+    /// which frame of the group at that base frame is meant: `01:00:00:29.3`.
+    FrameGroup,
+}
+
+/**
+Holds the sections of a high frame rate timecode in its ST 12-1 compatible form: the timecode
+at the base rate, plus the index of the frame within its group.
+
+Returned by [Timecode::hfr_sections].
+
+# Examples
+
+```rust
+use vtc::{Timecode, rates};
+let tc = Timecode::with_frames("01:00:00:119", rates::F120).unwrap();
+let sections = tc.hfr_sections().unwrap();
+assert_eq!(4, sections.group_size);
+assert_eq!(29, sections.base.frames);
+assert_eq!(3, sections.index);
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HfrSections {
+    /// The sections of the timecode at the base rate, like 30 fps for 120 fps.
+    pub base: TimecodeSections,
+    /// Which frame of the group at the base frame this is, from `0` to `group_size - 1`.
+    pub index: i64,
+    /// The number of high frame rate frames in each frame of the base rate.
+    pub group_size: i64,
+}
+
+/**
+A struct that bundles a high frame rate timecode str together with the [HfrMode] it is
+written in, to hint parsing.
+
+This struct, which implements [FramesSource], parses strings in either form returned by
+[Timecode::timecode_hfr]. An error is returned if the rate is not a high frame rate.
+
+# Examples
+
+```rust
+use vtc::{HfrMode, HfrStr, Timecode, rates};
+
+let tc = Timecode::with_frames(HfrStr::new("01:00:00:29.3", HfrMode::FrameGroup), rates::F120)
+    .unwrap();
+assert_eq!("01:00:00:119", tc.timecode());
+
+let tc = Timecode::with_frames(HfrStr::new("01:00:00:99", HfrMode::FullFrames), rates::F100)
+    .unwrap();
+assert_eq!(Some("01:00:00:24.3".to_string()), tc.timecode_hfr(HfrMode::FrameGroup));
+```
+*/
+#[derive(Debug)]
+pub struct HfrStr<'a> {
+    pub(crate) input: &'a str,
+    pub(crate) mode: HfrMode,
+}
+
+impl<'a> HfrStr<'a> {
+    /// Create a [HfrStr] object from a string and the [HfrMode] it is written in.
+    pub fn new(input: &'a str, mode: HfrMode) -> Self {
+        HfrStr { input, mode }
+    }
+}
+
+impl<'a> FramesSource for HfrStr<'a> {
+    fn to_frames(&self, rate: Framerate) -> FramesSourceResult {
+        let (base_rate, group_size) = match hfr_base(rate) {
+            Some(base) => base,
+            None => {
+                return Err(TimecodeParseError::Conversion(
+                    ParseDiagnostic::new(
+                        self.input,
+                        format!(
+                            "{} is not a high frame rate with an ST 12-1 base rate",
+                            rate
+                        ),
+                    )
+                    .with_span(0..self.input.len()),
+                ))
+            }
+        };
+
+        match self.mode {
+            HfrMode::FullFrames => self.input.to_frames(rate),
+            HfrMode::FrameGroup => parse_frame_group(self.input, base_rate, group_size),
+        }
+    }
+}
+
+/// Returns the ST 12-1 base rate of a high frame rate, and how many of its frames fall in each
+/// frame of the base rate. Returns [None] if `rate` is not an ST 12-3 high frame rate, or its
+/// timebase is not a whole multiple of an ST 12-1 timebase.
+pub(crate) fn hfr_base(rate: Framerate) -> Option<(Framerate, i64)> {
+    if !rate.info().st_12_3 || !rate.timebase().is_integer() {
+        return None;
+    }
+
+    let timebase = rate.timebase().to_integer();
+    let base_timebase = BASE_TIMEBASES
+        .iter()
+        .copied()
+        .find(|base| timebase % base == 0)?;
+
+    // NTSC and drop-frame rates group into the NTSC and drop-frame version of their base.
+    let base_rate = Framerate::with_timebase(base_timebase, rate.ntsc()).ok()?;
+
+    Some((base_rate, timebase / base_timebase))
+}
+
+/// Does the work of [Timecode::hfr_sections].
+pub(crate) fn hfr_sections(tc: &Timecode) -> Option<HfrSections> {
+    let (_, group_size) = hfr_base(tc.rate())?;
+
+    let mut base = tc.sections();
+    let index = base.frames % group_size;
+    base.frames /= group_size;
+
+    Some(HfrSections {
+        base,
+        index,
+        group_size,
+    })
+}
+
+/// Does the work of [Timecode::timecode_hfr].
+pub(crate) fn timecode_hfr(tc: &Timecode, mode: HfrMode) -> Option<String> {
+    let sections = hfr_sections(tc)?;
+    let base = &sections.base;
+
+    let sign = if base.negative { "-" } else { "" };
+    let frame_sep = if tc.rate().ntsc() == Ntsc::DropFrame {
+        ";"
+    } else {
+        ":"
+    };
+
+    let formatted = match mode {
+        HfrMode::FullFrames => format!(
+            "{}{:02}:{:02}:{:02}{}{:0width$}",
+            sign,
+            base.hours,
+            base.minutes,
+            base.seconds,
+            frame_sep,
+            base.frames * sections.group_size + sections.index,
+            width = frame_digits(tc.rate()),
+        ),
+        HfrMode::FrameGroup => format!(
+            "{}{:02}:{:02}:{:02}{}{:02}.{}",
+            sign, base.hours, base.minutes, base.seconds, frame_sep, base.frames, sections.index,
+        ),
+    };
+
+    Some(formatted)
+}
+
+/// Does the work of [Timecode::frame_pair].
+pub(crate) fn frame_pair(tc: &Timecode) -> Option<(TimecodeSections, i64)> {
+    let sections = hfr_sections(tc)?;
+    if sections.group_size != FRAME_PAIR_SIZE {
+        return None;
+    }
+    Some((sections.base, sections.index))
+}
+
+/// Does the work of [Timecode::with_frame_pair], returning the frame count at `rate`.
+pub(crate) fn frame_pair_to_frames(
+    sections: &TimecodeSections,
+    pair_index: i64,
+    rate: Framerate,
+) -> FramesSourceResult {
+    let frame_sep = if rate.ntsc() == Ntsc::DropFrame {
+        ';'
+    } else {
+        ':'
+    };
+    let sign = if sections.negative { "-" } else { "" };
+    let label = format!(
+        "{}{:02}:{:02}:{:02}{}{:02}",
+        sign, sections.hours, sections.minutes, sections.seconds, frame_sep, sections.frames
+    );
+
+    let base_rate = match hfr_base(rate) {
+        Some((base_rate, FRAME_PAIR_SIZE)) => base_rate,
+        _ => {
+            return Err(TimecodeParseError::Conversion(ParseDiagnostic::new(
+                label.as_str(),
+                format!("{} is not a rate which labels frames in pairs", rate),
+            )))
+        }
+    };
+    let base_timebase = base_rate.timebase().to_integer();
+
+    // Each section is checked against its own range, as the lenient parser would otherwise let
+    // an overflowing value roll into the next section.
+    let mut section_start = sign.len();
+    for (name, value, limit) in [
+        ("hours", sections.hours, i64::MAX),
+        ("minutes", sections.minutes, SEXAGESIMAL_LIMIT),
+        ("seconds", sections.seconds, SEXAGESIMAL_LIMIT),
+        ("frames", sections.frames, base_timebase),
+    ] {
+        let section_end = section_start + format!("{:02}", value).len();
+        if value < 0 || value >= limit {
+            return Err(TimecodeParseError::SectionOutOfRange(
+                ParseDiagnostic::new(
+                    label.as_str(),
+                    format!("{} at {}: {} must be < {}", label, base_rate, name, limit),
+                )
+                .with_span(section_start..section_end)
+                .with_expected(0..=limit - 1),
+            ));
+        }
+        section_start = section_end + 1;
+    }
+
+    if !(0..FRAME_PAIR_SIZE).contains(&pair_index) {
+        return Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                label.as_str(),
+                format!(
+                    "{}: frame pair index must be 0 or 1, got {}",
+                    label, pair_index
+                ),
+            )
+            .with_expected(0..=FRAME_PAIR_SIZE - 1),
+        ));
+    }
+
+    let base_frames = label.as_str().to_frames(base_rate)?.abs();
+    let frames = base_frames * FRAME_PAIR_SIZE + pair_index;
+    if sections.negative {
+        Ok(-frames)
+    } else {
+        Ok(frames)
+    }
+}
+
+/// Parses a frame group timecode like '01:00:00:29.3' into a frame count at the high frame
+/// rate.
+fn parse_frame_group(input: &str, base_rate: Framerate, group_size: i64) -> FramesSourceResult {
+    let (tc_str, index_str) = match input.rsplit_once('.') {
+        Some(split) => split,
+        None => {
+            return Err(TimecodeParseError::MissingSection(
+                ParseDiagnostic::new(
+                    input,
+                    format!("{}: frame group timecode must end with a .index", input),
+                )
+                .with_span(input.len()..input.len())
+                .with_expected(0..=group_size - 1),
+            ))
+        }
+    };
+    let index_span = tc_str.len() + 1..input.len();
+
+    let matched = match TIMECODE_REGEX.captures(tc_str) {
+        Some(matched) => matched,
+        None => {
+            return Err(TimecodeParseError::UnknownStrFormat(
+                ParseDiagnostic::new(
+                    input,
+                    format!("{} is not a known frame group timecode format", input),
+                )
+                .with_span(0..tc_str.len()),
+            ))
+        }
+    };
+
+    // A base frame past the base timebase would roll into the next second, which is never how
+    // a frame group is written.
+    let base_timebase = base_rate.timebase().to_integer();
+    let frames_match = matched.name("frames").unwrap();
+    let base_frames = convert_tc_int(frames_match.as_str(), "frames")?;
+    if base_frames >= base_timebase {
+        return Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{} at {}: base frames must be < {}",
+                    input, base_rate, base_timebase
+                ),
+            )
+            .with_span(frames_match.range())
+            .with_expected(0..=base_timebase - 1),
+        ));
+    }
+
+    let index = match index_str.parse::<i64>() {
+        Ok(parsed) if index_str.chars().all(|c| c.is_ascii_digit()) => parsed,
+        _ => {
+            return Err(TimecodeParseError::MalformedSection(
+                ParseDiagnostic::new(
+                    input,
+                    format!("{}: frame index '{}' is not a number", input, index_str),
+                )
+                .with_span(index_span),
+            ))
+        }
+    };
+    if index >= group_size {
+        return Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: frame index must be < {} frames per group",
+                    input, group_size
+                ),
+            )
+            .with_span(index_span)
+            .with_expected(0..=group_size - 1),
+        ));
+    }
+
+    let is_negative = matched.name("negative").is_some();
+    let base_frame_count = parse_timecode_string(matched, base_rate)?.abs();
+
+    let frames = base_frame_count * group_size + index;
+    if is_negative {
+        Ok(-frames)
+    } else {
+        Ok(frames)
+    }
+}
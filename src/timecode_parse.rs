@@ -1,25 +1,320 @@
 use num::{traits::Inv, Rational64, Zero};
 
-use crate::{Framerate, FramesSourceResult, TimecodeParseError};
+use crate::consts::{SECONDS_PER_HOUR_I64, SECONDS_PER_MINUTE_I64};
+use crate::timecode_format::frame_digits;
+use std::ops::Range;
+
+use crate::{
+    drop_frame, Framerate, FramesSource, FramesSourceResult, Ntsc, ParseDiagnostic, RoundingMode,
+    Timecode, TimecodeParseError,
+};
 
 /// convert_tc_int handles converting an int found in a string and returning an error if one
 /// occurs.
 pub(super) fn convert_tc_int(value: &str, section_name: &str) -> FramesSourceResult {
     match value.parse::<i64>() {
         Ok(parsed) => Ok(parsed),
-        Err(err) => Err(TimecodeParseError::Conversion(format!(
-            "error converting {} to i64: {}",
-            section_name, err,
-        ))),
+        Err(err) => Err(TimecodeParseError::Conversion(
+            ParseDiagnostic::new(
+                value,
+                format!("error converting {} to i64: {}", section_name, err),
+            )
+            .with_span(0..value.len()),
+        )),
     }
 }
 
-/// takes in a seconds value and a framerate and rounds it to the nearest whole-frame.
-pub(crate) fn round_seconds_to_frame(seconds: Rational64, rate: Framerate) -> Rational64 {
+/// takes in a seconds value and a framerate and rounds it to a whole-frame using `mode`.
+pub(crate) fn round_seconds_to_frame(
+    seconds: Rational64,
+    rate: Framerate,
+    mode: RoundingMode,
+) -> Rational64 {
     if seconds % rate.playback().inv() != Rational64::zero() {
-        let frames = (seconds * rate.playback()).round();
+        let frames = mode.round(seconds * rate.playback());
         frames / rate.playback()
     } else {
         seconds
     }
 }
+
+/// The number of sections in a full timecode string: hours, minutes, seconds and frames.
+const STRICT_SECTION_COUNT: usize = 4;
+
+/// The largest value allowed in each of the hours, minutes and seconds sections of a strictly
+/// parsed timecode.
+const STRICT_SECTION_MAXIMUMS: [(&str, i64); 3] = [("hours", 23), ("minutes", 59), ("seconds", 59)];
+
+/// parse_strict_timecode parses a full, well-formed SMPTE timecode string into a frame count,
+/// returning an error on any deviation from the format [crate::Timecode::timecode] produces.
+///
+/// Where the lenient parser can make sense of a rejected string, its canonical form is offered
+/// as the suggestion on the error.
+pub(crate) fn parse_strict_timecode(input: &str, rate: Framerate) -> FramesSourceResult {
+    validate_strict_timecode(input, rate).map_err(|err| {
+        let trimmed: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+        let suggestion = trimmed
+            .as_str()
+            .to_frames(rate)
+            .map(|frames| Timecode::with_i64_frames(frames, rate).timecode())
+            .ok()
+            .filter(|suggestion| suggestion != input)
+            .filter(|suggestion| validate_strict_timecode(suggestion, rate).is_ok());
+
+        err.with_default_suggestion(suggestion)
+    })
+}
+
+/// Does the work of [parse_strict_timecode], without looking for suggestions.
+fn validate_strict_timecode(input: &str, rate: Framerate) -> FramesSourceResult {
+    let context = format!("{} at {}", input, rate);
+
+    if let Some((index, character)) = input.char_indices().find(|(_, c)| c.is_whitespace()) {
+        return Err(TimecodeParseError::Whitespace(
+            ParseDiagnostic::new(
+                input,
+                format!("{}: timecode cannot contain whitespace", context),
+            )
+            .with_span(index..index + character.len_utf8()),
+        ));
+    }
+
+    let is_negative = input.starts_with('-');
+    let body_start = if is_negative { 1 } else { 0 };
+
+    // Split the string into the spans of its digit sections, and the separators between them.
+    let mut sections: Vec<Range<usize>> = Vec::new();
+    let mut separators: Vec<(usize, char)> = Vec::new();
+    let mut section_start = body_start;
+    for (index, character) in input[body_start..].char_indices() {
+        let index = index + body_start;
+        if character.is_ascii_digit() {
+            continue;
+        }
+        if character != ':' && character != ';' {
+            return Err(TimecodeParseError::SeparatorMismatch(
+                ParseDiagnostic::new(
+                    input,
+                    format!(
+                        "{}: '{}' is not a valid timecode separator, expected ':' or ';'",
+                        context, character,
+                    ),
+                )
+                .with_span(index..index + character.len_utf8()),
+            ));
+        }
+        sections.push(section_start..index);
+        separators.push((index, character));
+        section_start = index + character.len_utf8();
+    }
+    sections.push(section_start..input.len());
+
+    if sections.len() > STRICT_SECTION_COUNT {
+        return Err(TimecodeParseError::MalformedSection(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: found {} sections, expected {}",
+                    context,
+                    sections.len(),
+                    STRICT_SECTION_COUNT,
+                ),
+            )
+            .with_span(sections[STRICT_SECTION_COUNT].start..input.len()),
+        ));
+    }
+
+    if sections.len() < STRICT_SECTION_COUNT {
+        return Err(TimecodeParseError::MissingSection(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: found {} sections, expected hours, minutes, seconds and frames",
+                    context,
+                    sections.len(),
+                ),
+            )
+            .with_span(0..input.len()),
+        ));
+    }
+
+    let section_names = STRICT_SECTION_MAXIMUMS.iter().map(|(name, _)| *name);
+    for (section, name) in sections.iter().zip(section_names.chain(["frames"])) {
+        if section.is_empty() {
+            return Err(TimecodeParseError::MissingSection(
+                ParseDiagnostic::new(input, format!("{}: {} section is empty", context, name))
+                    .with_span(section.clone()),
+            ));
+        }
+    }
+
+    // Check our separators match the NTSC setting of our rate.
+    let is_drop_frame = rate.ntsc() == Ntsc::DropFrame;
+    let (frame_separator_index, frame_separator) = separators[STRICT_SECTION_COUNT - 2];
+    if is_drop_frame && frame_separator != ';' {
+        return Err(TimecodeParseError::SeparatorMismatch(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: drop-frame timecode must separate frames with ';', found '{}'",
+                    context, frame_separator,
+                ),
+            )
+            .with_span(frame_separator_index..frame_separator_index + 1),
+        ));
+    }
+    if let Some((index, _)) = separators.iter().find(|(_, c)| *c == ';') {
+        if !is_drop_frame {
+            return Err(TimecodeParseError::SeparatorMismatch(
+                ParseDiagnostic::new(
+                    input,
+                    format!(
+                        "{}: non-drop-frame timecode must only use ':' separators, found ';'",
+                        context,
+                    ),
+                )
+                .with_span(*index..*index + 1),
+            ));
+        }
+    }
+
+    // Check each section has the right number of digits, and is in range.
+    let mut values = [0i64; STRICT_SECTION_COUNT];
+    for (index, (name, maximum)) in STRICT_SECTION_MAXIMUMS.iter().enumerate() {
+        values[index] = parse_strict_section(
+            input,
+            &context,
+            sections[index].clone(),
+            name,
+            &[2],
+            *maximum,
+        )?;
+    }
+
+    // Frames may be padded to two digits, as Timecode::timecode writes them, or to the width of
+    // the largest frame, as TimecodeFormat does by default.
+    let max_frame = rate.timebase().ceil().to_integer() - 1;
+    let written_width = input[sections[3].clone()]
+        .trim_start_matches('0')
+        .len()
+        .max(2);
+    let mut frame_widths = vec![written_width, frame_digits(rate)];
+    frame_widths.sort_unstable();
+    frame_widths.dedup();
+    values[3] = parse_strict_section(
+        input,
+        &context,
+        sections[3].clone(),
+        "frames",
+        &frame_widths,
+        max_frame,
+    )?;
+
+    let [hours, minutes, seconds, frames] = values;
+    if is_drop_frame {
+        let timebase = rate.timebase().to_integer();
+        if drop_frame::is_dropped_label(minutes, seconds, frames, timebase) {
+            return Err(dropped_label_error(
+                input,
+                sections[3].clone(),
+                frames,
+                rate,
+            ));
+        }
+    }
+
+    let seconds = hours * SECONDS_PER_HOUR_I64 + minutes * SECONDS_PER_MINUTE_I64 + seconds;
+    let mut frame_count = (Rational64::from_integer(seconds) * rate.timebase())
+        .round()
+        .to_integer()
+        + frames;
+    if is_drop_frame {
+        let timebase = rate.timebase().to_integer();
+        frame_count = drop_frame::label_to_frames(frame_count, timebase);
+    }
+
+    if is_negative {
+        frame_count = -frame_count;
+    }
+
+    Ok(frame_count)
+}
+
+/// Parses a single section of a strict timecode string, checking it's digit count is one of
+/// `widths` and it's value is in range.
+fn parse_strict_section(
+    input: &str,
+    context: &str,
+    span: Range<usize>,
+    section_name: &str,
+    widths: &[usize],
+    maximum: i64,
+) -> FramesSourceResult {
+    let section = &input[span.clone()];
+    if !widths.contains(&section.len()) {
+        let widths: Vec<String> = widths.iter().map(|width| width.to_string()).collect();
+        return Err(TimecodeParseError::MalformedSection(
+            ParseDiagnostic::new(
+                input,
+                format!(
+                    "{}: {} must have {} digits, found '{}'",
+                    context,
+                    section_name,
+                    widths.join(" or "),
+                    section,
+                ),
+            )
+            .with_span(span),
+        ));
+    }
+
+    let value = convert_tc_int(section, section_name)?;
+    if value > maximum {
+        return Err(TimecodeParseError::SectionOutOfRange(
+            ParseDiagnostic::new(
+                input,
+                format!("{}: {} must be < {}", context, section_name, maximum + 1),
+            )
+            .with_span(span)
+            .with_expected(0..=maximum),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Returns the error for a drop-frame tc-string whose frames section, found at `frames_span` of
+/// `input`, is one of the frame numbers skipped at the top of most minutes.
+///
+/// **WARNING** this method will panic if a non-drop-frame Framerate is passed to it.
+pub(crate) fn dropped_label_error(
+    input: &str,
+    frames_span: Range<usize>,
+    frames: i64,
+    rate: Framerate,
+) -> TimecodeParseError {
+    let timebase = rate.timebase().to_integer();
+    let dropped = rate.drop_frames_per_minute().unwrap();
+
+    // The first frame of a drop-minute is the one right after the dropped frame numbers.
+    let suggestion = format!(
+        "{}{:0width$}{}",
+        &input[..frames_span.start],
+        dropped,
+        &input[frames_span.end..],
+        width = frames_span.len(),
+    );
+
+    TimecodeParseError::DropFrameValue(
+        ParseDiagnostic::new(
+            input,
+            format!(
+                "{} at {}: drop-frame tc cannot have a frames value of less than {} on minutes not divisible by 10, found '{}'",
+                input, rate, dropped, frames,
+            ),
+        )
+        .with_span(frames_span)
+        .with_expected(dropped..=timebase - 1)
+        .with_suggestion(suggestion),
+    )
+}
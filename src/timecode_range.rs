@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Framerate, FramesSource, RoundingMode, SpeedChange, Timecode, TimecodeError};
+
+/**
+A span of frames from a start [Timecode] up to, but not including, an end [Timecode], like the
+in and out points of an edit.
+
+Both ends are always at the same [Framerate].
+
+# Examples
+
+```rust
+use vtc::{Timecode, TimecodeRange, rates};
+
+let start = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+let end = Timecode::with_frames("01:00:10:00", rates::F23_98).unwrap();
+let range = TimecodeRange::new(start, end).unwrap();
+
+assert_eq!("00:00:10:00", range.duration().timecode());
+assert!(range.contains(&start));
+assert!(!range.contains(&end));
+assert_eq!("01:00:00:00 - 01:00:10:00", range.to_string());
+```
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimecodeRange {
+    start: Timecode,
+    end: Timecode,
+}
+
+impl TimecodeRange {
+    /// Creates a new [TimecodeRange] from `start` up to, but not including, `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `end` is at a different rate than `start`, or
+    /// [TimecodeError::EndBeforeStart] if `end` is before `start`.
+    pub fn new(start: Timecode, end: Timecode) -> Result<Self, TimecodeError> {
+        TimecodeError::check_rate(end.rate(), start.rate())?;
+        if end < start {
+            return Err(TimecodeError::EndBeforeStart { start, end });
+        }
+
+        Ok(TimecodeRange { start, end })
+    }
+
+    /// Creates a new [TimecodeRange] lasting `duration` from `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if `duration` is at a different rate than `start`,
+    /// or [TimecodeError::EndBeforeStart] if `duration` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{Timecode, TimecodeRange, rates};
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let duration = Timecode::with_frames(48, rates::F24).unwrap();
+    /// let range = TimecodeRange::with_duration(start, duration).unwrap();
+    /// assert_eq!("01:00:02:00", range.end().timecode());
+    /// ```
+    pub fn with_duration(start: Timecode, duration: Timecode) -> Result<Self, TimecodeError> {
+        TimecodeError::check_rate(duration.rate(), start.rate())?;
+        TimecodeRange::new(start, start + duration)
+    }
+
+    /// Creates a new [TimecodeRange] from `start` up to, but not including, `end`, both read at
+    /// `rate` like [Timecode::with_frames].
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::Parse] if `start` or `end` cannot be parsed, or
+    /// [TimecodeError::EndBeforeStart] if `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{TimecodeRange, rates};
+    /// let range = TimecodeRange::with_frames("01:00:00:00", "01:00:10:00", rates::F24).unwrap();
+    /// assert_eq!(240, range.duration().frames());
+    ///
+    /// let range = TimecodeRange::with_frames(24, 48, rates::F24).unwrap();
+    /// assert_eq!("00:00:01:00 - 00:00:02:00", range.to_string());
+    /// ```
+    pub fn with_frames<T: FramesSource>(
+        start: T,
+        end: T,
+        rate: Framerate,
+    ) -> Result<Self, TimecodeError> {
+        TimecodeRange::new(
+            Timecode::with_frames(start, rate)?,
+            Timecode::with_frames(end, rate)?,
+        )
+    }
+
+    /// Returns the first frame of the range.
+    pub fn start(&self) -> Timecode {
+        self.start
+    }
+
+    /// Returns the frame after the last frame of the range.
+    pub fn end(&self) -> Timecode {
+        self.end
+    }
+
+    /// Returns the [Framerate] of the range.
+    pub fn rate(&self) -> Framerate {
+        self.start.rate()
+    }
+
+    /// Returns the length of the range.
+    pub fn duration(&self) -> Timecode {
+        self.end - self.start
+    }
+
+    /// Returns whether the range has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether `tc` is a frame of the range.
+    pub fn contains(&self, tc: &Timecode) -> bool {
+        self.start <= *tc && *tc < self.end
+    }
+
+    /// Returns whether the range shares at least one frame with `other`. Empty ranges overlap
+    /// nothing.
+    pub fn overlaps(&self, other: &TimecodeRange) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the frames the range shares with `other`, or [None] if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{TimecodeRange, rates};
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+    ///
+    /// let shared = range(0, 48).intersection(&range(24, 96)).unwrap();
+    /// assert_eq!(range(24, 48), shared);
+    ///
+    /// assert_eq!(None, range(0, 24).intersection(&range(24, 48)));
+    /// ```
+    pub fn intersection(&self, other: &TimecodeRange) -> Option<TimecodeRange> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(TimecodeRange {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// Returns the range after a [SpeedChange], keeping the start where it is and scaling the
+    /// duration. Partial frames are snapped using `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{RoundingMode, SpeedChange, Timecode, TimecodeRange, rates};
+    /// let start = Timecode::with_frames("01:00:00:00", rates::F24).unwrap();
+    /// let end = Timecode::with_frames("02:00:00:00", rates::F24).unwrap();
+    /// let reel = TimecodeRange::new(start, end).unwrap();
+    ///
+    /// let pal = SpeedChange::new(rates::F24, rates::F25);
+    /// let sped_up = reel.change_speed(pal, RoundingMode::HalfUp);
+    ///
+    /// assert_eq!("01:00:00:00", sped_up.start().timecode());
+    /// assert_eq!("00:57:36:00", sped_up.duration().timecode());
+    /// ```
+    pub fn change_speed(&self, speed: SpeedChange, mode: RoundingMode) -> Self {
+        TimecodeRange {
+            start: self.start,
+            end: self.start + self.duration().change_speed(speed, mode),
+        }
+    }
+}
+
+impl Display for TimecodeRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.start.timecode(), self.end.timecode())
+    }
+}
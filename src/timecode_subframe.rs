@@ -0,0 +1,371 @@
+use num::{abs, Rational64, Signed};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Neg, Sub};
+
+use crate::consts::TIMECODE_REGEX;
+use crate::source_frames::parse_timecode_string;
+use crate::{
+    Framerate, ParseDiagnostic, RoundingMode, SecondsSource, SecondsSourceResult, Timecode,
+    TimecodeParseError, TimecodeSections,
+};
+
+/// The [Result] type returned by [SubframeTimecode::with_seconds].
+pub type SubframeTimecodeParseResult = Result<SubframeTimecode, TimecodeParseError>;
+
+/// A struct that bundles a sub-frame timecode str together with the number of subframes each
+/// frame is divided into, to hint parsing.
+///
+/// This struct, which implements [SecondsSource], parses strings in the
+/// `{HOURS}:{MINUTES}:{SECONDS}:{FRAMES}.{SUBFRAMES}` format, like `01:00:00:00.42`.
+///
+/// # Examples
+///
+/// ```rust
+/// use vtc::{SubframeStr, SubframeTimecode, Timecode, rates};
+/// use num::Rational64;
+///
+/// let tc = SubframeTimecode::with_seconds(SubframeStr::new("00:00:01:00.50", 100), rates::F24)
+///     .unwrap();
+/// assert_eq!(Rational64::new(49, 48), tc.seconds());
+///
+/// // Parsing into a regular Timecode rounds to the nearest frame.
+/// let tc = Timecode::with_seconds(SubframeStr::new("00:00:01:00.50", 100), rates::F24).unwrap();
+/// assert_eq!("00:00:01:01", tc.timecode());
+/// ```
+#[derive(Debug)]
+pub struct SubframeStr<'a> {
+    pub(crate) input: &'a str,
+    pub(crate) divisions: i64,
+}
+
+impl<'a> SubframeStr<'a> {
+    /// Create a [SubframeStr] object from a string and the number of subframes in a frame.
+    pub fn new(input: &'a str, divisions: i64) -> Self {
+        SubframeStr { input, divisions }
+    }
+}
+
+impl<'a> SecondsSource for SubframeStr<'a> {
+    fn to_seconds(&self, rate: Framerate) -> SecondsSourceResult {
+        if self.divisions < 1 {
+            return Err(TimecodeParseError::Conversion(
+                ParseDiagnostic::new(
+                    self.input,
+                    format!(
+                        "subframe divisions must be at least 1, got {}",
+                        self.divisions
+                    ),
+                )
+                .with_expected(1..=i64::MAX),
+            ));
+        }
+
+        let (tc_str, subframes_str) = match self.input.rsplit_once('.') {
+            Some(split) => split,
+            None => (self.input, "0"),
+        };
+
+        let matched = match TIMECODE_REGEX.captures(tc_str) {
+            Some(matched) => matched,
+            None => {
+                return Err(TimecodeParseError::UnknownStrFormat(
+                    ParseDiagnostic::new(
+                        self.input,
+                        format!("{} is not a known subframe timecode format", self.input),
+                    )
+                    .with_span(0..tc_str.len()),
+                ))
+            }
+        };
+
+        let is_negative = matched.name("negative").is_some();
+        let frames = parse_timecode_string(matched, rate)?;
+
+        let is_digits =
+            !subframes_str.is_empty() && subframes_str.bytes().all(|b| b.is_ascii_digit());
+        let subframes = match subframes_str.parse::<i64>() {
+            Ok(parsed) if is_digits => parsed,
+            _ => {
+                return Err(TimecodeParseError::UnknownStrFormat(
+                    ParseDiagnostic::new(
+                        self.input,
+                        format!("{} is not a known subframe timecode format", self.input),
+                    )
+                    .with_span(self.input.len() - subframes_str.len()..self.input.len()),
+                ))
+            }
+        };
+
+        if subframes >= self.divisions {
+            return Err(TimecodeParseError::UnknownStrFormat(
+                ParseDiagnostic::new(
+                    self.input,
+                    format!(
+                        "subframes value of {} must be less than {} divisions in '{}'",
+                        subframes, self.divisions, self.input,
+                    ),
+                )
+                .with_span(self.input.len() - subframes_str.len()..self.input.len())
+                .with_expected(0..=self.divisions - 1),
+            ));
+        }
+
+        let mut subframes_rat = Rational64::new(subframes, self.divisions);
+        if is_negative {
+            subframes_rat = -subframes_rat;
+        }
+
+        Ok((Rational64::from_integer(frames) + subframes_rat) / rate.playback())
+    }
+}
+
+/**
+[SubframeTimecode] is a timecode which is not snapped to a whole frame.
+
+[Timecode] always rounds the seconds it is created with to the nearest frame. Audio post
+workflows like Pro Tools divide each frame into 80 or 100 subframes, and subtitle formats
+often place events at millisecond offsets between frames. [SubframeTimecode] is the opt-in
+type for these workflows: it keeps the exact [Rational64] seconds it was created with, and
+exposes the subframe index for any number of frame divisions.
+
+# Examples
+
+```rust
+use vtc::{SubframeTimecode, rates};
+use num::Rational64;
+
+// 1/3 of a second at 24 fps is 8 frames exactly, 1/7 of a second is not.
+let tc = SubframeTimecode::with_seconds(Rational64::new(1, 7), rates::F24).unwrap();
+assert_eq!(Rational64::new(1, 7), tc.seconds());
+assert_eq!(3, tc.frame().frames());
+assert_eq!(42, tc.subframes(100));
+assert_eq!("00:00:00:03.42", tc.timecode(100));
+assert_eq!("00:00:00:03.34", tc.timecode(80));
+```
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct SubframeTimecode {
+    seconds: Rational64,
+    rate: Framerate,
+}
+
+impl SubframeTimecode {
+    /// Returns the Framerate of the timecode.
+    pub fn rate(&self) -> Framerate {
+        self.rate
+    }
+
+    /// Returns the exact rational representation of the real-world seconds that would have
+    /// elapsed between 00:00:00:00 and this timecode. Unlike [Timecode::seconds], this value is
+    /// never rounded to a frame.
+    pub fn seconds(&self) -> Rational64 {
+        self.seconds
+    }
+
+    /// Returns the [Timecode] of the whole frame this value falls within.
+    ///
+    /// Negative values are treated symmetrically to positive ones, so `-00:00:00:03.42` falls
+    /// within frame `-00:00:00:03`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{SubframeTimecode, rates};
+    /// use num::Rational64;
+    /// let tc = SubframeTimecode::with_seconds(Rational64::new(-1, 7), rates::F24).unwrap();
+    /// assert_eq!("-00:00:00:03", tc.frame().timecode());
+    /// ```
+    pub fn frame(&self) -> Timecode {
+        let mode = if self.seconds.is_negative() {
+            RoundingMode::Ceil
+        } else {
+            RoundingMode::Floor
+        };
+        self.to_timecode(mode)
+    }
+
+    /// Returns a [Timecode] snapped to a whole frame using `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{SubframeTimecode, RoundingMode, rates};
+    /// use num::Rational64;
+    /// let tc = SubframeTimecode::with_seconds(Rational64::new(1, 7), rates::F24).unwrap();
+    /// assert_eq!("00:00:00:03", tc.to_timecode(RoundingMode::HalfUp).timecode());
+    /// assert_eq!("00:00:00:04", tc.to_timecode(RoundingMode::Ceil).timecode());
+    /// ```
+    pub fn to_timecode(&self, mode: RoundingMode) -> Timecode {
+        Timecode::with_rational_seconds_rounded(self.seconds, self.rate, mode)
+    }
+
+    /// Returns the index of the subframe this value falls within when each frame is divided
+    /// into `divisions` subframes. Subframes count away from zero for negative values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{SubframeTimecode, rates};
+    /// use num::Rational64;
+    /// // Half a frame at 24 fps.
+    /// let tc = SubframeTimecode::with_seconds(Rational64::new(1, 48), rates::F24).unwrap();
+    /// assert_eq!(50, tc.subframes(100));
+    /// assert_eq!(40, tc.subframes(80));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisions` is less than 1.
+    pub fn subframes(&self, divisions: i64) -> i64 {
+        assert!(divisions > 0, "subframe divisions must be at least 1");
+        let frames = abs(self.seconds) * self.rate.playback();
+        (frames.fract() * divisions).floor().to_integer()
+    }
+
+    /// The individual sections of the frame this value falls within. See
+    /// [Timecode::sections].
+    pub fn sections(&self) -> TimecodeSections {
+        let mut sections = self.frame().sections();
+        sections.negative = self.seconds.is_negative();
+        sections
+    }
+
+    /// Returns the formatted SMPTE timecode with a subframe field, when each frame is divided
+    /// into `divisions` subframes: (ex: 01:00:00:00.42).
+    ///
+    /// The subframe field is zero-padded to the width of the largest subframe index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{SubframeTimecode, rates};
+    /// use num::Rational64;
+    /// let tc = SubframeTimecode::with_seconds(Rational64::new(3600001, 1000), rates::F24).unwrap();
+    /// assert_eq!("01:00:00:00.02", tc.timecode(100));
+    /// assert_eq!("01:00:00:00.024", tc.timecode(1000));
+    ///
+    /// let tc = SubframeTimecode::with_seconds(Rational64::new(-3600001, 1000), rates::F24).unwrap();
+    /// assert_eq!("-01:00:00:00.02", tc.timecode(100));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisions` is less than 1.
+    pub fn timecode(&self, divisions: i64) -> String {
+        let subframes = self.subframes(divisions);
+        let width = (divisions - 1).to_string().len();
+
+        // The zero frame cannot carry a sign on its own, so values between 0 and -1 frames need
+        // to have their sign added back.
+        let frame = self.frame();
+        let sign = if self.seconds.is_negative() && frame.frames() == 0 {
+            "-"
+        } else {
+            ""
+        };
+
+        format!(
+            "{}{}.{:0width$}",
+            sign,
+            frame.timecode(),
+            subframes,
+            width = width
+        )
+    }
+
+    /// Returns a new [SubframeTimecode] with a [SubframeTimecode::seconds] return value exactly
+    /// equal to the seconds arg.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - A value which can be represented as a number of seconds. This includes
+    ///   [SubframeStr] values.
+    ///
+    /// * `rate` - The Framerate of the timecode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use vtc::{SubframeStr, SubframeTimecode, rates};
+    /// let tc = SubframeTimecode::with_seconds(SubframeStr::new("01:00:00:12.42", 100), rates::F24)
+    ///     .unwrap();
+    /// assert_eq!("01:00:00:12.42", tc.timecode(100));
+    /// assert_eq!(86412, tc.frame().frames());
+    /// ```
+    pub fn with_seconds<T: SecondsSource>(
+        seconds: T,
+        rate: Framerate,
+    ) -> SubframeTimecodeParseResult {
+        let seconds = seconds.to_seconds(rate)?;
+        Ok(SubframeTimecode { seconds, rate })
+    }
+}
+
+impl From<Timecode> for SubframeTimecode {
+    fn from(tc: Timecode) -> Self {
+        SubframeTimecode {
+            seconds: tc.seconds(),
+            rate: tc.rate(),
+        }
+    }
+}
+
+impl Display for SubframeTimecode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{} @ {}]", self.timecode(100), self.rate)
+    }
+}
+
+impl PartialEq for SubframeTimecode {
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+    }
+}
+
+impl Eq for SubframeTimecode {}
+
+impl PartialOrd for SubframeTimecode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SubframeTimecode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.seconds.cmp(&other.seconds)
+    }
+}
+
+impl Add for SubframeTimecode {
+    type Output = SubframeTimecode;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        SubframeTimecode {
+            seconds: self.seconds + rhs.seconds,
+            rate: self.rate,
+        }
+    }
+}
+
+impl Sub for SubframeTimecode {
+    type Output = SubframeTimecode;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SubframeTimecode {
+            seconds: self.seconds - rhs.seconds,
+            rate: self.rate,
+        }
+    }
+}
+
+impl Neg for SubframeTimecode {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        SubframeTimecode {
+            seconds: -self.seconds,
+            rate: self.rate,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, DropFrameMarker, Framerate, Ntsc, Rollover, SignStyle, Timecode, TimecodeFormat,
+        TimecodeParseError,
+    };
+    use rstest::rstest;
+
+    struct FormatCase {
+        tc: &'static str,
+        rate: Framerate,
+        format: TimecodeFormat,
+        expected: &'static str,
+    }
+
+    #[rstest]
+    // Defaults match timecode() ----
+    #[case::default_ndf(FormatCase{
+        tc: "01:00:00:00",
+        rate: rates::F23_98,
+        format: TimecodeFormat::default(),
+        expected: "01:00:00:00",
+    })]
+    #[case::default_df(FormatCase{
+        tc: "-00:01:00;02",
+        rate: rates::F29_97_DF,
+        format: TimecodeFormat::default(),
+        expected: "-00:01:00;02",
+    })]
+    #[case::default_hfr(FormatCase{
+        tc: "00:00:01:07",
+        rate: Framerate::with_timebase(120, Ntsc::NonDropFrame).unwrap(),
+        format: TimecodeFormat::default(),
+        expected: "00:00:01:007",
+    })]
+    // Separators ----
+    #[case::separators(FormatCase{
+        tc: "01:02:03:04",
+        rate: rates::F24,
+        format: TimecodeFormat::default().separator('.').frame_separator(','),
+        expected: "01.02.03,04",
+    })]
+    #[case::minutes_separator(FormatCase{
+        tc: "01:02:03:04",
+        rate: rates::F24,
+        format: TimecodeFormat::default().minutes_separator(','),
+        expected: "01:02,03:04",
+    })]
+    #[case::hours_separator(FormatCase{
+        tc: "01:02:03:04",
+        rate: rates::F24,
+        format: TimecodeFormat::default().hours_separator('h'),
+        expected: "01h02:03:04",
+    })]
+    // Drop-frame markers ----
+    #[case::df_all_separators(FormatCase{
+        tc: "01:00:00;00",
+        rate: rates::F29_97_DF,
+        format: TimecodeFormat::default().drop_frame_marker(DropFrameMarker::AllSeparators),
+        expected: "01;00;00;00",
+    })]
+    #[case::df_custom_separator(FormatCase{
+        tc: "01:00:00;00",
+        rate: rates::F29_97_DF,
+        format: TimecodeFormat::default().drop_frame_separator('.'),
+        expected: "01:00:00.00",
+    })]
+    #[case::df_suffix(FormatCase{
+        tc: "01:00:00;00",
+        rate: rates::F59_94_DF,
+        format: TimecodeFormat::default()
+            .drop_frame_marker(DropFrameMarker::Suffix)
+            .drop_frame_suffix(" (DF)"),
+        expected: "01:00:00:00 (DF)",
+    })]
+    #[case::df_none(FormatCase{
+        tc: "01:00:00;00",
+        rate: rates::F29_97_DF,
+        format: TimecodeFormat::default().drop_frame_marker(DropFrameMarker::None),
+        expected: "01:00:00:00",
+    })]
+    #[case::df_marker_ignored_for_ndf(FormatCase{
+        tc: "01:00:00:00",
+        rate: rates::F29_97_NDF,
+        format: TimecodeFormat::default().drop_frame_marker(DropFrameMarker::Suffix),
+        expected: "01:00:00:00",
+    })]
+    // Padding ----
+    #[case::hour_digits_one(FormatCase{
+        tc: "01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().hour_digits(1),
+        expected: "1:00:00:00",
+    })]
+    #[case::hour_digits_three(FormatCase{
+        tc: "01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().hour_digits(3),
+        expected: "001:00:00:00",
+    })]
+    #[case::frame_digits(FormatCase{
+        tc: "01:00:00:01",
+        rate: rates::F24,
+        format: TimecodeFormat::default().frame_digits(3),
+        expected: "01:00:00:001",
+    })]
+    #[case::trim_leading_zeros(FormatCase{
+        tc: "00:00:03:12",
+        rate: rates::F24,
+        format: TimecodeFormat::default().trim_leading_zeros(true),
+        expected: "03:12",
+    })]
+    #[case::trim_leading_zeros_all(FormatCase{
+        tc: "00:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().trim_leading_zeros(true),
+        expected: "00",
+    })]
+    #[case::trim_leading_zeros_keeps_inner(FormatCase{
+        tc: "00:10:00:12",
+        rate: rates::F24,
+        format: TimecodeFormat::default().trim_leading_zeros(true),
+        expected: "10:00:12",
+    })]
+    // Signs ----
+    #[case::sign_always_positive(FormatCase{
+        tc: "01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().sign(SignStyle::Always),
+        expected: "+01:00:00:00",
+    })]
+    #[case::sign_always_zero(FormatCase{
+        tc: "00:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().sign(SignStyle::Always),
+        expected: "00:00:00:00",
+    })]
+    #[case::sign_always_negative(FormatCase{
+        tc: "-01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().sign(SignStyle::Always),
+        expected: "-01:00:00:00",
+    })]
+    #[case::sign_parentheses(FormatCase{
+        tc: "-01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().sign(SignStyle::Parentheses),
+        expected: "(01:00:00:00)",
+    })]
+    #[case::sign_never(FormatCase{
+        tc: "-01:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default().sign(SignStyle::Never),
+        expected: "01:00:00:00",
+    })]
+    // Rollover ----
+    #[case::rollover_wrap(FormatCase{
+        tc: "-00:00:00:01",
+        rate: rates::F24,
+        format: TimecodeFormat::default().rollover(Rollover::Wrap24Hours),
+        expected: "23:59:59:23",
+    })]
+    #[case::rollover_days(FormatCase{
+        tc: "49:00:00;00",
+        rate: rates::F29_97_DF,
+        format: TimecodeFormat::default()
+            .rollover(Rollover::Days)
+            .drop_frame_marker(DropFrameMarker::AllSeparators),
+        expected: "2;01;00;00;00",
+    })]
+    #[case::rollover_days_negative(FormatCase{
+        tc: "-25:00:00:00",
+        rate: rates::F24,
+        format: TimecodeFormat::default()
+            .rollover(Rollover::Days)
+            .sign(SignStyle::Parentheses),
+        expected: "(1:01:00:00:00)",
+    })]
+    fn test_format(#[case] case: FormatCase) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(case.tc, case.rate)?;
+        assert_eq!(case.expected, tc.format(&case.format), "{:?}", case.format);
+        Ok(())
+    }
+
+    /// tests that the default format matches timecode() for every rate under 100 fps.
+    #[rstest]
+    fn test_default_matches_timecode(
+        #[values(
+            rates::F23_98,
+            rates::F24,
+            rates::F25,
+            rates::F29_97_NDF,
+            rates::F29_97_DF,
+            rates::F59_94_DF,
+            rates::F60
+        )]
+        rate: Framerate,
+        #[values("00:00:00:00", "-01:00:00:01", "12:34:56:12", "37:10:00:02")] tc: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc, rate)?;
+        assert_eq!(tc.timecode(), tc.format(&TimecodeFormat::default()));
+        Ok(())
+    }
+}
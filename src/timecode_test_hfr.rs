@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, Framerate, HfrMode, HfrStr, Ntsc, Timecode, TimecodeParseError, TimecodeSections,
+    };
+    use rstest::rstest;
+
+    struct HfrCase {
+        rate: Framerate,
+        frames: i64,
+        full_frames: &'static str,
+        frame_group: &'static str,
+        group_size: i64,
+    }
+
+    /// tests formatting and parsing high frame rate timecode in both modes.
+    #[rstest]
+    #[case::f120(HfrCase{
+        rate: rates::F120,
+        frames: 432119,
+        full_frames: "01:00:00:119",
+        frame_group: "01:00:00:29.3",
+        group_size: 4,
+    })]
+    #[case::f119_88_ndf(HfrCase{
+        rate: rates::F119_88_NDF,
+        frames: 432000,
+        full_frames: "01:00:00:000",
+        frame_group: "01:00:00:00.0",
+        group_size: 4,
+    })]
+    #[case::f119_88_df(HfrCase{
+        rate: rates::F119_88_DF,
+        frames: 431683,
+        full_frames: "01:00:00;115",
+        frame_group: "01:00:00;28.3",
+        group_size: 4,
+    })]
+    #[case::f100(HfrCase{
+        rate: rates::F100,
+        frames: 360099,
+        full_frames: "01:00:00:99",
+        frame_group: "01:00:00:24.3",
+        group_size: 4,
+    })]
+    #[case::f96(HfrCase{
+        rate: rates::F96,
+        frames: 95,
+        full_frames: "00:00:00:95",
+        frame_group: "00:00:00:23.3",
+        group_size: 4,
+    })]
+    #[case::f59_94_df(HfrCase{
+        rate: rates::F59_94_DF,
+        frames: 215785,
+        full_frames: "01:00:00;01",
+        frame_group: "01:00:00;00.1",
+        group_size: 2,
+    })]
+    #[case::f50(HfrCase{
+        rate: rates::F50,
+        frames: 49,
+        full_frames: "00:00:00:49",
+        frame_group: "00:00:00:24.1",
+        group_size: 2,
+    })]
+    #[case::f47_95(HfrCase{
+        rate: rates::F47_95,
+        frames: 47,
+        full_frames: "00:00:00:47",
+        frame_group: "00:00:00:23.1",
+        group_size: 2,
+    })]
+    #[case::negative(HfrCase{
+        rate: rates::F120,
+        frames: -5,
+        full_frames: "-00:00:00:005",
+        frame_group: "-00:00:00:01.1",
+        group_size: 4,
+    })]
+    fn test_hfr(#[case] case: HfrCase) {
+        let tc = Timecode::with_frames(case.frames, case.rate).unwrap();
+
+        assert_eq!(
+            Some(case.full_frames.to_string()),
+            tc.timecode_hfr(HfrMode::FullFrames),
+            "full frames"
+        );
+        assert_eq!(
+            Some(case.frame_group.to_string()),
+            tc.timecode_hfr(HfrMode::FrameGroup),
+            "frame group"
+        );
+
+        let sections = tc.hfr_sections().unwrap();
+        assert_eq!(case.group_size, sections.group_size, "group size");
+        assert_eq!(
+            tc.sections().frames,
+            sections.base.frames * sections.group_size + sections.index,
+            "sections rebuild frames"
+        );
+
+        let parsed = Timecode::with_frames(
+            HfrStr::new(case.full_frames, HfrMode::FullFrames),
+            case.rate,
+        )
+        .unwrap();
+        assert_eq!(case.frames, parsed.frames(), "parsed full frames");
+
+        let parsed = Timecode::with_frames(
+            HfrStr::new(case.frame_group, HfrMode::FrameGroup),
+            case.rate,
+        )
+        .unwrap();
+        assert_eq!(case.frames, parsed.frames(), "parsed frame group");
+    }
+
+    #[test]
+    fn test_hfr_sections() {
+        let tc = Timecode::with_frames("01:02:03:117", rates::F120).unwrap();
+        let sections = tc.hfr_sections().unwrap();
+        assert_eq!(
+            TimecodeSections {
+                negative: false,
+                hours: 1,
+                minutes: 2,
+                seconds: 3,
+                frames: 29,
+            },
+            sections.base
+        );
+        assert_eq!(1, sections.index);
+    }
+
+    #[rstest]
+    #[case::f24(rates::F24)]
+    #[case::f29_97_df(rates::F29_97_DF)]
+    #[case::f1000(rates::F1000)]
+    #[case::no_base(Framerate::with_timebase(35, Ntsc::None).unwrap())]
+    fn test_hfr_not_high_frame_rate(#[case] rate: Framerate) {
+        let tc = Timecode::with_frames(1, rate).unwrap();
+        assert_eq!(None, tc.hfr_sections(), "no sections");
+        assert_eq!(None, tc.timecode_hfr(HfrMode::FullFrames), "no full frames");
+        assert_eq!(None, tc.timecode_hfr(HfrMode::FrameGroup), "no frame group");
+
+        let err = Timecode::with_frames(HfrStr::new("00:00:00:00.0", HfrMode::FrameGroup), rate)
+            .unwrap_err();
+        assert!(matches!(err, TimecodeParseError::Conversion(_)), "{}", err);
+    }
+
+    #[rstest]
+    #[case::missing_index("01:00:00:29", rates::F120)]
+    #[case::index_too_large("01:00:00:29.4", rates::F120)]
+    #[case::index_signed("01:00:00:29.+1", rates::F120)]
+    #[case::base_frames_too_large("01:00:00:30.0", rates::F120)]
+    #[case::dropped_label("00:01:00;00.1", rates::F59_94_DF)]
+    #[case::not_timecode("abc.1", rates::F120)]
+    fn test_hfr_frame_group_rejects(#[case] input: &str, #[case] rate: Framerate) {
+        let result = Timecode::with_frames(HfrStr::new(input, HfrMode::FrameGroup), rate);
+        assert!(result.is_err(), "{} is rejected", input);
+    }
+
+    /// Shorthand for building the sections of a frame pair label.
+    fn label(
+        negative: bool,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        frames: i64,
+    ) -> TimecodeSections {
+        TimecodeSections {
+            negative,
+            hours,
+            minutes,
+            seconds,
+            frames,
+        }
+    }
+
+    /// tests converting to and from a frame pair label in both directions.
+    #[rstest]
+    #[case::f60_first(rates::F60, "01:00:00:58", label(false, 1, 0, 0, 29), 0)]
+    #[case::f60_second(rates::F60, "01:00:00:59", label(false, 1, 0, 0, 29), 1)]
+    #[case::f50(rates::F50, "00:00:01:01", label(false, 0, 0, 1, 0), 1)]
+    #[case::f59_94_df_drop_minute(rates::F59_94_DF, "00:01:00;04", label(false, 0, 1, 0, 2), 0)]
+    #[case::f59_94_df_tenth_minute(rates::F59_94_DF, "00:10:00;01", label(false, 0, 10, 0, 0), 1)]
+    #[case::f59_94_ndf(rates::F59_94_NDF, "23:59:59:59", label(false, 23, 59, 59, 29), 1)]
+    #[case::negative(rates::F60, "-00:00:00:03", label(true, 0, 0, 0, 1), 1)]
+    fn test_frame_pair(
+        #[case] rate: Framerate,
+        #[case] timecode: &str,
+        #[case] sections: TimecodeSections,
+        #[case] pair_index: i64,
+    ) {
+        let tc = Timecode::with_frames(timecode, rate).unwrap();
+        assert_eq!(
+            Some((sections.clone(), pair_index)),
+            tc.frame_pair(),
+            "frame pair"
+        );
+
+        let parsed = Timecode::with_frame_pair(sections, pair_index, rate).unwrap();
+        assert_eq!(tc, parsed, "parsed from frame pair");
+    }
+
+    #[rstest]
+    #[case::f29_97_df(rates::F29_97_DF)]
+    #[case::f120(rates::F120)]
+    #[case::f24(rates::F24)]
+    fn test_frame_pair_not_paired(#[case] rate: Framerate) {
+        let tc = Timecode::with_frames(1, rate).unwrap();
+        assert_eq!(None, tc.frame_pair(), "no frame pair");
+
+        let err = Timecode::with_frame_pair(label(false, 0, 0, 0, 0), 0, rate).unwrap_err();
+        assert!(matches!(err, TimecodeParseError::Conversion(_)), "{}", err);
+    }
+
+    #[rstest]
+    #[case::frames_too_large(label(false, 0, 0, 0, 30), 0, rates::F60, Some(9..11))]
+    #[case::seconds_too_large(label(false, 0, 0, 60, 0), 0, rates::F60, Some(6..8))]
+    #[case::minutes_negative(label(true, 0, -1, 0, 0), 0, rates::F60, Some(4..6))]
+    #[case::pair_index_too_large(label(false, 0, 0, 0, 0), 2, rates::F60, None)]
+    #[case::pair_index_negative(label(false, 0, 0, 0, 0), -1, rates::F50, None)]
+    fn test_frame_pair_out_of_range(
+        #[case] sections: TimecodeSections,
+        #[case] pair_index: i64,
+        #[case] rate: Framerate,
+        #[case] span: Option<std::ops::Range<usize>>,
+    ) {
+        let err = Timecode::with_frame_pair(sections, pair_index, rate).unwrap_err();
+        assert!(
+            matches!(err, TimecodeParseError::SectionOutOfRange(_)),
+            "{}",
+            err
+        );
+        assert_eq!(span, err.diagnostic().span(), "span");
+    }
+
+    #[test]
+    fn test_frame_pair_dropped_label() {
+        let err =
+            Timecode::with_frame_pair(label(false, 0, 1, 0, 1), 0, rates::F59_94_DF).unwrap_err();
+        assert!(
+            matches!(err, TimecodeParseError::DropFrameValue(_)),
+            "{}",
+            err
+        );
+        assert_eq!(Some("00:01:00;02"), err.diagnostic().suggestion());
+    }
+}
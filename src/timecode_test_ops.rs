@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{rates, Framerate, Timecode, TimecodeParseError};
+    use crate::{rates, Framerate, Rollover, Timecode, TimecodeParseError};
     use rstest::rstest;
     use std::fmt::{Debug, Display};
     use std::ops::{Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
@@ -562,4 +562,169 @@ mod test {
         let rebased = case.tc_in.rebase(case.new_rate);
         assert_eq!(case.expected, rebased, "rebased value")
     }
+
+    /// tests moving a timecode to a new rate while keeping its label.
+    #[rstest]
+    #[case::df_to_ndf(
+        "01:00:00;00",
+        rates::F29_97_DF,
+        rates::F29_97_NDF,
+        "01:00:00:00",
+        108000
+    )]
+    #[case::ndf_to_df(
+        "00:10:00:00",
+        rates::F29_97_NDF,
+        rates::F29_97_DF,
+        "00:10:00;00",
+        17982
+    )]
+    #[case::ntsc_to_whole("01:00:00:00", rates::F23_98, rates::F24, "01:00:00:00", 86400)]
+    #[case::to_larger("00:00:01:23", rates::F24, rates::F60, "00:00:01:23", 83)]
+    #[case::negative("-00:00:01:02", rates::F25, rates::F24, "-00:00:01:02", -26)]
+    #[case::high_frame_rate("00:00:00:99", rates::F100, rates::F120, "00:00:00:99", 99)]
+    fn test_relabel(
+        #[case] tc_in: &str,
+        #[case] rate_in: Framerate,
+        #[case] new_rate: Framerate,
+        #[case] expected: &str,
+        #[case] frames: i64,
+    ) {
+        let tc = Timecode::with_frames(tc_in, rate_in).unwrap();
+        let relabeled = tc.relabel(new_rate).expect("relabeled");
+        assert_eq!(expected, relabeled.timecode(), "label");
+        assert_eq!(frames, relabeled.frames(), "frames");
+        assert_eq!(new_rate, relabeled.rate(), "rate");
+    }
+
+    #[rstest]
+    #[case::frames_too_large("00:00:00:24", rates::F25, rates::F24, Some(9..11))]
+    #[case::dropped_label("00:01:00:01", rates::F29_97_NDF, rates::F29_97_DF, Some(9..11))]
+    fn test_relabel_rejects(
+        #[case] tc_in: &str,
+        #[case] rate_in: Framerate,
+        #[case] new_rate: Framerate,
+        #[case] span: Option<std::ops::Range<usize>>,
+    ) {
+        let tc = Timecode::with_frames(tc_in, rate_in).unwrap();
+        let err = tc.relabel(new_rate).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                TimecodeParseError::SectionOutOfRange(_) | TimecodeParseError::DropFrameValue(_)
+            ),
+            "{}",
+            err
+        );
+        assert_eq!(span, err.diagnostic().span(), "span");
+    }
+
+    /// tests formatting values past 24 hours and below zero with each rollover policy.
+    #[rstest]
+    #[case(
+        "34:46:26:04",
+        rates::F23_98,
+        "34:46:26:04",
+        "10:46:26:04",
+        "1:10:46:26:04"
+    )]
+    #[case(
+        "24:00:00:00",
+        rates::F24,
+        "24:00:00:00",
+        "00:00:00:00",
+        "1:00:00:00:00"
+    )]
+    #[case("23:59:59:23", rates::F24, "23:59:59:23", "23:59:59:23", "23:59:59:23")]
+    #[case(
+        "-00:00:00:01",
+        rates::F24,
+        "-00:00:00:01",
+        "23:59:59:23",
+        "-00:00:00:01"
+    )]
+    #[case(
+        "-25:00:00:00",
+        rates::F25,
+        "-25:00:00:00",
+        "23:00:00:00",
+        "-1:01:00:00:00"
+    )]
+    #[case(
+        "34:46:26;04",
+        rates::F29_97_DF,
+        "34:46:26;04",
+        "10:46:26;04",
+        "1:10:46:26;04"
+    )]
+    #[case(
+        "-00:00:00;01",
+        rates::F29_97_DF,
+        "-00:00:00;01",
+        "23:59:59;29",
+        "-00:00:00;01"
+    )]
+    #[case(
+        "-00:10:00;00",
+        rates::F29_97_DF,
+        "-00:10:00;00",
+        "23:50:00;00",
+        "-00:10:00;00"
+    )]
+    #[case(
+        "72:01:00;04",
+        rates::F59_94_DF,
+        "72:01:00;04",
+        "00:01:00;04",
+        "3:00:01:00;04"
+    )]
+    fn test_rollover(
+        #[case] tc_str: &str,
+        #[case] rate: Framerate,
+        #[case] unbounded: &str,
+        #[case] wrapped: &str,
+        #[case] days: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc_str, rate)?;
+
+        assert_eq!(
+            unbounded,
+            tc.timecode_rollover(Rollover::Unbounded),
+            "unbounded"
+        );
+        assert_eq!(
+            wrapped,
+            tc.timecode_rollover(Rollover::Wrap24Hours),
+            "wrapped"
+        );
+        assert_eq!(days, tc.timecode_rollover(Rollover::Days), "days");
+
+        let rolled = tc.rollover(Rollover::Wrap24Hours);
+        assert_eq!(wrapped, rolled.timecode(), "rollover value");
+        assert!(rolled.frames() >= 0, "rollover value is positive");
+        assert!(
+            rolled.frames() < rate.frames_per_24_hours(),
+            "rollover under 24 hours"
+        );
+        Ok(())
+    }
+
+    /// tests that drop-frame and non-drop timecode past 24 hours round-trip the same way.
+    #[rstest]
+    #[case("25:10:00;02", rates::F29_97_DF, "01:10:00;02")]
+    #[case("25:11:00;02", rates::F29_97_DF, "01:11:00;02")]
+    #[case("26:08:00;04", rates::F59_94_DF, "02:08:00;04")]
+    #[case("25:11:00:02", rates::F29_97_NDF, "01:11:00:02")]
+    fn test_past_24_hours_round_trip(
+        #[case] tc_str: &str,
+        #[case] rate: Framerate,
+        #[case] day_earlier: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc_str, rate)?;
+        assert_eq!(tc_str, tc.timecode());
+
+        let day = Timecode::with_frames(rate.frames_per_24_hours(), rate)?;
+        assert_eq!(day_earlier, (tc - day).timecode(), "a day earlier");
+        Ok(())
+    }
 }
@@ -4,8 +4,9 @@ mod test {
     use rstest::rstest;
 
     use crate::{
-        rates, source_ppro_ticks::PremiereTicksSource, FeetFramesStr, FilmFormat, Framerate,
-        FramesSource, Ntsc, SecondsSource, Timecode, TimecodeParseError,
+        rates, source_ppro_ticks::PremiereTicksSource, DurationStr, FeetFramesStr, FilmFormat,
+        FilmFormatSpec, Framerate, FramesSource, Ntsc, Representation, SecondsSource, Timecode,
+        TimecodeParseError,
     };
     use std::fmt::Debug;
     use std::ops::Deref;
@@ -1545,4 +1546,598 @@ mod test {
 
         Ok(())
     }
+
+    /// test strict parsing of well-formed timecode
+    #[rstest]
+    #[case("00:00:00:00", rates::F24, 0)]
+    #[case("01:00:00:00", rates::F23_98, 86400)]
+    #[case("-01:00:00:00", rates::F23_98, -86400)]
+    #[case("23:59:59:23", rates::F24, 2073599)]
+    #[case("00:01:00;02", rates::F29_97_DF, 1800)]
+    #[case("00;01;00;02", rates::F29_97_DF, 1800)]
+    #[case("00:10:00;00", rates::F29_97_DF, 17982)]
+    #[case("00:00:01:59", rates::F59_94_NDF, 119)]
+    #[case(
+        "00:00:00:119",
+        Framerate::with_playback(120, Ntsc::None).unwrap(),
+        119
+    )]
+    #[case("00:00:00:05", rates::F120, 5)]
+    #[case("00:00:00:005", rates::F120, 5)]
+    #[case("00:00:01:99", rates::F1000, 1099)]
+    #[case("00:00:01:099", rates::F1000, 1099)]
+    fn test_parse_strict(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] frames: i64,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::parse_strict(input, rate)?;
+        assert_eq!(frames, tc.frames(), "frames");
+        assert_eq!(Timecode::with_frames(input, rate)?, tc, "matches lenient");
+
+        Ok(())
+    }
+
+    /// test strict parsing accepts the timecode Timecode::timecode writes at every rate
+    #[test]
+    fn test_parse_strict_round_trip() -> Result<(), TimecodeParseError> {
+        let frame_counts = [
+            0, 1, 5, 23, 99, 100, 101, 1000, 1799, 1800, 17982, 1_000_000,
+        ];
+        for rate in rates::ALL.iter().copied() {
+            for frame_count in frame_counts.iter().flat_map(|count| [*count, -count]) {
+                let tc = Timecode::with_frames(frame_count, rate)?;
+                let parsed = Timecode::parse_strict(&tc.timecode(), rate)
+                    .unwrap_or_else(|err| panic!("{}", err));
+                assert_eq!(tc, parsed, "{} at {}", tc.timecode(), rate);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// test strict parsing rejects malformed timecode
+    #[rstest]
+    #[case(" 01:00:00:00", rates::F24, "Whitespace")]
+    #[case("01:00:00:00\n", rates::F24, "Whitespace")]
+    #[case("01: 00:00:00", rates::F24, "Whitespace")]
+    #[case("00:00:00", rates::F24, "MissingSection")]
+    #[case("01::00:00", rates::F24, "MissingSection")]
+    #[case("", rates::F24, "MissingSection")]
+    #[case("-", rates::F24, "MissingSection")]
+    #[case("1:00:00:00", rates::F24, "MalformedSection")]
+    #[case("01:00:00:000", rates::F24, "MalformedSection")]
+    #[case("01:00:00:00:00", rates::F24, "MalformedSection")]
+    #[case("00:00:00:5", rates::F120, "MalformedSection")]
+    #[case("00:00:00:0005", rates::F120, "MalformedSection")]
+    #[case("24:00:00:00", rates::F24, "SectionOutOfRange")]
+    #[case("00:60:00:00", rates::F24, "SectionOutOfRange")]
+    #[case("00:00:60:00", rates::F24, "SectionOutOfRange")]
+    #[case("00:00:00:24", rates::F24, "SectionOutOfRange")]
+    #[case("00:00:00;30", rates::F29_97_DF, "SectionOutOfRange")]
+    #[case("00:00:00;00", rates::F24, "SeparatorMismatch")]
+    #[case("00;00:00:00", rates::F24, "SeparatorMismatch")]
+    #[case("00:00:00:00", rates::F29_97_DF, "SeparatorMismatch")]
+    #[case("00;00;00:00", rates::F29_97_DF, "SeparatorMismatch")]
+    #[case("00|00|00|00", rates::F24, "SeparatorMismatch")]
+    #[case("+00:00:00:00", rates::F24, "SeparatorMismatch")]
+    #[case("00:01:00;01", rates::F29_97_DF, "DropFrameValue")]
+    #[case("00:01:00;03", rates::F59_94_DF, "DropFrameValue")]
+    fn test_parse_strict_rejects(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] expected: &str,
+    ) {
+        let err = Timecode::parse_strict(input, rate).expect_err("expected parse error");
+        let variant = match err {
+            TimecodeParseError::Whitespace(_) => "Whitespace",
+            TimecodeParseError::MissingSection(_) => "MissingSection",
+            TimecodeParseError::MalformedSection(_) => "MalformedSection",
+            TimecodeParseError::SectionOutOfRange(_) => "SectionOutOfRange",
+            TimecodeParseError::SeparatorMismatch(_) => "SeparatorMismatch",
+            TimecodeParseError::DropFrameValue(_) => "DropFrameValue",
+            _ => "other",
+        };
+        assert_eq!(expected, variant, "{:?}", err);
+    }
+
+    /// test the diagnostics carried by parse errors
+    #[rstest]
+    #[case::out_of_range(
+        "01:00:00:24",
+        rates::F24,
+        Some(9..11),
+        Some(0..=23),
+        "01:00:00:24 at [24]: frames must be < 24",
+        Some("01:00:01:00")
+    )]
+    #[case::minutes_out_of_range(
+        "00:75:00:00",
+        rates::F24,
+        Some(3..5),
+        Some(0..=59),
+        "00:75:00:00 at [24]: minutes must be < 60",
+        Some("01:15:00:00")
+    )]
+    #[case::whitespace(
+        "01:00:00:00 ",
+        rates::F24,
+        Some(11..12),
+        None,
+        "01:00:00:00  at [24]: timecode cannot contain whitespace",
+        Some("01:00:00:00")
+    )]
+    #[case::missing_section(
+        "-3:12",
+        rates::F24,
+        Some(0..5),
+        None,
+        "-3:12 at [24]: found 2 sections, expected hours, minutes, seconds and frames",
+        Some("-00:00:03:12")
+    )]
+    #[case::malformed_section(
+        "1:00:00:00",
+        rates::F24,
+        Some(0..1),
+        None,
+        "1:00:00:00 at [24]: hours must have 2 digits, found '1'",
+        Some("01:00:00:00")
+    )]
+    #[case::high_rate_suggestion(
+        "0:00:00:05",
+        rates::F120,
+        Some(0..1),
+        None,
+        "0:00:00:05 at [120]: hours must have 2 digits, found '0'",
+        Some("00:00:00:05")
+    )]
+    #[case::ndf_separator(
+        "01:00:00;00",
+        rates::F24,
+        Some(8..9),
+        None,
+        "01:00:00;00 at [24]: non-drop-frame timecode must only use ':' separators, found ';'",
+        Some("01:00:00:00")
+    )]
+    #[case::bad_separator(
+        "01.00.00.00",
+        rates::F24,
+        Some(2..3),
+        None,
+        "01.00.00.00 at [24]: '.' is not a valid timecode separator, expected ':' or ';'",
+        None
+    )]
+    #[case::hours_out_of_range(
+        "24:00:00:00",
+        rates::F24,
+        Some(0..2),
+        Some(0..=23),
+        "24:00:00:00 at [24]: hours must be < 24",
+        None
+    )]
+    #[case::dropped_frame(
+        "00:01:00;01",
+        rates::F29_97_DF,
+        Some(9..11),
+        Some(2..=29),
+        "00:01:00;01 at [29.97 NTSC DF]: drop-frame tc cannot have a frames value of less than 2 on minutes not divisible by 10, found '1'",
+        Some("00:01:00;02")
+    )]
+    fn test_parse_strict_diagnostic(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] span: Option<std::ops::Range<usize>>,
+        #[case] expected: Option<std::ops::RangeInclusive<i64>>,
+        #[case] message: &str,
+        #[case] suggestion: Option<&str>,
+    ) {
+        let err = Timecode::parse_strict(input, rate).expect_err("expected parse error");
+        let diagnostic = err.diagnostic();
+
+        assert_eq!(input, diagnostic.input(), "input");
+        assert_eq!(span, diagnostic.span(), "span");
+        assert_eq!(expected, diagnostic.expected(), "expected");
+        assert_eq!(message, diagnostic.message(), "message");
+        assert_eq!(suggestion, diagnostic.suggestion(), "suggestion");
+    }
+
+    /// test the diagnostics carried by lenient parse errors
+    #[rstest]
+    fn test_parse_lenient_diagnostic() {
+        let err = Timecode::with_frames("-01:01:00;01", rates::F29_97_DF).unwrap_err();
+        let diagnostic = err.diagnostic();
+        assert!(matches!(err, TimecodeParseError::DropFrameValue(_)));
+        assert_eq!(Some(10..12), diagnostic.span(), "span");
+        assert_eq!(Some("-01:01:00;02"), diagnostic.suggestion(), "suggestion");
+
+        let err = Timecode::with_frames("notatimecode", rates::F24).unwrap_err();
+        assert!(matches!(err, TimecodeParseError::UnknownStrFormat(_)));
+        assert_eq!("notatimecode", err.diagnostic().input(), "input");
+        assert_eq!(Some(0..12), err.diagnostic().span(), "span");
+        assert_eq!(None, err.diagnostic().suggestion(), "suggestion");
+    }
+
+    /// test parse errors can be used as std errors
+    #[rstest]
+    fn test_parse_error_display() {
+        let err: Box<dyn std::error::Error> =
+            Box::new(Timecode::parse_strict("01:00:00:24", rates::F24).unwrap_err());
+        assert_eq!(
+            "01:00:00:24 at [24]: frames must be < 24 (did you mean '01:00:01:00'?)",
+            err.to_string(),
+        );
+
+        let err: Box<dyn std::error::Error> =
+            Box::new(Framerate::with_playback("24/1", Ntsc::NonDropFrame).unwrap_err());
+        assert_eq!(
+            "ntsc framerates must be n/1001 (did you mean '24000/1001'?)",
+            err.to_string(),
+        );
+    }
+
+    /// test parsing human duration strings
+    #[rstest]
+    #[case("1h 2m 3s 4f", rates::F24, "01:02:03:04")]
+    #[case("1h2m3s4f", rates::F24, "01:02:03:04")]
+    #[case("1 hour 2 minutes 3 seconds 4 frames", rates::F24, "01:02:03:04")]
+    #[case("1H 2M", rates::F24, "01:02:00:00")]
+    #[case("90s", rates::F24, "00:01:30:00")]
+    #[case("90s", rates::F23_98, "00:01:30:00")]
+    #[case("1500f", rates::F24, "00:01:02:12")]
+    #[case("1.5h", rates::F24, "01:30:00:00")]
+    #[case("2.5s", rates::F24, "00:00:02:12")]
+    #[case("-3s 12f", rates::F24, "-00:00:03:12")]
+    #[case("  30 sec  ", rates::F29_97_NDF, "00:00:30:00")]
+    #[case("2:30 min", rates::F24, "00:02:30:00")]
+    #[case("2:30.5 min", rates::F24, "00:02:30:12")]
+    #[case("1:30 h", rates::F24, "01:30:00:00")]
+    #[case("1:02:03 hrs", rates::F24, "01:02:03:00")]
+    #[case("-2:30 min", rates::F24, "-00:02:30:00")]
+    #[case("1m 2f", rates::F29_97_DF, "00:01:00;02")]
+    #[case("10m", rates::F29_97_DF, "00:10:00;00")]
+    #[case("1h", rates::F29_97_DF, "01:00:00;00")]
+    #[case("PT1H2M3.5S", rates::F24, "01:02:03:12")]
+    #[case("PT0S", rates::F24, "00:00:00:00")]
+    #[case("PT1,5S", rates::F24, "00:00:01:12")]
+    #[case("P1D", rates::F24, "24:00:00:00")]
+    #[case("P1DT1H", rates::F24, "25:00:00:00")]
+    #[case("-PT30S", rates::F24, "-00:00:30:00")]
+    #[case("PT1H", rates::F23_98, "00:59:56:10")]
+    fn test_parse_duration_str(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(DurationStr::new(input), rate)?;
+        assert_eq!(expected, tc.timecode(), "with_frames");
+
+        let tc = Timecode::with_seconds(DurationStr::new(input), rate)?;
+        assert_eq!(expected, tc.timecode(), "with_seconds");
+
+        Ok(())
+    }
+
+    /// test unknown duration strings are rejected
+    #[rstest]
+    #[case("")]
+    #[case("-")]
+    #[case("P")]
+    #[case("PT")]
+    #[case("P1Y")]
+    #[case("1:30")]
+    #[case("1:02:03 min")]
+    #[case("2s 1h")]
+    #[case("1.5f")]
+    #[case("3 parsecs")]
+    fn test_parse_duration_str_rejects(#[case] input: &str) {
+        let err = Timecode::with_frames(DurationStr::new(input), rates::F24)
+            .expect_err("expected parse error");
+        assert!(
+            matches!(err, TimecodeParseError::UnknownStrFormat(_)),
+            "{:?}",
+            err
+        );
+    }
+
+    /// test durations too large to count are rejected rather than overflowing
+    #[rstest]
+    #[case("100.000000000000000001s", rates::F24)]
+    #[case("PT100.000000000000000001S", rates::F24)]
+    #[case("P999999999999999D", rates::F24)]
+    #[case("9999999999999999h", rates::F24)]
+    #[case("9999999999999999:00 h", rates::F24)]
+    #[case("1s 9223372036854775807f", rates::F24)]
+    #[case("99999999999999999:00 min", rates::F24)]
+    fn test_parse_duration_str_overflow(#[case] input: &str, #[case] rate: Framerate) {
+        let err = Timecode::with_frames(DurationStr::new(input), rate)
+            .expect_err("expected with_frames error");
+        assert!(
+            matches!(err, TimecodeParseError::Conversion(_)),
+            "with_frames: {:?}",
+            err
+        );
+
+        let err = Timecode::with_seconds(DurationStr::new(input), rate)
+            .expect_err("expected with_seconds error");
+        assert!(
+            matches!(err, TimecodeParseError::Conversion(_)),
+            "with_seconds: {:?}",
+            err
+        );
+    }
+
+    /// test formatting ISO 8601 durations
+    #[rstest]
+    #[case("01:02:03:12", rates::F24, 3, "PT1H2M3.5S")]
+    #[case("00:00:00:00", rates::F24, 3, "PT0S")]
+    #[case("00:00:00:01", rates::F24, 3, "PT0.042S")]
+    #[case("00:00:00:01", rates::F24, 0, "PT0S")]
+    #[case("00:00:00:23", rates::F24, 1, "PT1S")]
+    #[case("00:59:59:23", rates::F24, 1, "PT1H")]
+    #[case("00:02:00:00", rates::F24, 3, "PT2M")]
+    #[case("-00:00:30:00", rates::F24, 3, "-PT30S")]
+    #[case("01:00:00:00", rates::F23_98, 3, "PT1H3.6S")]
+    #[case("00:59:56:10", rates::F23_98, 3, "PT1H0.013S")]
+    #[case("00:59:56:10", rates::F23_98, 1, "PT1H")]
+    #[case("25:00:00:00", rates::F24, 3, "PT25H")]
+    #[case("00:00:00:01", rates::F23_98, 18, "PT0.041708333333333333S")]
+    #[case("00:00:00:01", rates::F23_98, 19, "PT0.041708333333333333S")]
+    #[case("00:00:00:01", rates::F23_98, usize::MAX, "PT0.041708333333333333S")]
+    #[case("-00:00:00:12", rates::F24, 40, "-PT0.5S")]
+    fn test_iso8601_duration(
+        #[case] tc: &str,
+        #[case] rate: Framerate,
+        #[case] precision: usize,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc, rate)?;
+        assert_eq!(expected, tc.iso8601_duration(precision));
+
+        Ok(())
+    }
+
+    /// test that ISO 8601 durations of large values do not overflow
+    #[rstest]
+    #[case(rates::F24, 3)]
+    #[case(rates::F23_98, 18)]
+    #[case(rates::F23_98, usize::MAX)]
+    fn test_iso8601_duration_large(
+        #[case] rate: Framerate,
+        #[case] precision: usize,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(i64::MAX / rate.playback().numer(), rate)?;
+        let formatted = tc.iso8601_duration(precision);
+        assert!(formatted.starts_with("PT"), "{}", formatted);
+
+        let formatted = (-tc).iso8601_duration(precision);
+        assert!(formatted.starts_with("-PT"), "{}", formatted);
+
+        Ok(())
+    }
+
+    /// test formatting and re-parsing human durations
+    #[rstest]
+    #[case("01:02:03:04", rates::F24, "1h 2m 3s 4f")]
+    #[case("00:00:00:00", rates::F24, "0f")]
+    #[case("00:00:00:12", rates::F24, "12f")]
+    #[case("01:00:00:00", rates::F24, "1h")]
+    #[case("-00:01:30:00", rates::F24, "-1m 30s")]
+    #[case("00:01:00;02", rates::F29_97_DF, "1m 2f")]
+    #[case("00:00:00:119", Framerate::with_playback(120, Ntsc::None).unwrap(), "119f")]
+    fn test_human_duration(
+        #[case] tc: &str,
+        #[case] rate: Framerate,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc, rate)?;
+        assert_eq!(expected, tc.human_duration(), "formatted");
+
+        let parsed = Timecode::with_frames(DurationStr::new(expected), rate)?;
+        assert_eq!(tc, parsed, "round trip");
+
+        Ok(())
+    }
+
+    /// test detecting the representation of a string
+    #[rstest]
+    #[case("01:00:00:00", rates::F23_98, "01:00:00:00", Representation::Timecode)]
+    #[case(
+        "-01:00:00:00",
+        rates::F23_98,
+        "-01:00:00:00",
+        Representation::Timecode
+    )]
+    #[case(
+        "01:00:00;00",
+        rates::F29_97_DF,
+        "01:00:00;00",
+        Representation::DropFrameTimecode
+    )]
+    #[case(
+        "00:00:00:00",
+        rates::F29_97_DF,
+        "00:00:00;00",
+        Representation::DropFrameTimecode
+    )]
+    #[case("3;12", rates::F24, "00:00:03:12", Representation::Timecode)]
+    #[case("1:45", rates::F24, "00:01:45:00", Representation::Runtime)]
+    #[case("1:12:30", rates::F24, "01:12:30:00", Representation::Runtime)]
+    #[case("1:75", Framerate::with_playback(120, Ntsc::None).unwrap(), "00:00:01:75", Representation::Timecode)]
+    #[case("01:00:03.6", rates::F23_98, "01:00:00:00", Representation::Runtime)]
+    #[case("1.5", rates::F24, "00:00:01:12", Representation::Runtime)]
+    #[case(
+        "5400+00",
+        rates::F23_98,
+        "01:00:00:00",
+        Representation::FeetAndFrames(FilmFormat::FF35mm4perf)
+    )]
+    #[case(
+        "-1+15",
+        rates::F24,
+        "-00:00:01:07",
+        Representation::FeetAndFrames(FilmFormat::FF35mm4perf)
+    )]
+    #[case(
+        "1+20.1",
+        rates::F24,
+        "00:00:01:17",
+        Representation::FeetAndFrames(FilmFormat::FF35mm3perf)
+    )]
+    #[case(
+        "1+25",
+        rates::F24,
+        "00:00:02:09",
+        Representation::FeetAndFrames(FilmFormat::FF35mm2perf)
+    )]
+    #[case("86400", rates::F23_98, "01:00:00:00", Representation::Frames)]
+    #[case("0", rates::F23_98, "00:00:00:00", Representation::Frames)]
+    #[case(
+        "10594584000000000",
+        rates::F23_98,
+        "11:34:26:16",
+        Representation::PremiereTicks
+    )]
+    #[case(
+        "10594584001",
+        rates::F23_98,
+        "122622:30:00:01",
+        Representation::Frames
+    )]
+    #[case(
+        "18018/5",
+        rates::F23_98,
+        "01:00:00:00",
+        Representation::RationalSeconds
+    )]
+    #[case("-1/2", rates::F24, "-00:00:00:12", Representation::RationalSeconds)]
+    fn test_parse_any(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] expected_tc: &str,
+        #[case] expected_representation: Representation,
+    ) -> Result<(), TimecodeParseError> {
+        let (tc, representation) = Timecode::parse_any(input, rate)?;
+        assert_eq!(expected_tc, tc.timecode(), "timecode");
+        assert_eq!(expected_representation, representation, "representation");
+
+        Ok(())
+    }
+
+    /// test strings parse_any cannot decide on
+    #[rstest]
+    #[case("1:12", rates::F24, "Ambiguous")]
+    #[case("1:02:03", rates::F24, "Ambiguous")]
+    #[case("1+17", rates::F24, "Ambiguous")]
+    #[case("915372057600000", rates::F23_98, "Ambiguous")]
+    #[case("-10584000000", rates::F24, "Ambiguous")]
+    #[case("1:75", rates::F24, "SectionOutOfRange")]
+    #[case("1+32", rates::F24, "SectionOutOfRange")]
+    #[case("1.2.3", rates::F24, "UnknownStrFormat")]
+    #[case("1/0", rates::F24, "UnknownStrFormat")]
+    #[case("one hour", rates::F24, "UnknownStrFormat")]
+    fn test_parse_any_rejects(
+        #[case] input: &str,
+        #[case] rate: Framerate,
+        #[case] expected: &str,
+    ) {
+        let err = Timecode::parse_any(input, rate).expect_err("expected parse error");
+        let variant = match err {
+            TimecodeParseError::Ambiguous(_) => "Ambiguous",
+            TimecodeParseError::SectionOutOfRange(_) => "SectionOutOfRange",
+            TimecodeParseError::UnknownStrFormat(_) => "UnknownStrFormat",
+            _ => "other",
+        };
+        assert_eq!(expected, variant, "{:?}", err);
+    }
+
+    /// test feet and frames for gauges outside the main parse table.
+    #[rstest]
+    #[case(FilmFormat::FF65mm5perf, 13, "1+01.1")]
+    #[case(FilmFormat::FF65mm5perf, 64, "5+00.0")]
+    #[case(FilmFormat::FF65mm5perf, -13, "-1+01.1")]
+    #[case(FilmFormat::FF65mm8perf, 100, "12+04")]
+    #[case(FilmFormat::FF70mmImax15perf, 24, "5+03.5")]
+    #[case(FilmFormat::FF35mm8perf, 100, "12+04")]
+    #[case(FilmFormat::FFSuper8, 100, "1+28")]
+    #[case(FilmFormat::FF9_5mm, 100, "2+20")]
+    #[case(
+        FilmFormat::Custom(FilmFormatSpec::new(6, 64).unwrap().with_perf_field(false)),
+        24,
+        "2+03"
+    )]
+    fn test_feet_and_frames_gauges(
+        #[case] format: FilmFormat,
+        #[case] frames: i64,
+        #[case] expected: &str,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(frames, rates::F24)?;
+        assert_eq!(expected, tc.feet_and_frames(format), "feet and frames");
+
+        let parsed = Timecode::with_frames(FeetFramesStr::new(expected, format), rates::F24)?;
+        assert_eq!(frames, parsed.frames(), "parsed");
+
+        Ok(())
+    }
+
+    /// test every frame survives a round trip through feet and frames, for every gauge.
+    #[rstest]
+    #[case(FilmFormat::FF35mm4perf)]
+    #[case(FilmFormat::FF35mm3perf)]
+    #[case(FilmFormat::FF35mm2perf)]
+    #[case(FilmFormat::FF16mm)]
+    #[case(FilmFormat::FF65mm5perf)]
+    #[case(FilmFormat::FF65mm8perf)]
+    #[case(FilmFormat::FF70mmImax15perf)]
+    #[case(FilmFormat::FF35mm8perf)]
+    #[case(FilmFormat::FFSuper8)]
+    #[case(FilmFormat::FF9_5mm)]
+    #[case(FilmFormat::Custom(FilmFormatSpec::new(7, 50).unwrap()))]
+    fn test_feet_and_frames_round_trip(
+        #[case] format: FilmFormat,
+    ) -> Result<(), TimecodeParseError> {
+        for frames in -500..500 {
+            let footage = Timecode::with_frames(frames, rates::F24)?.feet_and_frames(format);
+            let parsed =
+                Timecode::with_frames(FeetFramesStr::new(footage.as_str(), format), rates::F24)?;
+            assert_eq!(frames, parsed.frames(), "{}", footage);
+        }
+
+        Ok(())
+    }
+
+    /// test feet and frames with perf fields or feet which cannot be counted.
+    #[rstest]
+    #[case("12+01.3", FilmFormat::FF35mm3perf, "SectionOutOfRange")]
+    #[case("12+01.9", FilmFormat::FF35mm3perf, "SectionOutOfRange")]
+    #[case("12+01.99999999999999999999", FilmFormat::FF35mm3perf, "Conversion")]
+    #[case("9223372036854775807+00", FilmFormat::FF35mm4perf, "Conversion")]
+    #[case("144115188075855871+15", FilmFormat::FF35mm4perf, "Conversion")]
+    fn test_feet_and_frames_rejects(
+        #[case] input: &str,
+        #[case] format: FilmFormat,
+        #[case] expected: &str,
+    ) {
+        let err = Timecode::with_frames(FeetFramesStr::new(input, format), rates::F24)
+            .expect_err("expected parse error");
+        let variant = match err {
+            TimecodeParseError::SectionOutOfRange(_) => "SectionOutOfRange",
+            TimecodeParseError::Conversion(_) => "Conversion",
+            _ => "other",
+        };
+        assert_eq!(expected, variant, "{:?}", err);
+    }
+
+    #[rstest]
+    #[case(0, 64)]
+    #[case(4, 0)]
+    #[case(-1, 64)]
+    fn test_film_format_spec_rejects(#[case] perfs_per_frame: i64, #[case] perfs_per_foot: i64) {
+        assert_eq!(None, FilmFormatSpec::new(perfs_per_frame, perfs_per_foot));
+    }
+
+    #[test]
+    fn test_film_format_spec_builtin() {
+        let spec = FilmFormatSpec::new(4, 64).unwrap();
+        assert_eq!(FilmFormat::FF35mm4perf.spec(), spec);
+    }
 }
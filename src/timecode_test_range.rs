@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::range;
+    use crate::{rates, RoundingMode, SpeedChange, Timecode, TimecodeError, TimecodeRange};
+    use rstest::rstest;
+
+    #[test]
+    fn test_range_basics() {
+        let range = range(24, 72);
+
+        assert_eq!(48, range.duration().frames());
+        assert_eq!(rates::F24, range.rate());
+        assert!(!range.is_empty());
+        assert!(range.contains(&Timecode::with_frames(24, rates::F24).unwrap()));
+        assert!(range.contains(&Timecode::with_frames(71, rates::F24).unwrap()));
+        assert!(!range.contains(&Timecode::with_frames(72, rates::F24).unwrap()));
+        assert!(!range.contains(&Timecode::with_frames(23, rates::F24).unwrap()));
+    }
+
+    #[test]
+    fn test_range_empty() {
+        let range = range(24, 24);
+        assert!(range.is_empty());
+        assert!(!range.contains(&range.start()));
+    }
+
+    #[rstest]
+    #[case::inside(range(0, 48), range(12, 24), Some(range(12, 24)))]
+    #[case::start(range(0, 48), range(-12, 12), Some(range(0, 12)))]
+    #[case::end(range(0, 48), range(36, 60), Some(range(36, 48)))]
+    #[case::touching(range(0, 48), range(48, 60), None)]
+    #[case::apart(range(0, 48), range(60, 72), None)]
+    #[case::empty(range(0, 48), range(12, 12), None)]
+    fn test_range_intersection(
+        #[case] a: TimecodeRange,
+        #[case] b: TimecodeRange,
+        #[case] expected: Option<TimecodeRange>,
+    ) {
+        assert_eq!(expected.is_some(), a.overlaps(&b), "a overlaps b");
+        assert_eq!(expected.is_some(), b.overlaps(&a), "b overlaps a");
+        assert_eq!(expected, a.intersection(&b), "a intersection b");
+        assert_eq!(expected, b.intersection(&a), "b intersection a");
+    }
+
+    #[test]
+    fn test_range_with_duration() {
+        let start = Timecode::with_frames("01:00:00;00", rates::F29_97_DF).unwrap();
+        let duration = Timecode::with_frames("00:01:00;02", rates::F29_97_DF).unwrap();
+        let range = TimecodeRange::with_duration(start, duration).unwrap();
+
+        assert_eq!("01:01:00;02", range.end().timecode());
+    }
+
+    #[test]
+    fn test_range_end_before_start() {
+        let err = TimecodeRange::new(
+            Timecode::with_frames(24, rates::F24).unwrap(),
+            Timecode::with_frames(23, rates::F24).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::EndBeforeStart {
+                start: Timecode::with_frames(24, rates::F24).unwrap(),
+                end: Timecode::with_frames(23, rates::F24).unwrap(),
+            },
+            err
+        );
+
+        let err = TimecodeRange::with_duration(
+            Timecode::with_frames(24, rates::F24).unwrap(),
+            Timecode::with_frames(-1, rates::F24).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::EndBeforeStart {
+                start: Timecode::with_frames(24, rates::F24).unwrap(),
+                end: Timecode::with_frames(23, rates::F24).unwrap(),
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_range_mixed_rates() {
+        let err = TimecodeRange::new(
+            Timecode::with_frames(24, rates::F24).unwrap(),
+            Timecode::with_frames(48, rates::F48).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F48,
+                expected: rates::F24,
+            },
+            err
+        );
+
+        let err = TimecodeRange::with_duration(
+            Timecode::with_frames(24, rates::F24).unwrap(),
+            Timecode::with_frames(48, rates::F48).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F48,
+                expected: rates::F24,
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_range_with_frames_errors() {
+        let err = TimecodeRange::with_frames("01:00:00:00", "bad", rates::F24).unwrap_err();
+        assert!(matches!(err, TimecodeError::Parse(_)), "{:?}", err);
+
+        let err = TimecodeRange::with_frames(48, 24, rates::F24).unwrap_err();
+        assert_eq!(
+            TimecodeError::EndBeforeStart {
+                start: Timecode::with_frames(48, rates::F24).unwrap(),
+                end: Timecode::with_frames(24, rates::F24).unwrap(),
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_range_change_speed() {
+        let start = Timecode::with_frames("01:00:00:00", rates::F23_98).unwrap();
+        let end = Timecode::with_frames("01:20:00:00", rates::F23_98).unwrap();
+        let reel = TimecodeRange::new(start, end).unwrap();
+
+        let pull_up = SpeedChange::new(rates::F23_98, rates::F24);
+        let changed = reel.change_speed(pull_up, RoundingMode::HalfUp);
+
+        assert_eq!(start, changed.start());
+        assert_eq!("01:19:58:19", changed.end().timecode());
+    }
+}
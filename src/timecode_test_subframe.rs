@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        rates, Framerate, RoundingMode, SubframeStr, SubframeTimecode, Timecode, TimecodeParseError,
+    };
+    use num::Rational64;
+    use rstest::rstest;
+
+    struct SubframeCase {
+        input: &'static str,
+        divisions: i64,
+        rate: Framerate,
+        seconds: Rational64,
+        frame: &'static str,
+        subframes: i64,
+    }
+
+    /// tests parsing and formatting subframe timecode strings.
+    #[rstest]
+    #[case(SubframeCase{
+        input: "01:00:00:00.42",
+        divisions: 100,
+        rate: rates::F24,
+        seconds: Rational64::new(8640042, 2400),
+        frame: "01:00:00:00",
+        subframes: 42,
+    })]
+    #[case(SubframeCase{
+        input: "01:00:00:00.79",
+        divisions: 80,
+        rate: rates::F25,
+        seconds: Rational64::new(7200079, 2000),
+        frame: "01:00:00:00",
+        subframes: 79,
+    })]
+    #[case(SubframeCase{
+        input: "00:00:00:00.00",
+        divisions: 100,
+        rate: rates::F23_98,
+        seconds: Rational64::new(0, 1),
+        frame: "00:00:00:00",
+        subframes: 0,
+    })]
+    #[case(SubframeCase{
+        input: "-00:00:00:00.50",
+        divisions: 100,
+        rate: rates::F24,
+        seconds: Rational64::new(-1, 48),
+        frame: "00:00:00:00",
+        subframes: 50,
+    })]
+    #[case(SubframeCase{
+        input: "-00:00:01:02.25",
+        divisions: 100,
+        rate: rates::F24,
+        seconds: Rational64::new(-105, 96),
+        frame: "-00:00:01:02",
+        subframes: 25,
+    })]
+    #[case(SubframeCase{
+        input: "00:01:00;02.50",
+        divisions: 100,
+        rate: rates::F29_97_DF,
+        seconds: Rational64::new(18005, 10) * Rational64::new(1001, 30000),
+        frame: "00:01:00;02",
+        subframes: 50,
+    })]
+    fn test_parse_subframes(#[case] case: SubframeCase) -> Result<(), TimecodeParseError> {
+        let tc = SubframeTimecode::with_seconds(
+            SubframeStr::new(case.input, case.divisions),
+            case.rate,
+        )?;
+
+        assert_eq!(case.seconds, tc.seconds(), "seconds of {}", case.input);
+        assert_eq!(case.frame, tc.frame().timecode(), "frame of {}", case.input);
+        assert_eq!(
+            case.subframes,
+            tc.subframes(case.divisions),
+            "subframes of {}",
+            case.input
+        );
+        assert_eq!(
+            case.input,
+            tc.timecode(case.divisions),
+            "timecode round trip"
+        );
+        Ok(())
+    }
+
+    /// tests that malformed subframe strings are rejected.
+    #[rstest]
+    #[case("01:00:00:00.100", 100)]
+    #[case("01:00:00:00.80", 80)]
+    #[case("01:00:00:00.", 100)]
+    #[case("01:00:00:00.+1", 100)]
+    #[case("01:00:00:00.-5", 100)]
+    #[case("01:00:00:00.-0", 100)]
+    #[case("01:00:00:00.1.1", 100)]
+    #[case("5400+00.1", 100)]
+    #[case("01:00:00:00.01", 0)]
+    fn test_parse_subframes_malformed(#[case] input: &str, #[case] divisions: i64) {
+        let result = SubframeTimecode::with_seconds(SubframeStr::new(input, divisions), rates::F24);
+        assert!(result.is_err(), "expected error for {}", input);
+    }
+
+    /// tests that converting from a Timecode is lossless and snapping back matches.
+    #[rstest]
+    #[case("01:00:00:00", rates::F23_98)]
+    #[case("-00:10:00;00", rates::F29_97_DF)]
+    #[case("17:23:13:02", rates::F24)]
+    fn test_from_timecode(
+        #[case] tc_str: &str,
+        #[case] rate: Framerate,
+    ) -> Result<(), TimecodeParseError> {
+        let tc = Timecode::with_frames(tc_str, rate)?;
+        let subframe = SubframeTimecode::from(tc);
+
+        assert_eq!(tc.seconds(), subframe.seconds());
+        assert_eq!(0, subframe.subframes(100));
+        assert_eq!(tc, subframe.frame());
+        assert_eq!(tc, subframe.to_timecode(RoundingMode::Floor));
+        assert_eq!(tc.sections(), subframe.sections());
+        Ok(())
+    }
+
+    /// tests arithmetic keeps sub-frame precision.
+    #[test]
+    fn test_arithmetic() -> Result<(), TimecodeParseError> {
+        let quarter =
+            SubframeTimecode::with_seconds(SubframeStr::new("00:00:00:00.25", 100), rates::F24)?;
+        let half = quarter + quarter;
+        assert_eq!("00:00:00:00.50", half.timecode(100));
+
+        let whole = half + half;
+        assert_eq!("00:00:00:01.00", whole.timecode(100));
+        assert!(whole > half);
+
+        let negative = quarter - half;
+        assert_eq!("-00:00:00:00.25", negative.timecode(100));
+        assert_eq!(quarter, -negative);
+        Ok(())
+    }
+}
@@ -0,0 +1,455 @@
+use num::Rational64;
+
+use crate::{Framerate, Timecode, TimecodeError, TimecodeRange};
+
+/**
+A clip of source material cut into a timeline, like a single event of an EDL.
+
+Record frames map to source frames by frame count, scaled by the clip's speed, so a source at a
+different rate than the record side is mapped frame for frame, as EDLs do.
+
+# Examples
+
+```rust
+use vtc::{Clip, Timecode, TimecodeRange, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+
+let clip = Clip::new(
+    "A001",
+    range("14:21:00:00", "14:21:02:00"),
+    range("01:00:10:00", "01:00:12:00"),
+);
+
+let record = Timecode::with_frames("01:00:11:00", rates::F24).unwrap();
+let source = clip.source_at(&record).unwrap();
+assert_eq!("14:21:01:00", source.timecode());
+assert_eq!(Some(record), clip.record_at(&source));
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Clip {
+    reel: String,
+    source: TimecodeRange,
+    record: TimecodeRange,
+    speed: Rational64,
+}
+
+impl Clip {
+    /// Creates a new [Clip] of `source` on `reel`, cut into the timeline at `record`.
+    ///
+    /// The speed is the ratio of source frames to record frames, or 1 if `record` is empty.
+    pub fn new(reel: &str, source: TimecodeRange, record: TimecodeRange) -> Self {
+        let record_frames = record.duration().frames();
+        let speed = if record_frames == 0 {
+            Rational64::from_integer(1)
+        } else {
+            Rational64::new(source.duration().frames(), record_frames)
+        };
+
+        Clip {
+            reel: reel.to_string(),
+            source,
+            record,
+            speed,
+        }
+    }
+
+    /// Returns the clip with a speed of `speed` source frames per record frame, like the
+    /// speed of an EDL motion effect. `0` is a freeze frame of the source in, and negative
+    /// speeds play backwards from the source in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num::Rational64;
+    /// use vtc::{Clip, Timecode, TimecodeRange, rates};
+    ///
+    /// let source = Timecode::with_frames("14:21:00:00", rates::F24).unwrap();
+    /// let source = TimecodeRange::new(source, source).unwrap();
+    /// let record = TimecodeRange::with_frames("01:00:10:00", "01:00:12:00", rates::F24).unwrap();
+    ///
+    /// let clip = Clip::new("A001", source, record).with_speed(Rational64::new(1, 2));
+    /// let record = Timecode::with_frames("01:00:11:00", rates::F24).unwrap();
+    /// assert_eq!("14:21:00:12", clip.source_at(&record).unwrap().timecode());
+    /// ```
+    pub fn with_speed(mut self, speed: Rational64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Returns the source reel of the clip.
+    pub fn reel(&self) -> &str {
+        &self.reel
+    }
+
+    /// Returns the source in and out of the clip.
+    pub fn source(&self) -> TimecodeRange {
+        self.source
+    }
+
+    /// Returns the record in and out of the clip.
+    pub fn record(&self) -> TimecodeRange {
+        self.record
+    }
+
+    /// Returns the number of source frames played per record frame.
+    pub fn speed(&self) -> Rational64 {
+        self.speed
+    }
+
+    /// Returns the source frame under record timecode `tc`, or [None] if the clip is not on
+    /// the timeline at `tc`, or `tc` is not at the record rate.
+    pub fn source_at(&self, tc: &Timecode) -> Option<Timecode> {
+        if tc.rate() != self.record.rate() || !self.record.contains(tc) {
+            return None;
+        }
+
+        let offset = tc.frames() - self.record.start().frames();
+        let source_offset = (Rational64::from_integer(offset) * self.speed)
+            .floor()
+            .to_integer();
+
+        Some(Timecode::with_i64_frames(
+            self.source.start().frames() + source_offset,
+            self.source.rate(),
+        ))
+    }
+
+    /// Returns the first record timecode showing source timecode `tc`, or [None] if the clip
+    /// never shows it, or `tc` is not at the source rate.
+    pub fn record_at(&self, tc: &Timecode) -> Option<Timecode> {
+        if tc.rate() != self.source.rate() {
+            return None;
+        }
+
+        let source_offset = tc.frames() - self.source.start().frames();
+
+        let offset = if *self.speed.numer() == 0 {
+            0
+        } else {
+            (Rational64::from_integer(source_offset) / self.speed)
+                .ceil()
+                .to_integer()
+        };
+
+        let record =
+            Timecode::with_i64_frames(self.record.start().frames() + offset, self.record.rate());
+
+        match self.source_at(&record) {
+            Some(shown) if shown == *tc => Some(record),
+            _ => None,
+        }
+    }
+}
+
+/// A single track of a [Timeline], holding clips in record order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Track {
+    name: String,
+    rate: Framerate,
+    clips: Vec<Clip>,
+}
+
+impl Track {
+    /// Returns the name of the track, like `V1`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the clips of the track, sorted by record in and then record out.
+    pub fn clips(&self) -> &[Clip] {
+        &self.clips
+    }
+
+    /// Returns the first clip on the track at record timecode `tc`, and the source frame it
+    /// shows there.
+    pub fn source_at(&self, tc: &Timecode) -> Option<(&Clip, Timecode)> {
+        self.clips
+            .iter()
+            .find_map(|clip| clip.source_at(tc).map(|source| (clip, source)))
+    }
+
+    /// Returns the record range from the first record in to the last record out of the track,
+    /// or [None] if the track has no clips.
+    pub fn extent(&self) -> Option<TimecodeRange> {
+        extent(self.clips.iter().map(|clip| clip.record))
+    }
+
+    /// Returns the total record duration of the clips on the track. Overlapping frames are
+    /// counted once per clip.
+    pub fn duration(&self) -> Timecode {
+        self.clips
+            .iter()
+            .fold(Timecode::with_i64_frames(0, self.rate), |total, clip| {
+                total + clip.record.duration()
+            })
+    }
+
+    /// Returns the record ranges between clips where the track is empty, in order.
+    pub fn gaps(&self) -> Vec<TimecodeRange> {
+        gaps(&coverage(self.clips.iter().map(|clip| clip.record)))
+    }
+
+    /// Returns the record ranges where two clips on the track overlap, in order. A range
+    /// covered by three clips is returned once for each pair.
+    pub fn overlaps(&self) -> Vec<TimecodeRange> {
+        let mut overlaps = Vec::new();
+        for (i, clip) in self.clips.iter().enumerate() {
+            for other in &self.clips[i + 1..] {
+                if other.record.start() >= clip.record.end() {
+                    break;
+                }
+                if let Some(shared) = clip.record.intersection(&other.record) {
+                    overlaps.push(shared);
+                }
+            }
+        }
+
+        overlaps.sort_by_key(|range| (range.start(), range.end()));
+        overlaps
+    }
+}
+
+/**
+A lightweight editorial timeline of tracks and clips, independent of any EDL or XML format, for
+asking which source frame is under a record timecode and the reverse.
+
+Tracks are stacked in the order they are first added, so the last track is on top.
+
+# Examples
+
+```rust
+use vtc::{Clip, Timecode, TimecodeRange, Timeline, rates};
+
+let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+
+let mut timeline = Timeline::new(rates::F24);
+timeline
+    .add_clip(
+        "V1",
+        Clip::new("A001", range("14:21:00:00", "14:21:10:00"), range("01:00:00:00", "01:00:10:00")),
+    )
+    .unwrap();
+timeline
+    .add_clip(
+        "V2",
+        Clip::new("B002", range("09:00:00:00", "09:00:02:00"), range("01:00:04:00", "01:00:06:00")),
+    )
+    .unwrap();
+
+let record = Timecode::with_frames("01:00:05:00", rates::F24).unwrap();
+let (track, clip, source) = timeline.source_at(&record).unwrap();
+assert_eq!("V2", track.name());
+assert_eq!("B002", clip.reel());
+assert_eq!("09:00:01:00", source.timecode());
+
+let source = Timecode::with_frames("14:21:02:00", rates::F24).unwrap();
+let uses = timeline.record_at("A001", &source);
+assert_eq!("01:00:02:00", uses[0].2.timecode());
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timeline {
+    rate: Framerate,
+    tracks: Vec<Track>,
+}
+
+impl Timeline {
+    /// Creates a new, empty [Timeline] with a record rate of `rate`.
+    pub fn new(rate: Framerate) -> Self {
+        Timeline {
+            rate,
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Returns the record rate of the timeline.
+    pub fn rate(&self) -> Framerate {
+        self.rate
+    }
+
+    /// Returns the tracks of the timeline, from the bottom to the top.
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Returns the track named `name`, or [None] if there is no such track.
+    pub fn track(&self, name: &str) -> Option<&Track> {
+        self.tracks.iter().find(|track| track.name == name)
+    }
+
+    /// Adds `clip` to the track named `track`, adding the track on top if it does not exist
+    /// yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [TimecodeError::RateMismatch] if the record range of `clip` is not at the
+    /// timeline rate.
+    pub fn add_clip(&mut self, track: &str, clip: Clip) -> Result<(), TimecodeError> {
+        TimecodeError::check_rate(clip.record.rate(), self.rate)?;
+
+        let index = match self
+            .tracks
+            .iter()
+            .position(|existing| existing.name == track)
+        {
+            Some(index) => index,
+            None => {
+                self.tracks.push(Track {
+                    name: track.to_string(),
+                    rate: self.rate,
+                    clips: Vec::new(),
+                });
+                self.tracks.len() - 1
+            }
+        };
+
+        let clips = &mut self.tracks[index].clips;
+        let key = (clip.record.start(), clip.record.end());
+        let position = clips
+            .partition_point(|existing| (existing.record.start(), existing.record.end()) <= key);
+        clips.insert(position, clip);
+
+        Ok(())
+    }
+
+    /// Returns the top-most clip at record timecode `tc`, the track it is on, and the source
+    /// frame it shows there. `tc` must be at the timeline rate.
+    pub fn source_at(&self, tc: &Timecode) -> Option<(&Track, &Clip, Timecode)> {
+        self.tracks.iter().rev().find_map(|track| {
+            track
+                .source_at(tc)
+                .map(|(clip, source)| (track, clip, source))
+        })
+    }
+
+    /// Returns every use of source timecode `tc` from `reel`: the track, the clip and the
+    /// first record timecode showing it, sorted by track from the bottom up and then by
+    /// record timecode. `tc` must be at the source rate of the clips.
+    pub fn record_at(&self, reel: &str, tc: &Timecode) -> Vec<(&Track, &Clip, Timecode)> {
+        self.tracks
+            .iter()
+            .flat_map(|track| {
+                track
+                    .clips
+                    .iter()
+                    .filter(move |clip| clip.reel == reel)
+                    .filter_map(move |clip| clip.record_at(tc).map(|record| (track, clip, record)))
+            })
+            .collect()
+    }
+
+    /// Returns the record ranges between clips where no track has a clip, in order.
+    pub fn gaps(&self) -> Vec<TimecodeRange> {
+        gaps(&coverage(self.record_ranges()))
+    }
+
+    /// Returns the clip count, extent and total durations of the timeline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vtc::{Clip, TimecodeRange, Timeline, rates};
+    ///
+    /// let range = |start, end| TimecodeRange::with_frames(start, end, rates::F24).unwrap();
+    ///
+    /// let mut timeline = Timeline::new(rates::F24);
+    /// let source = range("14:21:00:00", "14:21:10:00");
+    /// for record in &[
+    ///     range("01:00:00:00", "01:00:10:00"),
+    ///     range("01:00:08:00", "01:00:18:00"),
+    ///     range("01:00:20:00", "01:00:30:00"),
+    /// ] {
+    ///     timeline.add_clip("V1", Clip::new("A001", source, *record)).unwrap();
+    /// }
+    ///
+    /// let summary = timeline.summary();
+    /// assert_eq!(3, summary.clips);
+    /// assert_eq!("01:00:00:00 - 01:00:30:00", summary.extent.unwrap().to_string());
+    /// assert_eq!("00:00:28:00", summary.picture.timecode());
+    /// assert_eq!("00:00:02:00", summary.gaps.timecode());
+    /// assert_eq!("00:00:02:00", summary.overlaps.timecode());
+    /// ```
+    pub fn summary(&self) -> TimelineSummary {
+        let zero = Timecode::with_i64_frames(0, self.rate);
+        let total = |ranges: Vec<TimecodeRange>| {
+            ranges
+                .iter()
+                .fold(zero, |total, range| total + range.duration())
+        };
+
+        let covered = coverage(self.record_ranges());
+        TimelineSummary {
+            clips: self.tracks.iter().map(|track| track.clips.len()).sum(),
+            extent: extent(self.record_ranges()),
+            picture: total(covered.clone()),
+            gaps: total(gaps(&covered)),
+            overlaps: total(self.tracks.iter().flat_map(Track::overlaps).collect()),
+        }
+    }
+
+    /// Returns the record ranges of every clip on every track.
+    fn record_ranges(&self) -> impl Iterator<Item = TimecodeRange> + '_ {
+        self.tracks
+            .iter()
+            .flat_map(|track| track.clips.iter().map(|clip| clip.record))
+    }
+}
+
+/// The totals of a [Timeline], returned by [Timeline::summary].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimelineSummary {
+    /// The number of clips on all tracks.
+    pub clips: usize,
+    /// The record range from the first record in to the last record out, or [None] if the
+    /// timeline has no clips.
+    pub extent: Option<TimecodeRange>,
+    /// The duration of the record ranges where any track has a clip.
+    pub picture: Timecode,
+    /// The duration of the gaps where no track has a clip.
+    pub gaps: Timecode,
+    /// The duration of the overlaps between clips on the same track, summed over all tracks.
+    pub overlaps: Timecode,
+}
+
+/// Returns the range from the earliest start to the latest end of `ranges`.
+fn extent<I: Iterator<Item = TimecodeRange>>(ranges: I) -> Option<TimecodeRange> {
+    ranges.reduce(|extent, range| {
+        // Every range is at the timeline rate, and the end can never be before the start.
+        TimecodeRange::new(
+            extent.start().min(range.start()),
+            extent.end().max(range.end()),
+        )
+        .unwrap()
+    })
+}
+
+/// Merges `ranges` into sorted runs of frames covered by at least one range. Empty ranges
+/// cover nothing.
+fn coverage<I: Iterator<Item = TimecodeRange>>(ranges: I) -> Vec<TimecodeRange> {
+    let mut ranges: Vec<TimecodeRange> = ranges.filter(|range| !range.is_empty()).collect();
+    ranges.sort_by_key(|range| (range.start(), range.end()));
+
+    let mut covered: Vec<TimecodeRange> = Vec::new();
+    for range in ranges {
+        match covered.last_mut() {
+            Some(last) if range.start() <= last.end() => {
+                // Every range is at the timeline rate, and the end can never be before the
+                // start.
+                *last = TimecodeRange::new(last.start(), last.end().max(range.end())).unwrap();
+            }
+            _ => covered.push(range),
+        }
+    }
+
+    covered
+}
+
+/// Returns the ranges between sorted, non-overlapping `covered` ranges.
+fn gaps(covered: &[TimecodeRange]) -> Vec<TimecodeRange> {
+    covered
+        .windows(2)
+        .map(|pair| TimecodeRange::new(pair[0].end(), pair[1].start()).unwrap())
+        .collect()
+}
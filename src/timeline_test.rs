@@ -0,0 +1,341 @@
+#[cfg(test)]
+mod test {
+    use crate::test_support::{range, range_at, tc};
+    use crate::{rates, Clip, Timecode, TimecodeError, TimecodeRange, Timeline};
+    use num::Rational64;
+    use rstest::rstest;
+
+    fn ranges(ranges: Vec<TimecodeRange>) -> Vec<String> {
+        ranges.iter().map(|range| range.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case::first("01:00:10:00", Some("14:21:00:00"))]
+    #[case::middle("01:00:11:06", Some("14:21:01:06"))]
+    #[case::last("01:00:11:23", Some("14:21:01:23"))]
+    #[case::out("01:00:12:00", None)]
+    #[case::before("01:00:09:23", None)]
+    fn test_clip_source_at(#[case] record: &str, #[case] expected: Option<&str>) {
+        let clip = Clip::new(
+            "A001",
+            range("14:21:00:00", "14:21:02:00"),
+            range("01:00:10:00", "01:00:12:00"),
+        );
+
+        let source = clip.source_at(&tc(record));
+        assert_eq!(expected, source.map(|source| source.timecode()).as_deref());
+
+        if let Some(source) = source {
+            assert_eq!(Some(tc(record)), clip.record_at(&source), "round trip");
+        }
+    }
+
+    #[rstest]
+    #[case::double(Rational64::from_integer(2), "01:00:11:00", Some("14:21:02:00"))]
+    #[case::half(Rational64::new(1, 2), "01:00:11:01", Some("14:21:00:12"))]
+    #[case::freeze(Rational64::from_integer(0), "01:00:11:12", Some("14:21:00:00"))]
+    #[case::reverse(Rational64::from_integer(-1), "01:00:10:12", Some("14:20:59:12"))]
+    fn test_clip_speed(
+        #[case] speed: Rational64,
+        #[case] record: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let clip = Clip::new(
+            "A001",
+            range("14:21:00:00", "14:21:02:00"),
+            range("01:00:10:00", "01:00:12:00"),
+        )
+        .with_speed(speed);
+        assert_eq!(speed, clip.speed());
+
+        let source = clip.source_at(&tc(record));
+        assert_eq!(expected, source.map(|source| source.timecode()).as_deref());
+    }
+
+    #[rstest]
+    #[case::double(Rational64::from_integer(2), "14:21:02:00", Some("01:00:11:00"))]
+    #[case::double_skipped(Rational64::from_integer(2), "14:21:02:01", None)]
+    #[case::half(Rational64::new(1, 2), "14:21:00:12", Some("01:00:11:00"))]
+    #[case::freeze(Rational64::from_integer(0), "14:21:00:00", Some("01:00:10:00"))]
+    #[case::freeze_other(Rational64::from_integer(0), "14:21:00:01", None)]
+    #[case::reverse(Rational64::from_integer(-1), "14:20:59:12", Some("01:00:10:12"))]
+    #[case::beyond(Rational64::from_integer(1), "14:21:05:00", None)]
+    fn test_clip_record_at(
+        #[case] speed: Rational64,
+        #[case] source: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let clip = Clip::new(
+            "A001",
+            range("14:21:00:00", "14:21:02:00"),
+            range("01:00:10:00", "01:00:12:00"),
+        )
+        .with_speed(speed);
+
+        let record = clip.record_at(&tc(source));
+        assert_eq!(expected, record.map(|record| record.timecode()).as_deref());
+    }
+
+    #[test]
+    fn test_clip_default_speed() {
+        let clip = Clip::new(
+            "A001",
+            range("14:21:00:00", "14:21:04:00"),
+            range("01:00:10:00", "01:00:12:00"),
+        );
+        assert_eq!(Rational64::from_integer(2), clip.speed());
+
+        let source = tc("14:21:00:00");
+        let clip = Clip::new(
+            "A001",
+            TimecodeRange::new(source, source).unwrap(),
+            range("01:00:10:00", "01:00:10:00"),
+        );
+        assert_eq!(Rational64::from_integer(1), clip.speed());
+    }
+
+    #[test]
+    fn test_clip_mixed_rates() {
+        let source = range_at("14:21:00:00", "14:21:02:00", rates::F25);
+        let clip = Clip::new("A001", source, range("01:00:10:00", "01:00:12:02"));
+
+        let source = clip.source_at(&tc("01:00:11:00")).unwrap();
+        assert_eq!(rates::F25, source.rate());
+        assert_eq!("14:21:00:24", source.timecode());
+
+        // Timecode at the wrong rate is not mapped by frame count.
+        let record = Timecode::with_frames("01:00:11:00", rates::F23_98).unwrap();
+        assert_eq!(None, clip.source_at(&record));
+        let source = Timecode::with_frames("14:21:00:24", rates::F24).unwrap();
+        assert_eq!(None, clip.record_at(&source));
+        assert_eq!(
+            Some(tc("01:00:11:00")),
+            clip.record_at(&Timecode::with_frames("14:21:00:24", rates::F25).unwrap())
+        );
+    }
+
+    fn timeline() -> Timeline {
+        let mut timeline = Timeline::new(rates::F24);
+        let clips = vec![
+            (
+                "V1",
+                Clip::new(
+                    "A001",
+                    range("14:21:00:00", "14:21:10:00"),
+                    range("01:00:00:00", "01:00:10:00"),
+                ),
+            ),
+            (
+                "V1",
+                Clip::new(
+                    "A001",
+                    range("14:21:00:00", "14:21:05:00"),
+                    range("01:00:20:00", "01:00:25:00"),
+                ),
+            ),
+            (
+                "V1",
+                Clip::new(
+                    "A002",
+                    range("10:00:00:00", "10:00:12:00"),
+                    range("01:00:08:00", "01:00:20:00"),
+                ),
+            ),
+            (
+                "V2",
+                Clip::new(
+                    "B001",
+                    range("09:00:00:00", "09:00:02:00"),
+                    range("01:00:04:00", "01:00:06:00"),
+                ),
+            ),
+            (
+                "V2",
+                Clip::new(
+                    "B001",
+                    range("09:00:00:00", "09:00:05:00"),
+                    range("01:00:30:00", "01:00:35:00"),
+                ),
+            ),
+        ];
+
+        for (track, clip) in clips {
+            timeline.add_clip(track, clip).unwrap();
+        }
+        timeline
+    }
+
+    #[test]
+    fn test_timeline_tracks() {
+        let timeline = timeline();
+
+        let names: Vec<&str> = timeline.tracks().iter().map(|track| track.name()).collect();
+        assert_eq!(vec!["V1", "V2"], names, "track order");
+
+        let v1 = timeline.track("V1").unwrap();
+        let records: Vec<String> = v1
+            .clips()
+            .iter()
+            .map(|clip| clip.record().to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "01:00:00:00 - 01:00:10:00",
+                "01:00:08:00 - 01:00:20:00",
+                "01:00:20:00 - 01:00:25:00",
+            ],
+            records,
+            "clip order"
+        );
+
+        assert!(timeline.track("V3").is_none());
+    }
+
+    #[rstest]
+    #[case::bottom("01:00:02:00", Some(("V1", "A001", "14:21:02:00")))]
+    #[case::top("01:00:05:00", Some(("V2", "B001", "09:00:01:00")))]
+    #[case::overlap("01:00:09:00", Some(("V1", "A001", "14:21:09:00")))]
+    #[case::after_overlap("01:00:10:00", Some(("V1", "A002", "10:00:02:00")))]
+    #[case::gap("01:00:27:00", None)]
+    #[case::end("01:00:35:00", None)]
+    fn test_timeline_source_at(#[case] record: &str, #[case] expected: Option<(&str, &str, &str)>) {
+        let timeline = timeline();
+        let found = timeline
+            .source_at(&tc(record))
+            .map(|(track, clip, source)| (track.name(), clip.reel(), source.timecode()));
+
+        assert_eq!(
+            expected,
+            found
+                .as_ref()
+                .map(|(track, reel, source)| (*track, *reel, source.as_str()))
+        );
+    }
+
+    #[test]
+    fn test_timeline_record_at() {
+        let timeline = timeline();
+
+        let uses: Vec<(String, String)> = timeline
+            .record_at("A001", &tc("14:21:02:00"))
+            .into_iter()
+            .map(|(track, _, record)| (track.name().to_string(), record.timecode()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("V1".to_string(), "01:00:02:00".to_string()),
+                ("V1".to_string(), "01:00:22:00".to_string()),
+            ],
+            uses
+        );
+
+        assert!(timeline.record_at("A003", &tc("14:21:02:00")).is_empty());
+        assert!(timeline.record_at("A001", &tc("14:21:12:00")).is_empty());
+    }
+
+    #[test]
+    fn test_track_gaps_and_overlaps() {
+        let timeline = timeline();
+
+        let v1 = timeline.track("V1").unwrap();
+        assert!(v1.gaps().is_empty(), "v1 gaps");
+        assert_eq!(
+            vec!["01:00:08:00 - 01:00:10:00"],
+            ranges(v1.overlaps()),
+            "v1 overlaps"
+        );
+        assert_eq!(
+            "01:00:00:00 - 01:00:25:00",
+            v1.extent().unwrap().to_string()
+        );
+        assert_eq!("00:00:27:00", v1.duration().timecode(), "v1 duration");
+
+        let v2 = timeline.track("V2").unwrap();
+        assert_eq!(
+            vec!["01:00:06:00 - 01:00:30:00"],
+            ranges(v2.gaps()),
+            "v2 gaps"
+        );
+        assert!(v2.overlaps().is_empty(), "v2 overlaps");
+        assert_eq!("00:00:07:00", v2.duration().timecode(), "v2 duration");
+    }
+
+    #[test]
+    fn test_track_overlaps_nested() -> Result<(), TimecodeError> {
+        let mut timeline = Timeline::new(rates::F24);
+        let source = range("14:21:00:00", "14:21:10:00");
+        for record in &[
+            range("01:00:00:00", "01:00:10:00"),
+            range("01:00:02:00", "01:00:04:00"),
+            range("01:00:03:00", "01:00:12:00"),
+        ] {
+            timeline.add_clip("V1", Clip::new("A001", source, *record))?;
+        }
+
+        assert_eq!(
+            vec![
+                "01:00:02:00 - 01:00:04:00",
+                "01:00:03:00 - 01:00:04:00",
+                "01:00:03:00 - 01:00:10:00",
+            ],
+            ranges(timeline.track("V1").unwrap().overlaps())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timeline_summary() {
+        let timeline = timeline();
+
+        assert_eq!(
+            vec!["01:00:25:00 - 01:00:30:00"],
+            ranges(timeline.gaps()),
+            "gaps"
+        );
+
+        let summary = timeline.summary();
+        assert_eq!(5, summary.clips, "clips");
+        assert_eq!(
+            "01:00:00:00 - 01:00:35:00",
+            summary.extent.unwrap().to_string(),
+            "extent"
+        );
+        assert_eq!("00:00:30:00", summary.picture.timecode(), "picture");
+        assert_eq!("00:00:05:00", summary.gaps.timecode(), "gaps");
+        assert_eq!("00:00:02:00", summary.overlaps.timecode(), "overlaps");
+    }
+
+    #[test]
+    fn test_timeline_summary_empty() {
+        let summary = Timeline::new(rates::F24).summary();
+        assert_eq!(0, summary.clips);
+        assert_eq!(None, summary.extent);
+        assert_eq!(0, summary.picture.frames());
+        assert_eq!(0, summary.gaps.frames());
+        assert_eq!(0, summary.overlaps.frames());
+    }
+
+    #[test]
+    fn test_timeline_rate_mismatch() {
+        let record = range_at("01:00:00:00", "01:00:01:00", rates::F25);
+
+        let mut timeline = Timeline::new(rates::F24);
+        let err = timeline
+            .add_clip(
+                "V1",
+                Clip::new("A001", range("14:21:00:00", "14:21:01:00"), record),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            TimecodeError::RateMismatch {
+                found: rates::F25,
+                expected: rates::F24,
+            },
+            err
+        );
+        assert!(timeline.tracks().is_empty());
+    }
+}